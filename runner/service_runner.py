@@ -5,8 +5,8 @@ SFC, DISM) and streams progress to stderr for the UI while emitting a final JSON
 report to stdout. Windows elevation is requested automatically when needed.
 """
 
-import sys, os, ctypes, json, subprocess, argparse, logging, time
-from typing import List, Dict, Any, Callable
+import sys, os, ctypes, json, subprocess, argparse, logging, threading, time
+from typing import List, Dict, Any, Callable, Optional
 
 # Import Sentry configuration early for error tracking
 try:
@@ -209,6 +209,62 @@ TASK_HANDLERS: Dict[str, TaskHandler] = {
 }
 
 
+def _load_previous_results(
+    log_file: Optional[str], tasks: List[Task], resume_from: int
+) -> List[TaskResult]:
+    """Recover results of tasks completed by a previous (crashed) runner instance.
+
+    Reads the last PROGRESS_JSON snapshot from the shared log file. Tasks without
+    a recorded result get a failure placeholder so result indexes stay aligned.
+    """
+    previous: List[TaskResult] = []
+    if log_file and os.path.isfile(log_file):
+        try:
+            with open(log_file, "r", encoding="utf-8", errors="replace") as f:
+                for line in f:
+                    marker = line.find("PROGRESS_JSON:")
+                    if marker == -1:
+                        continue
+                    try:
+                        payload = json.loads(line[marker + len("PROGRESS_JSON:") :])
+                        previous = payload.get("results") or previous
+                    except Exception:
+                        continue
+        except Exception as e:  # noqa: BLE001
+            logging.warning("Could not read previous progress from log: %s", e)
+
+    results: List[TaskResult] = list(previous[:resume_from])
+    for idx in range(len(results), resume_from):
+        results.append(
+            {
+                "task_type": tasks[idx].get("type", ""),
+                "status": "failure",
+                "summary": {"reason": "Result lost: the runner crashed during this task."},
+            }
+        )
+    return results
+
+
+# Seconds between heartbeat file updates; AutoService reads the file's mtime.
+HEARTBEAT_INTERVAL_SECONDS: float = 5.0
+
+
+def _start_heartbeat(path: str) -> None:
+    """Touch ``path`` every few seconds from a daemon thread as a liveness signal."""
+
+    def beat() -> None:
+        while True:
+            try:
+                with open(path, "a", encoding="utf-8"):
+                    pass
+                os.utime(path, None)
+            except Exception:  # noqa: BLE001
+                pass
+            time.sleep(HEARTBEAT_INTERVAL_SECONDS)
+
+    threading.Thread(target=beat, name="heartbeat", daemon=True).start()
+
+
 def main():
     """Entrypoint: parse input, execute tasks, emit final JSON report.
 
@@ -238,6 +294,22 @@ def main():
         default=None,
         help="Optional path to write a live log file (in addition to stderr).",
     )
+    parser.add_argument(
+        "--resume-from",
+        dest="resume_from",
+        type=int,
+        default=0,
+        help="Index of the first task to run; earlier tasks are treated as already completed "
+        "(used by AutoService to resume after a runner crash).",
+    )
+    parser.add_argument(
+        "--heartbeat-file",
+        dest="heartbeat_file",
+        type=str,
+        default=None,
+        help="Optional file whose modification time is refreshed every few seconds "
+        "while the runner is alive.",
+    )
     args = parser.parse_args()
 
     # Configure file logging if requested
@@ -265,6 +337,9 @@ def main():
         # Relaunch initiated successfully; exit unelevated instance so elevated one can proceed
         sys.exit(0)
 
+    if args.heartbeat_file:
+        _start_heartbeat(args.heartbeat_file)
+
     raw_input = args.json_input
     logging.info(f"Received input: {raw_input[:MAX_LOG_SNIPPET]}...")
     input_data = None
@@ -333,7 +408,18 @@ def main():
     all_results = []
     overall_success = True
 
+    resume_from = max(0, min(args.resume_from or 0, len(tasks)))
+    if resume_from:
+        all_results = _load_previous_results(args.log_file, tasks, resume_from)
+        overall_success = not any(
+            r.get("status") in ("failure", "error") for r in all_results
+        )
+        logging.info("Resuming from task %d/%d", resume_from + 1, len(tasks))
+        flush_logs()
+
     for idx, task in enumerate(tasks):
+        if idx < resume_from:
+            continue
         task_type = task.get("type", "")
         handler = TASK_HANDLERS.get(task_type) if task_type else None
 
@@ -0,0 +1,495 @@
+//! Software-state audit commands (Windows-first).
+//!
+//! Responsibilities:
+//! - Enumerate installed fonts from the registry and the Windows Fonts directory
+//! - Read system, user and process environment variables
+//! - Report Windows activation/licensing status
+//! - List shares hosted by this machine and drives mapped to remote shares
+//!
+//! Each command returns structured data for the UI/report and a clear
+//! "not supported" error on other platforms.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Style words stripped from a font's registry name to get its family.
+const FONT_STYLE_WORDS: &[&str] = &[
+    "regular",
+    "bold",
+    "italic",
+    "oblique",
+    "light",
+    "semilight",
+    "semibold",
+    "demibold",
+    "extralight",
+    "extrabold",
+    "ultralight",
+    "ultrabold",
+    "black",
+    "heavy",
+    "medium",
+    "thin",
+    "condensed",
+    "narrow",
+];
+
+/// Name fragments marking an environment variable as sensitive (matched uppercase).
+const SENSITIVE_ENV_MARKERS: &[&str] = &[
+    "TOKEN",
+    "SECRET",
+    "PASSWORD",
+    "PASSWD",
+    "API_KEY",
+    "APIKEY",
+    "ACCESS_KEY",
+    "PRIVATE_KEY",
+    "CREDENTIAL",
+    "AUTH",
+];
+/// Replacement shown for masked environment variable values.
+const MASKED_VALUE: &str = "********";
+
+/// Windows licensing state returned by `get_windows_activation_status`.
+#[derive(Debug, Serialize)]
+pub struct ActivationStatus {
+    pub activated: bool,
+    /// Edition as named by the licensing service (e.g. "Windows(R), Professional edition")
+    pub edition: Option<String>,
+    /// License channel, e.g. "RETAIL", "OEM_DM" or "VOLUME_KMSCLIENT"
+    pub channel: Option<String>,
+    /// Last five characters of the product key (the full key is never exposed)
+    pub partial_product_key: Option<String>,
+    /// When the current grace/volume activation period ends (RFC 3339), if it expires
+    pub expiry: Option<String>,
+    /// Human-readable license status, e.g. "Licensed" or "Notification"
+    pub license_status: String,
+}
+
+/// Shares hosted by this machine and connections to remote shares.
+#[derive(Debug, Serialize)]
+pub struct NetworkShares {
+    pub hosted: Vec<HostedShare>,
+    pub mapped: Vec<MappedShare>,
+}
+
+/// A share hosted by this machine (as listed by `net share`).
+#[derive(Debug, Serialize)]
+pub struct HostedShare {
+    pub name: String,
+    pub path: String,
+    pub description: String,
+    /// "disk", "printer", "device" or "ipc"
+    pub kind: String,
+    /// True for administrative shares such as `C$` and `ADMIN$`
+    pub administrative: bool,
+}
+
+/// A connection to a remote share (as listed by `net use`).
+#[derive(Debug, Serialize)]
+pub struct MappedShare {
+    /// Drive letter (e.g. "Z:"), or None for a deviceless connection
+    pub local_name: Option<String>,
+    pub remote_path: String,
+    /// Connection state as reported by Windows, e.g. "Connected" or "Disconnected"
+    pub status: String,
+    pub connected: bool,
+    /// True when the mapping is restored at logon
+    pub persistent: bool,
+}
+
+/// An installed font family and the files that provide it.
+#[derive(Debug, Serialize)]
+pub struct FontFamily {
+    pub family: String,
+    pub files: Vec<String>,
+}
+
+/// Raw font registration as read from the registry.
+#[derive(Debug, Deserialize)]
+struct FontRegistration {
+    name: String,
+    file: String,
+}
+
+#[tauri::command]
+/// List installed font families with their file paths, deduplicated by family.
+///
+/// Combines the machine and per-user font registrations in the registry with any
+/// font files in `%WINDIR%\Fonts` that aren't registered. Windows-only.
+pub async fn list_installed_fonts() -> Result<Vec<FontFamily>, String> {
+    tauri::async_runtime::spawn_blocking(list_installed_fonts_blocking)
+        .await
+        .map_err(|e| format!("Font listing worker failed: {}", e))?
+}
+
+fn list_installed_fonts_blocking() -> Result<Vec<FontFamily>, String> {
+    let registrations = read_font_registrations()?;
+    let fonts_dir =
+        std::path::PathBuf::from(std::env::var("WINDIR").unwrap_or_else(|_| "C:\\Windows".into()))
+            .join("Fonts");
+
+    let mut families: BTreeMap<String, FontFamily> = BTreeMap::new();
+    let mut add = |family: String, file: String| {
+        let entry = families
+            .entry(family.to_lowercase())
+            .or_insert_with(|| FontFamily {
+                family,
+                files: Vec::new(),
+            });
+        if !entry.files.iter().any(|f| f.eq_ignore_ascii_case(&file)) {
+            entry.files.push(file);
+        }
+    };
+
+    let mut registered_files = Vec::new();
+    for reg in registrations {
+        // Relative registry values are file names inside the Fonts directory
+        let path = std::path::Path::new(&reg.file);
+        let full = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            fonts_dir.join(path)
+        };
+        let full = full.to_string_lossy().to_string();
+        registered_files.push(full.to_lowercase());
+        for family in font_families_from_name(&reg.name) {
+            add(family, full.clone());
+        }
+    }
+
+    if let Ok(entries) = std::fs::read_dir(&fonts_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_font = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| {
+                    matches!(
+                        e.to_ascii_lowercase().as_str(),
+                        "ttf" | "ttc" | "otf" | "fon"
+                    )
+                })
+                .unwrap_or(false);
+            let full = path.to_string_lossy().to_string();
+            if !is_font || registered_files.contains(&full.to_lowercase()) {
+                continue;
+            }
+            if let Some(stem) = path.file_stem() {
+                add(stem.to_string_lossy().to_string(), full);
+            }
+        }
+    }
+
+    Ok(families.into_values().collect())
+}
+
+#[tauri::command]
+/// Read environment variables for a scope.
+///
+/// # Arguments
+/// * `scope` - "system" and "user" read the persisted values from the registry
+///   (Windows-only); "process" returns the environment AutoService is running with
+/// * `mask_sensitive` - Replace values of variables that look like secrets (names
+///   containing TOKEN, PASSWORD, API_KEY, ...) with asterisks; defaults to true
+pub async fn get_environment_variables(
+    scope: String,
+    mask_sensitive: Option<bool>,
+) -> Result<BTreeMap<String, String>, String> {
+    // The system/user scopes shell out to PowerShell, so keep them off the async runtime.
+    tauri::async_runtime::spawn_blocking(move || {
+        get_environment_variables_blocking(&scope, mask_sensitive.unwrap_or(true))
+    })
+    .await
+    .map_err(|e| format!("Environment variables worker failed: {}", e))?
+}
+
+fn get_environment_variables_blocking(
+    scope: &str,
+    mask_sensitive: bool,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut vars: BTreeMap<String, String> = match scope.trim().to_ascii_lowercase().as_str() {
+        "process" => std::env::vars_os()
+            .map(|(k, v)| {
+                (
+                    k.to_string_lossy().to_string(),
+                    v.to_string_lossy().to_string(),
+                )
+            })
+            .collect(),
+        "system" => read_persisted_env("Machine")?,
+        "user" => read_persisted_env("User")?,
+        other => {
+            return Err(format!(
+                "Unknown scope '{}' (expected system, user or process)",
+                other
+            ))
+        }
+    };
+    if mask_sensitive {
+        for (name, value) in vars.iter_mut() {
+            let upper = name.to_ascii_uppercase();
+            if SENSITIVE_ENV_MARKERS.iter().any(|m| upper.contains(m)) {
+                *value = MASKED_VALUE.to_string();
+            }
+        }
+    }
+    Ok(vars)
+}
+
+// Read persisted (registry) environment variables for "Machine" or "User".
+#[cfg(windows)]
+fn read_persisted_env(target: &str) -> Result<BTreeMap<String, String>, String> {
+    let script = format!(
+        "$vars = [Environment]::GetEnvironmentVariables('{}'); $out = @{{}}; \
+         foreach ($k in $vars.Keys) {{ $out[[string]$k] = [string]$vars[$k] }}; \
+         ConvertTo-Json -InputObject $out -Compress",
+        target
+    );
+    let text = run_powershell(&script)?;
+    if text.trim().is_empty() {
+        return Ok(BTreeMap::new());
+    }
+    serde_json::from_str(text.trim())
+        .map_err(|e| format!("Failed to parse environment variables: {}", e))
+}
+
+#[cfg(not(windows))]
+fn read_persisted_env(_target: &str) -> Result<BTreeMap<String, String>, String> {
+    Err("Reading system/user environment variables is only supported on Windows".into())
+}
+
+#[tauri::command]
+/// Read the Windows activation status from WMI `SoftwareLicensingProduct`.
+///
+/// Only the partial (last five characters) product key is returned. Windows-only.
+pub async fn get_windows_activation_status() -> Result<ActivationStatus, String> {
+    tauri::async_runtime::spawn_blocking(get_windows_activation_status_blocking)
+        .await
+        .map_err(|e| format!("Activation status worker failed: {}", e))?
+}
+
+fn get_windows_activation_status_blocking() -> Result<ActivationStatus, String> {
+    let raw = query_windows_license()?;
+    let text = |key: &str| {
+        raw.get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let status_code = raw
+        .get("LicenseStatus")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0);
+    let license_status = license_status_label(status_code);
+    // Description reads like "Windows(R) Operating System, RETAIL channel"
+    let channel = text("Description").and_then(|d| {
+        d.rsplit(',')
+            .next()
+            .map(|c| c.trim().trim_end_matches("channel").trim().to_string())
+            .filter(|c| !c.is_empty())
+    });
+    let expiry = raw
+        .get("GracePeriodRemaining")
+        .and_then(|v| v.as_i64())
+        .filter(|minutes| *minutes > 0)
+        .map(|minutes| (chrono::Utc::now() + chrono::Duration::minutes(minutes)).to_rfc3339());
+
+    Ok(ActivationStatus {
+        activated: status_code == 1,
+        edition: text("Name"),
+        channel,
+        partial_product_key: text("PartialProductKey"),
+        expiry,
+        license_status: license_status.to_string(),
+    })
+}
+
+/// Human-readable name of a `SoftwareLicensingProduct.LicenseStatus` code.
+pub(crate) fn license_status_label(code: i64) -> &'static str {
+    match code {
+        0 => "Unlicensed",
+        1 => "Licensed",
+        2 => "Initial grace period",
+        3 => "Additional grace period",
+        4 => "Non-genuine grace period",
+        5 => "Notification",
+        6 => "Extended grace period",
+        _ => "Unknown",
+    }
+}
+
+/// PowerShell printing the licensing details of the installed Windows SKU as JSON
+/// (the filter is the ApplicationID of Windows in the licensing service).
+#[cfg(windows)]
+pub(crate) const WINDOWS_LICENSE_SCRIPT: &str = "Get-CimInstance SoftwareLicensingProduct \
+    -Filter 'ApplicationID=''55c92734-d682-4d71-983e-d6ec3f16059f'' AND PartialProductKey IS NOT NULL' | \
+    Select-Object -First 1 Name, Description, LicenseStatus, PartialProductKey, GracePeriodRemaining | \
+    ConvertTo-Json -Compress";
+
+#[cfg(windows)]
+fn query_windows_license() -> Result<serde_json::Value, String> {
+    let text = run_powershell(WINDOWS_LICENSE_SCRIPT)?;
+    if text.trim().is_empty() {
+        return Err("No Windows license information was found".into());
+    }
+    serde_json::from_str(text.trim()).map_err(|e| format!("Failed to parse license info: {}", e))
+}
+
+#[cfg(not(windows))]
+fn query_windows_license() -> Result<serde_json::Value, String> {
+    Err("Windows activation status is only available on Windows".into())
+}
+
+#[tauri::command]
+/// List shares hosted by this machine and drives/connections mapped to remote shares.
+///
+/// Uses WMI `Win32_Share` (what `net share` shows) and `Win32_NetworkConnection`
+/// (what `net use` shows), so output is structured and independent of the UI language.
+/// Windows-only.
+pub async fn list_network_shares() -> Result<NetworkShares, String> {
+    tauri::async_runtime::spawn_blocking(list_network_shares_blocking)
+        .await
+        .map_err(|e| format!("Network share listing worker failed: {}", e))?
+}
+
+fn list_network_shares_blocking() -> Result<NetworkShares, String> {
+    let (shares_raw, connections_raw) = query_network_shares()?;
+    let text = |v: &serde_json::Value, key: &str| {
+        v.get(key)
+            .and_then(|x| x.as_str())
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    };
+
+    let hosted = shares_raw
+        .iter()
+        .map(|v| {
+            let share_type = v.get("Type").and_then(|t| t.as_u64()).unwrap_or(0);
+            let kind = match share_type & 0x3 {
+                0 => "disk",
+                1 => "printer",
+                2 => "device",
+                _ => "ipc",
+            };
+            HostedShare {
+                name: text(v, "Name"),
+                path: text(v, "Path"),
+                description: text(v, "Description"),
+                kind: kind.to_string(),
+                administrative: share_type & 0x8000_0000 != 0,
+            }
+        })
+        .collect();
+    let mapped = connections_raw
+        .iter()
+        .map(|v| {
+            let status = text(v, "ConnectionState");
+            MappedShare {
+                local_name: Some(text(v, "LocalName")).filter(|s| !s.is_empty()),
+                remote_path: text(v, "RemoteName"),
+                connected: status.eq_ignore_ascii_case("connected"),
+                status,
+                persistent: v
+                    .get("Persistent")
+                    .and_then(|p| p.as_bool())
+                    .unwrap_or(false),
+            }
+        })
+        .collect();
+    Ok(NetworkShares { hosted, mapped })
+}
+
+#[cfg(windows)]
+fn query_network_shares() -> Result<(Vec<serde_json::Value>, Vec<serde_json::Value>), String> {
+    let shares = run_powershell(
+        "ConvertTo-Json -Compress -InputObject @(Get-CimInstance Win32_Share | \
+         Select-Object Name, Path, Description, Type)",
+    )?;
+    let connections = run_powershell(
+        "ConvertTo-Json -Compress -InputObject @(Get-CimInstance Win32_NetworkConnection | \
+         Select-Object LocalName, RemoteName, ConnectionState, Persistent)",
+    )?;
+    let parse = |text: &str| -> Result<Vec<serde_json::Value>, String> {
+        if text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        serde_json::from_str(text.trim()).map_err(|e| format!("Failed to parse share list: {}", e))
+    };
+    Ok((parse(&shares)?, parse(&connections)?))
+}
+
+#[cfg(not(windows))]
+fn query_network_shares() -> Result<(Vec<serde_json::Value>, Vec<serde_json::Value>), String> {
+    Err("Listing network shares is only supported on Windows".into())
+}
+
+// Turn a registry name like "Cambria & Cambria Math (TrueType)" or
+// "Arial Bold Italic (TrueType)" into family names ("Cambria", "Cambria Math", "Arial").
+fn font_families_from_name(name: &str) -> Vec<String> {
+    let base = match name.rfind(" (") {
+        Some(idx) if name.ends_with(')') => &name[..idx],
+        _ => name,
+    };
+    base.split(" & ")
+        .filter_map(|part| {
+            let mut words: Vec<&str> = part.split_whitespace().collect();
+            while words.len() > 1
+                && FONT_STYLE_WORDS.contains(&words[words.len() - 1].to_ascii_lowercase().as_str())
+            {
+                words.pop();
+            }
+            (!words.is_empty()).then(|| words.join(" "))
+        })
+        .collect()
+}
+
+#[cfg(windows)]
+fn read_font_registrations() -> Result<Vec<FontRegistration>, String> {
+    let script = r#"
+$ErrorActionPreference = 'SilentlyContinue'
+$out = @()
+foreach ($key in 'HKLM:\SOFTWARE\Microsoft\Windows NT\CurrentVersion\Fonts',
+                 'HKCU:\SOFTWARE\Microsoft\Windows NT\CurrentVersion\Fonts') {
+  $props = Get-ItemProperty -Path $key
+  if ($props) {
+    foreach ($p in $props.PSObject.Properties) {
+      if ($p.Name -notlike 'PS*') { $out += [pscustomobject]@{ name = $p.Name; file = [string]$p.Value } }
+    }
+  }
+}
+ConvertTo-Json -InputObject @($out) -Compress
+"#;
+    let text = run_powershell(script)?;
+    if text.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(text.trim()).map_err(|e| format!("Failed to parse font list: {}", e))
+}
+
+#[cfg(not(windows))]
+fn read_font_registrations() -> Result<Vec<FontRegistration>, String> {
+    Err("Listing installed fonts is only supported on Windows".into())
+}
+
+// Run a PowerShell script and return its stdout.
+#[cfg(windows)]
+fn run_powershell(script: &str) -> Result<String, String> {
+    let output = std::process::Command::new("powershell.exe")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-Command",
+            script,
+        ])
+        .output()
+        .map_err(|e| format!("Failed to start PowerShell: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("PowerShell failed: {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,612 @@
+//! Quick performance benchmarks that produce report-ready numbers.
+//!
+//! Responsibilities:
+//! - Sequential disk write/read throughput on a chosen drive
+//! - Memory bandwidth and latency
+//! - Thermal throttling detection under an all-core CPU load
+//!
+//! Benchmarks run on blocking worker threads, are registered in the operation
+//! registry so they can be cancelled, and emit progress events for the UI.
+use std::fs::{self, File, OpenOptions};
+use std::hint::black_box;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use sysinfo::{Components, Disks, System};
+use tauri::Emitter;
+
+use crate::operations::CancelToken;
+use crate::state::AppState;
+
+/// Size of each I/O request in the disk benchmark (a multiple of any sector size).
+const DISK_CHUNK_BYTES: usize = 1024 * 1024;
+/// Accepted range for the disk benchmark file size, in MB.
+const DISK_SIZE_RANGE_MB: std::ops::RangeInclusive<u64> = 16..=8192;
+/// Free space that must remain on the drive after the benchmark file is written.
+const DISK_FREE_MARGIN_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Upper bound for the memory benchmark buffer.
+const MEMORY_MAX_BUFFER_BYTES: u64 = 512 * 1024 * 1024;
+/// Smallest buffer that still measures RAM rather than CPU caches.
+const MEMORY_MIN_BUFFER_BYTES: u64 = 64 * 1024 * 1024;
+/// Number of timed passes per memory bandwidth test (the best one is reported).
+const MEMORY_PASSES: usize = 3;
+/// Dependent loads performed by the memory latency test.
+const MEMORY_LATENCY_STEPS: usize = 5_000_000;
+
+/// Accepted range for the thermal throttling test duration, in seconds.
+const THERMAL_DURATION_RANGE_SECS: std::ops::RangeInclusive<u64> = 10..=600;
+/// Temperature at or above which a frequency drop is attributed to heat.
+const THERMAL_HIGH_TEMP_C: f32 = 85.0;
+/// Sustained frequency drop (relative to the early peak) that counts as throttling.
+const THERMAL_DROP_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Result of `benchmark_disk`.
+#[derive(Debug, Serialize)]
+pub struct DiskBenchmarkResult {
+    /// Directory the benchmark file was written to
+    pub target_dir: String,
+    pub size_mb: u64,
+    pub write_mb_per_sec: f64,
+    pub read_mb_per_sec: f64,
+    pub write_seconds: f64,
+    pub read_seconds: f64,
+}
+
+#[tauri::command]
+/// Measure sequential write and read throughput of a drive.
+///
+/// Writes a temporary file of `size_mb` MB (flushed to disk), reads it back, then
+/// deletes it. Emits `disk_benchmark_progress` events (`{ phase, percent }`) and can
+/// be stopped with `cancel_all_operations`. Reads bypass the OS cache on Windows.
+///
+/// # Arguments
+/// * `drive` - A drive (e.g. "D:") or directory to benchmark
+/// * `size_mb` - Size of the test file, between 16 and 8192 MB
+pub async fn benchmark_disk(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    drive: String,
+    size_mb: u64,
+) -> Result<DiskBenchmarkResult, String> {
+    if !DISK_SIZE_RANGE_MB.contains(&size_mb) {
+        return Err(format!(
+            "Benchmark size must be between {} and {} MB",
+            DISK_SIZE_RANGE_MB.start(),
+            DISK_SIZE_RANGE_MB.end()
+        ));
+    }
+    let target_dir = benchmark_dir_for(&drive)?;
+    let size_bytes = size_mb * 1024 * 1024;
+    if let Some(free) = free_space_for(&target_dir) {
+        if free < size_bytes + DISK_FREE_MARGIN_BYTES {
+            return Err(format!(
+                "Not enough free space on {}: {} MB free, {} MB needed",
+                target_dir.display(),
+                free / (1024 * 1024),
+                (size_bytes + DISK_FREE_MARGIN_BYTES) / (1024 * 1024)
+            ));
+        }
+    }
+
+    let op = state.operations.register("disk_benchmark");
+    tauri::async_runtime::spawn_blocking(move || {
+        let cancel = op.token();
+        let file = target_dir.join(format!("autoservice_bench_{}.tmp", uuid::Uuid::new_v4()));
+        let emit = |phase: &str, percent: u64| {
+            let _ = app.emit(
+                "disk_benchmark_progress",
+                serde_json::json!({ "phase": phase, "percent": percent }),
+            );
+        };
+        let result = run_disk_benchmark(&file, size_bytes, &cancel, &emit);
+        let _ = fs::remove_file(&file);
+        let (write_seconds, read_seconds) = result?;
+        let mb = size_mb as f64;
+        Ok(DiskBenchmarkResult {
+            target_dir: target_dir.to_string_lossy().to_string(),
+            size_mb,
+            write_mb_per_sec: mb / write_seconds.max(f64::EPSILON),
+            read_mb_per_sec: mb / read_seconds.max(f64::EPSILON),
+            write_seconds,
+            read_seconds,
+        })
+    })
+    .await
+    .map_err(|e| format!("Disk benchmark worker failed: {}", e))?
+}
+
+/// Result of `benchmark_memory`.
+#[derive(Debug, Serialize)]
+pub struct MemoryBenchmarkResult {
+    /// Size of the buffer each bandwidth test streamed through
+    pub buffer_mb: u64,
+    pub write_gb_per_sec: f64,
+    pub read_gb_per_sec: f64,
+    pub copy_gb_per_sec: f64,
+    /// Average latency of a dependent random memory access, in nanoseconds
+    pub latency_ns: f64,
+}
+
+#[tauri::command]
+/// Measure approximate memory bandwidth (write/read/copy) and random-access latency.
+///
+/// The buffer is sized from currently available memory (a quarter of it, clamped to
+/// 64-512 MB) and the test refuses to run when that much cannot be spared. Runs on a
+/// worker thread and can be stopped with `cancel_all_operations`.
+pub async fn benchmark_memory(
+    state: tauri::State<'_, AppState>,
+) -> Result<MemoryBenchmarkResult, String> {
+    let mut sys = System::new();
+    sys.refresh_memory();
+    let available = sys.available_memory();
+    let buffer_bytes = (available / 4).min(MEMORY_MAX_BUFFER_BYTES);
+    if buffer_bytes < MEMORY_MIN_BUFFER_BYTES {
+        return Err(format!(
+            "Not enough free memory for the benchmark ({} MB available)",
+            available / (1024 * 1024)
+        ));
+    }
+
+    let op = state.operations.register("memory_benchmark");
+    tauri::async_runtime::spawn_blocking(move || {
+        run_memory_benchmark(buffer_bytes as usize, &op.token())
+    })
+    .await
+    .map_err(|e| format!("Memory benchmark worker failed: {}", e))?
+}
+
+fn run_memory_benchmark(
+    buffer_bytes: usize,
+    cancel: &CancelToken,
+) -> Result<MemoryBenchmarkResult, String> {
+    let words = buffer_bytes / std::mem::size_of::<u64>();
+    let half = words / 2;
+    let mut buffer = vec![0u64; words];
+    let gb = |bytes: usize, secs: f64| bytes as f64 / 1e9 / secs.max(f64::EPSILON);
+
+    let mut write_secs = f64::MAX;
+    let mut read_secs = f64::MAX;
+    let mut copy_secs = f64::MAX;
+    for pass in 0..MEMORY_PASSES {
+        if cancel.is_cancelled() {
+            return Err("Memory benchmark cancelled".into());
+        }
+        let started = Instant::now();
+        buffer.fill(pass as u64 + 1);
+        black_box(&buffer);
+        write_secs = write_secs.min(started.elapsed().as_secs_f64());
+
+        let started = Instant::now();
+        let sum = buffer.iter().fold(0u64, |acc, v| acc.wrapping_add(*v));
+        black_box(sum);
+        read_secs = read_secs.min(started.elapsed().as_secs_f64());
+
+        let started = Instant::now();
+        let (src, dst) = buffer.split_at_mut(half);
+        dst[..half].copy_from_slice(src);
+        black_box(&buffer);
+        copy_secs = copy_secs.min(started.elapsed().as_secs_f64());
+    }
+
+    if cancel.is_cancelled() {
+        return Err("Memory benchmark cancelled".into());
+    }
+    // Latency: chase a single random cycle through the buffer so every load depends
+    // on the previous one and hardware prefetchers can't help.
+    drop(buffer);
+    let chain = random_cycle(words.min(u32::MAX as usize));
+    let mut index = 0usize;
+    let started = Instant::now();
+    for _ in 0..MEMORY_LATENCY_STEPS {
+        index = chain[index] as usize;
+    }
+    black_box(index);
+    let latency_ns = started.elapsed().as_nanos() as f64 / MEMORY_LATENCY_STEPS as f64;
+
+    let half_bytes = half * std::mem::size_of::<u64>();
+    Ok(MemoryBenchmarkResult {
+        buffer_mb: (buffer_bytes / (1024 * 1024)) as u64,
+        write_gb_per_sec: gb(buffer_bytes, write_secs),
+        read_gb_per_sec: gb(buffer_bytes, read_secs),
+        // A copy reads and writes every byte once
+        copy_gb_per_sec: gb(half_bytes * 2, copy_secs),
+        latency_ns,
+    })
+}
+
+// Build a single cycle visiting every slot once (Sattolo's algorithm).
+fn random_cycle(len: usize) -> Vec<u32> {
+    let mut next: Vec<u32> = (0..len as u32).collect();
+    let mut x: u64 = 0x2545_F491_4F6C_DD1D;
+    for i in (1..len).rev() {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let j = (x % i as u64) as usize;
+        next.swap(i, j);
+    }
+    next
+}
+
+/// One sample taken while the thermal throttling load test runs.
+#[derive(Debug, Serialize)]
+pub struct ThermalSample {
+    pub elapsed_seconds: f64,
+    /// Average current frequency across all logical CPUs
+    pub avg_frequency_mhz: f64,
+    /// Hottest CPU temperature sensor, when sensors are readable
+    pub max_temperature_c: Option<f32>,
+}
+
+/// Result of `detect_thermal_throttling`.
+#[derive(Debug, Serialize)]
+pub struct ThermalThrottlingReport {
+    pub samples: Vec<ThermalSample>,
+    /// Highest average frequency seen in the first quarter of the test
+    pub peak_frequency_mhz: f64,
+    /// Average frequency over the second half of the test
+    pub sustained_frequency_mhz: f64,
+    /// Drop from peak to sustained frequency, in percent
+    pub frequency_drop_percent: f64,
+    pub max_temperature_c: Option<f32>,
+    pub throttling: bool,
+    /// "throttling", "no_throttling" or "inconclusive" (no sensors / static clock readings)
+    pub verdict: String,
+}
+
+#[tauri::command]
+/// Load every CPU core for `duration_seconds` while sampling frequency and temperature
+/// once per second, and judge whether the CPU throttled.
+///
+/// Throttling is reported when the sustained frequency drops at least 10% below the
+/// early peak while a CPU sensor reads 85 °C or more. When temperatures can't be read
+/// or the platform reports a fixed clock, the verdict is "inconclusive". Can be stopped
+/// with `cancel_all_operations`.
+pub async fn detect_thermal_throttling(
+    state: tauri::State<'_, AppState>,
+    duration_seconds: u64,
+) -> Result<ThermalThrottlingReport, String> {
+    if !THERMAL_DURATION_RANGE_SECS.contains(&duration_seconds) {
+        return Err(format!(
+            "Test duration must be between {} and {} seconds",
+            THERMAL_DURATION_RANGE_SECS.start(),
+            THERMAL_DURATION_RANGE_SECS.end()
+        ));
+    }
+    let op = state.operations.register("thermal_test");
+    tauri::async_runtime::spawn_blocking(move || {
+        run_thermal_test(Duration::from_secs(duration_seconds), &op.token())
+    })
+    .await
+    .map_err(|e| format!("Thermal test worker failed: {}", e))?
+}
+
+fn run_thermal_test(
+    duration: Duration,
+    cancel: &CancelToken,
+) -> Result<ThermalThrottlingReport, String> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    let handles: Vec<_> = (0..workers)
+        .map(|seed| {
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                let mut x = seed as u64 + 1;
+                while !stop.load(Ordering::Relaxed) {
+                    for _ in 0..100_000 {
+                        x = black_box(x.wrapping_mul(6364136223846793005).wrapping_add(1));
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let mut sys = System::new();
+    let mut components = Components::new_with_refreshed_list();
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    while started.elapsed() < duration && !cancel.is_cancelled() {
+        std::thread::sleep(Duration::from_secs(1));
+        sys.refresh_cpu_frequency();
+        components.refresh(false);
+        let cpus = sys.cpus();
+        let avg_frequency_mhz = if cpus.is_empty() {
+            0.0
+        } else {
+            cpus.iter().map(|c| c.frequency() as f64).sum::<f64>() / cpus.len() as f64
+        };
+        samples.push(ThermalSample {
+            elapsed_seconds: started.elapsed().as_secs_f64(),
+            avg_frequency_mhz,
+            max_temperature_c: max_cpu_temperature(&components),
+        });
+    }
+    stop.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.join();
+    }
+    if cancel.is_cancelled() {
+        return Err("Thermal test cancelled".into());
+    }
+    Ok(judge_throttling(samples))
+}
+
+// Hottest CPU-related sensor; falls back to all sensors when none are labelled as CPU.
+fn max_cpu_temperature(components: &Components) -> Option<f32> {
+    let is_cpu = |label: &str| {
+        let l = label.to_ascii_lowercase();
+        ["cpu", "package", "core", "tctl", "tdie"]
+            .iter()
+            .any(|k| l.contains(k))
+    };
+    let temps = |cpu_only: bool| {
+        components
+            .iter()
+            .filter(|c| !cpu_only || is_cpu(c.label()))
+            .filter_map(|c| c.temperature())
+            .filter(|t| t.is_finite() && *t > 0.0)
+            .fold(None, |max: Option<f32>, t| {
+                Some(max.map_or(t, |m| m.max(t)))
+            })
+    };
+    temps(true).or_else(|| temps(false))
+}
+
+fn judge_throttling(samples: Vec<ThermalSample>) -> ThermalThrottlingReport {
+    let freqs: Vec<f64> = samples.iter().map(|s| s.avg_frequency_mhz).collect();
+    let early = &freqs[..freqs.len().div_ceil(4)];
+    let late = &freqs[freqs.len() / 2..];
+    let peak = early.iter().cloned().fold(0.0, f64::max);
+    let sustained = if late.is_empty() {
+        0.0
+    } else {
+        late.iter().sum::<f64>() / late.len() as f64
+    };
+    let drop_percent = if peak > 0.0 {
+        ((peak - sustained) / peak * 100.0).max(0.0)
+    } else {
+        0.0
+    };
+    let max_temp = samples
+        .iter()
+        .filter_map(|s| s.max_temperature_c)
+        .fold(None, |max: Option<f32>, t| {
+            Some(max.map_or(t, |m| m.max(t)))
+        });
+    let static_clock = freqs.windows(2).all(|w| w[0] == w[1]);
+
+    let dropped = drop_percent >= THERMAL_DROP_THRESHOLD_PERCENT;
+    let hot = max_temp.is_some_and(|t| t >= THERMAL_HIGH_TEMP_C);
+    let throttling = dropped && hot;
+    let verdict = if throttling {
+        "throttling"
+    } else if max_temp.is_none() || static_clock {
+        "inconclusive"
+    } else {
+        "no_throttling"
+    };
+    ThermalThrottlingReport {
+        samples,
+        peak_frequency_mhz: peak,
+        sustained_frequency_mhz: sustained,
+        frequency_drop_percent: drop_percent,
+        max_temperature_c: max_temp,
+        throttling,
+        verdict: verdict.to_string(),
+    }
+}
+
+// Write then read back `size_bytes` at `file`, returning (write, read) seconds.
+fn run_disk_benchmark(
+    file: &Path,
+    size_bytes: u64,
+    cancel: &CancelToken,
+    emit: &dyn Fn(&str, u64),
+) -> Result<(f64, f64), String> {
+    let chunks = size_bytes / DISK_CHUNK_BYTES as u64;
+    let mut buffer = AlignedBuffer::new(DISK_CHUNK_BYTES);
+    fill_pseudo_random(buffer.as_mut_slice());
+
+    let started = Instant::now();
+    let mut out = File::create(file).map_err(|e| format!("Failed to create test file: {}", e))?;
+    for i in 0..chunks {
+        if cancel.is_cancelled() {
+            return Err("Disk benchmark cancelled".into());
+        }
+        out.write_all(buffer.as_slice())
+            .map_err(|e| format!("Write failed: {}", e))?;
+        report_progress(emit, "write", i, chunks);
+    }
+    out.sync_all()
+        .map_err(|e| format!("Failed to flush test file: {}", e))?;
+    drop(out);
+    let write_seconds = started.elapsed().as_secs_f64();
+
+    let started = Instant::now();
+    let mut input = open_uncached(file).map_err(|e| format!("Failed to open test file: {}", e))?;
+    for i in 0..chunks {
+        if cancel.is_cancelled() {
+            return Err("Disk benchmark cancelled".into());
+        }
+        input
+            .read_exact(buffer.as_mut_slice())
+            .map_err(|e| format!("Read failed: {}", e))?;
+        report_progress(emit, "read", i, chunks);
+    }
+    let read_seconds = started.elapsed().as_secs_f64();
+    Ok((write_seconds, read_seconds))
+}
+
+// Emit a progress event roughly every 5%.
+fn report_progress(emit: &dyn Fn(&str, u64), phase: &str, index: u64, total: u64) {
+    let step = (total / 20).max(1);
+    if (index + 1).is_multiple_of(step) || index + 1 == total {
+        emit(phase, (index + 1) * 100 / total);
+    }
+}
+
+// Pick a writable directory on the requested drive. The system temp directory is
+// preferred when it lives on that drive, since drive roots usually need elevation.
+fn benchmark_dir_for(drive: &str) -> Result<PathBuf, String> {
+    let trimmed = drive.trim();
+    if trimmed.is_empty() {
+        return Err("No drive specified".into());
+    }
+    let mut root = PathBuf::from(trimmed);
+    if trimmed.len() == 2 && trimmed.ends_with(':') {
+        root = PathBuf::from(format!("{}\\", trimmed));
+    }
+    if !root.is_dir() {
+        return Err(format!("Drive or directory not found: {}", trimmed));
+    }
+    let temp = std::env::temp_dir();
+    if temp.starts_with(&root) && temp.is_dir() {
+        return Ok(temp);
+    }
+    Ok(root)
+}
+
+// Free space of the disk whose mount point contains `dir`, if known.
+fn free_space_for(dir: &Path) -> Option<u64> {
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .iter()
+        .filter(|d| dir.starts_with(d.mount_point()))
+        .max_by_key(|d| d.mount_point().as_os_str().len())
+        .map(|d| d.available_space())
+}
+
+#[cfg(windows)]
+fn open_uncached(path: &Path) -> std::io::Result<File> {
+    use std::os::windows::fs::OpenOptionsExt;
+    /// FILE_FLAG_NO_BUFFERING: read straight from the device instead of the file cache.
+    const FILE_FLAG_NO_BUFFERING: u32 = 0x2000_0000;
+    OpenOptions::new()
+        .read(true)
+        .custom_flags(FILE_FLAG_NO_BUFFERING)
+        .open(path)
+}
+
+#[cfg(not(windows))]
+fn open_uncached(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new().read(true).open(path)
+}
+
+// Fill a buffer with cheap pseudo-random bytes so compression can't skew results.
+fn fill_pseudo_random(buf: &mut [u8]) {
+    let mut x: u64 = 0x9E37_79B9_7F4A_7C15;
+    for chunk in buf.chunks_mut(8) {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let bytes = x.to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+/// Heap buffer aligned to 4 KiB, as required for unbuffered I/O on Windows.
+struct AlignedBuffer {
+    storage: Vec<u8>,
+    offset: usize,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    const ALIGN: usize = 4096;
+
+    fn new(len: usize) -> Self {
+        let storage = vec![0u8; len + Self::ALIGN];
+        let offset = storage.as_ptr().align_offset(Self::ALIGN);
+        AlignedBuffer {
+            storage,
+            offset,
+            len,
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.storage[self.offset..self.offset + self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.storage[self.offset..self.offset + self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_judge_throttling() {
+        // (frequencies, temperature of every sample, verdict, drop percent)
+        let cases: [(&[f64], Option<f32>, &str, f64); 7] = [
+            (
+                &[4000.0, 4000.0, 3000.0, 3000.0],
+                Some(95.0),
+                "throttling",
+                25.0,
+            ),
+            (
+                &[4000.0, 4000.0, 3000.0, 3000.0],
+                Some(70.0),
+                "no_throttling",
+                25.0,
+            ),
+            (
+                &[4000.0, 3950.0, 3900.0, 3900.0],
+                Some(95.0),
+                "no_throttling",
+                2.5,
+            ),
+            (
+                &[4000.0, 4000.0, 4000.0, 4000.0],
+                Some(90.0),
+                "inconclusive",
+                0.0,
+            ),
+            (
+                &[4000.0, 3000.0, 3000.0, 3000.0],
+                None,
+                "inconclusive",
+                25.0,
+            ),
+            // Clocks ramping up over the test never count as a drop
+            (
+                &[3000.0, 3500.0, 4000.0, 4000.0],
+                Some(60.0),
+                "no_throttling",
+                0.0,
+            ),
+            (&[], None, "inconclusive", 0.0),
+        ];
+        for (freqs, temperature, verdict, drop_percent) in cases {
+            let samples = freqs
+                .iter()
+                .enumerate()
+                .map(|(i, &avg_frequency_mhz)| ThermalSample {
+                    elapsed_seconds: i as f64,
+                    avg_frequency_mhz,
+                    max_temperature_c: temperature,
+                })
+                .collect();
+            let report = judge_throttling(samples);
+            assert_eq!(report.verdict, verdict, "{:?} at {:?}", freqs, temperature);
+            assert_eq!(report.throttling, verdict == "throttling");
+            assert!(
+                (report.frequency_drop_percent - drop_percent).abs() < 1e-9,
+                "{:?}: drop {}",
+                freqs,
+                report.frequency_drop_percent
+            );
+            assert_eq!(report.max_temperature_c, temperature);
+        }
+    }
+}
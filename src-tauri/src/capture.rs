@@ -0,0 +1,166 @@
+//! Screen capture helpers for report documentation.
+//!
+//! Responsibilities:
+//! - Capture the primary display (or every display) to a PNG via the `xcap` crate
+//! - Save captures to an arbitrary path or straight into a report folder as `screenshot_{n}.png`
+//! - Surface a clear error when no interactive desktop is available (e.g. headless sessions)
+//! - Read and write clipboard text so copied error messages can go into report notes
+use std::path::{Path, PathBuf};
+
+use crate::state::AppState;
+
+#[tauri::command]
+/// Capture a screenshot and save it as a PNG, returning the written path.
+///
+/// # Arguments
+/// * `output_path` - Explicit destination file (used when `report_folder` is not provided)
+/// * `report_folder` - Optional report folder name under `data/reports`; the image is saved
+///   there as the next free `screenshot_{n}.png`
+/// * `all_displays` - Capture the union of all monitors instead of only the primary display
+///
+/// Windows-only. Returns an error when the session has no interactive desktop to capture.
+pub fn capture_screenshot(
+    state: tauri::State<AppState>,
+    output_path: Option<String>,
+    report_folder: Option<String>,
+    all_displays: Option<bool>,
+) -> Result<String, String> {
+    let target = match report_folder {
+        Some(folder) => {
+            crate::reports::validate_report_folder_name(&folder)?;
+            let dir = state.data_dir().join("reports").join(&folder);
+            if !dir.is_dir() {
+                return Err(format!("Report folder not found: {}", folder));
+            }
+            next_screenshot_path(&dir)
+        }
+        None => {
+            let path = output_path
+                .filter(|p| !p.trim().is_empty())
+                .ok_or_else(|| "Either output_path or report_folder is required".to_string())?;
+            PathBuf::from(path)
+        }
+    };
+
+    if let Some(parent) = target.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create output directory: {}", e))?;
+        }
+    }
+
+    capture_to_png(&target, all_displays.unwrap_or(false))?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+// Pick the first unused `screenshot_{n}.png` name inside a report folder (1-based).
+fn next_screenshot_path(dir: &Path) -> PathBuf {
+    let mut n = 1u32;
+    loop {
+        let candidate = dir.join(format!("screenshot_{}.png", n));
+        if !candidate.exists() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+#[cfg(windows)]
+const NO_DESKTOP_ERROR: &str =
+    "Screen capture is not available in this session (no interactive desktop)";
+
+#[cfg(windows)]
+fn capture_to_png(target: &Path, all_displays: bool) -> Result<(), String> {
+    use xcap::image::ImageFormat;
+    use xcap::Monitor;
+
+    // Headless/service sessions either fail to enumerate monitors or report none.
+    let monitors = Monitor::all().map_err(|_| NO_DESKTOP_ERROR.to_string())?;
+    let image = if all_displays {
+        capture_all_displays(&monitors)?
+    } else {
+        let primary = monitors
+            .iter()
+            .find(|m| m.is_primary().unwrap_or(false))
+            .or_else(|| monitors.first())
+            .ok_or_else(|| NO_DESKTOP_ERROR.to_string())?;
+        primary
+            .capture_image()
+            .map_err(|e| format!("Screenshot failed: {}", e))?
+    };
+
+    image
+        .save_with_format(target, ImageFormat::Png)
+        .map_err(|e| format!("Failed to save screenshot: {}", e))
+}
+
+// Stitch every monitor into one image, placing each capture at its desktop position.
+#[cfg(windows)]
+fn capture_all_displays(monitors: &[xcap::Monitor]) -> Result<xcap::image::RgbaImage, String> {
+    use xcap::image::{imageops, RgbaImage};
+
+    let mut shots = Vec::with_capacity(monitors.len());
+    for monitor in monitors {
+        let x = monitor
+            .x()
+            .map_err(|e| format!("Screenshot failed: {}", e))?;
+        let y = monitor
+            .y()
+            .map_err(|e| format!("Screenshot failed: {}", e))?;
+        let image = monitor
+            .capture_image()
+            .map_err(|e| format!("Screenshot failed: {}", e))?;
+        shots.push((x, y, image));
+    }
+
+    let left = shots.iter().map(|(x, _, _)| *x).min();
+    let top = shots.iter().map(|(_, y, _)| *y).min();
+    let right = shots
+        .iter()
+        .map(|(x, _, img)| *x + img.width() as i32)
+        .max();
+    let bottom = shots
+        .iter()
+        .map(|(_, y, img)| *y + img.height() as i32)
+        .max();
+    let (Some(left), Some(top), Some(right), Some(bottom)) = (left, top, right, bottom) else {
+        return Err(NO_DESKTOP_ERROR.into());
+    };
+
+    let mut canvas = RgbaImage::new((right - left) as u32, (bottom - top) as u32);
+    for (x, y, image) in &shots {
+        imageops::overlay(&mut canvas, image, (x - left) as i64, (y - top) as i64);
+    }
+    Ok(canvas)
+}
+
+#[cfg(not(windows))]
+fn capture_to_png(_target: &Path, _all_displays: bool) -> Result<(), String> {
+    Err("Screen capture is only supported on Windows".into())
+}
+
+#[tauri::command]
+/// Return the text currently on the clipboard.
+///
+/// Returns an empty string when the clipboard is empty or holds non-text content
+/// (e.g. an image or files).
+pub fn get_clipboard_text() -> Result<String, String> {
+    let mut clipboard = open_clipboard()?;
+    match clipboard.get_text() {
+        Ok(text) => Ok(text),
+        Err(arboard::Error::ContentNotAvailable) => Ok(String::new()),
+        Err(e) => Err(format!("Failed to read clipboard: {}", e)),
+    }
+}
+
+#[tauri::command]
+/// Replace the clipboard contents with `text`.
+pub fn set_clipboard_text(text: String) -> Result<(), String> {
+    open_clipboard()?
+        .set_text(text)
+        .map_err(|e| format!("Failed to write clipboard: {}", e))
+}
+
+fn open_clipboard() -> Result<arboard::Clipboard, String> {
+    arboard::Clipboard::new().map_err(|e| format!("Clipboard is not available: {}", e))
+}
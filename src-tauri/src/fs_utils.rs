@@ -0,0 +1,62 @@
+//! Small filesystem helpers shared across command modules.
+//!
+//! Responsibilities:
+//! - Atomic file replacement (write to a sibling temp file, then rename over the target)
+//! - Probing whether a directory is writable
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Suffix used for temporary files created by [`write_atomic`].
+pub const ATOMIC_TMP_SUFFIX: &str = ".autoservice-tmp";
+
+/// Atomically replace `path` with `contents`.
+///
+/// The data is written and flushed to a temporary file next to the target, then
+/// renamed over it, so a crash or full disk mid-write never leaves a truncated file.
+///
+/// # Errors
+/// Returns a user-facing error string if the temp file cannot be written or renamed.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), String> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| format!("Invalid path: {}", path.display()))?;
+    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+
+    let tmp = tmp_path_for(path);
+    let result = (|| -> std::io::Result<()> {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    })();
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp);
+        return Err(format!("Failed to write {}: {}", path.display(), e));
+    }
+    Ok(())
+}
+
+// Build the sibling temp path used while atomically writing `path`.
+fn tmp_path_for(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(ATOMIC_TMP_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Check that `dir` can be written to by creating (if needed) the directory and
+/// writing, then deleting, a small probe file.
+///
+/// # Errors
+/// Returns the first failure as a user-facing error string.
+pub fn probe_writable(dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Cannot create directory: {}", e))?;
+    let probe = dir.join(format!(".autoservice-write-test-{}", uuid::Uuid::new_v4()));
+    fs::write(&probe, b"ok").map_err(|e| format!("Cannot write: {}", e))?;
+    fs::remove_file(&probe).map_err(|e| format!("Cannot delete test file: {}", e))
+}
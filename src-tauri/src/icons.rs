@@ -52,7 +52,7 @@ pub fn suggest_logo_from_exe(
     state: tauri::State<crate::state::AppState>,
     exe_path: String,
 ) -> Result<Option<String>, String> {
-    get_logo_from_exe(state.data_dir.as_path(), &exe_path)
+    get_logo_from_exe(&state.data_dir(), &exe_path)
 }
 
 /// Internal function to load an image file as a data URL.
@@ -6,10 +6,20 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 
 // Module declarations for organizing code
+mod audit;
+mod benchmarks;
+mod capture;
+mod fs_utils;
 mod icons;
+mod maintenance;
+mod migration;
 mod models;
+mod operations;
 mod paths;
+mod power;
+mod processes;
 mod programs;
+mod qr;
 mod reports;
 mod scripts;
 mod settings;
@@ -20,24 +30,65 @@ mod system;
 use tauri::{Emitter, Manager};
 
 // Import command functions to bring them into scope for the handler
+use crate::audit::{
+    get_environment_variables, get_windows_activation_status, list_installed_fonts,
+    list_network_shares,
+};
+use crate::benchmarks::{benchmark_disk, benchmark_memory, detect_thermal_throttling};
+use crate::capture::{capture_screenshot, get_clipboard_text, set_clipboard_text};
 use crate::icons::{read_image_as_data_url, suggest_logo_from_exe};
+use crate::maintenance::{list_broken_entries, prune_broken_entries};
+use crate::migration::migrate_data_dir;
+use crate::operations::cancel_all_operations;
+use crate::power::{
+    check_pending_reboot, get_power_plan, get_sleep_settings, schedule_restart, schedule_shutdown,
+    set_power_plan, set_sleep_settings, KeepAwakeGuard,
+};
+use crate::processes::{close_browser, get_top_processes, kill_processes_by_name};
 use crate::programs::{
-    get_tool_statuses, launch_program, list_programs, remove_program, save_program,
+    check_program_duplicate, clear_tool_override, get_plan_tool_dependencies, get_tool_statuses,
+    get_tool_statuses_grouped, launch_program, list_programs, list_tool_companions, remove_program,
+    run_tool, save_program, set_tool_override,
 };
+use crate::qr::generate_report_qr;
 use crate::reports::{
-    delete_report, list_network_reports, list_reports, load_report, load_report_from_path,
-    open_absolute_path, open_report_folder, save_report, save_report_to_network, test_network_path,
+    add_report_note, cancel_verify_all_reports, canonicalize_unc_path, compress_report_logs,
+    compute_plan_progress_weights, copy_file_to_network, delete_network_report, delete_report,
+    diff_reports, export_report_zip, export_reports_csv, find_duplicate_reports,
+    find_reports_missing_on_network, format_report_json, get_report_content_hash, import_report,
+    list_network_reports, list_reports, list_reports_paged, load_report, load_report_from_path,
+    open_absolute_path, open_report_folder, preview_report_folder_name, read_report_log_tail,
+    repair_report_metadata, save_report, save_report_to_network, search_report_log,
+    set_report_notes, sync_reports_to_network, tail_report_log, test_network_path,
+    verify_all_reports, verify_report,
+};
+use crate::scripts::{
+    cache_script, check_script_syntax, list_scripts, preview_script, refresh_cached_script,
+    remove_script, run_script, save_script, script_requires_elevation,
 };
-use crate::scripts::{list_scripts, remove_script, run_script, save_script};
 use crate::settings::{
-    load_app_settings, make_portable_path, resolve_portable_path, save_app_settings,
+    export_settings_profile, get_last_network_path, get_setting, import_settings_profile,
+    list_recent_network_paths, load_app_settings, make_portable_path, resolve_portable_path,
+    save_app_settings, set_last_network_path, set_setting, start_watching_settings,
+    stop_watching_settings,
+};
+use crate::shortcuts::{
+    launch_shortcut, list_shortcut_macros, remove_shortcut_macro, run_shortcut_macro,
+    save_shortcut_macro,
+};
+use crate::state::{ActiveRun, AppState};
+use crate::system::{
+    diff_system_info, format_boot_time, format_duration_human, get_bitlocker_status,
+    get_gpu_report, get_machine_fingerprint, get_public_network_info, get_reliability_history,
+    get_system_extra, get_system_info, get_system_info_fast, get_system_summary_text, is_elevated,
+    load_system_history, record_system_snapshot, sample_disk_io, start_system_monitor,
+    stop_system_monitor,
 };
-use crate::shortcuts::launch_shortcut;
-use crate::state::AppState;
-use crate::system::get_system_info;
 use std::io::{BufRead, BufReader, Read};
 use std::path::PathBuf;
 use std::process::{Command as StdCommand, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// A simple greeting command for testing IPC communication.
@@ -69,7 +120,8 @@ fn greet(name: &str) -> String {
 #[tauri::command]
 fn get_data_dirs(state: tauri::State<AppState>) -> Result<serde_json::Value, String> {
     // Get the root data directory from application state
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
 
     // Get subdirectories using the paths module
     let (reports, programs, settings, resources) = crate::paths::subdirs(data_root);
@@ -95,9 +147,245 @@ fn get_data_dirs(state: tauri::State<AppState>) -> Result<serde_json::Value, Str
     }))
 }
 
+/// Writability of one of the application's key directories.
+#[derive(Debug, serde::Serialize)]
+struct DirWritability {
+    name: String,
+    path: String,
+    writable: bool,
+    error: Option<String>,
+}
+
+/// Checks that the directories the app writes to are writable.
+///
+/// Probes data/reports, data/logs, data/settings and the WebView profile folder by
+/// writing and deleting a temp file in each. Also run at startup, where failures
+/// are logged, so permission problems on locked-down machines surface early.
+///
+/// # Returns
+/// One entry per directory with its writability and the error, if any
+#[tauri::command]
+fn check_writable_dirs(state: tauri::State<AppState>) -> Vec<DirWritability> {
+    writable_dir_checks(&state.data_dir())
+}
+
+fn writable_dir_checks(data_root: &std::path::Path) -> Vec<DirWritability> {
+    let (reports, _programs, settings, _resources) = crate::paths::subdirs(data_root);
+    [
+        ("reports", reports),
+        ("logs", data_root.join("logs")),
+        ("settings", settings),
+        ("webview_profile", data_root.join("webview_profile")),
+    ]
+    .into_iter()
+    .map(|(name, dir)| {
+        let result = crate::fs_utils::probe_writable(&dir);
+        DirWritability {
+            name: name.to_string(),
+            path: dir.to_string_lossy().to_string(),
+            writable: result.is_ok(),
+            error: result.err(),
+        }
+    })
+    .collect()
+}
+
+/// Interpreter used for the dev-mode Python runner fallback (resolved from PATH).
+const PYTHON_INTERPRETER: &str = "python";
+
+/// Modules from runner/requirements.txt that the runner imports at startup.
+const RUNNER_PYTHON_MODULES: &[&str] = &["dotenv", "requests", "speedtest", "sentry_sdk", "psutil"];
+
+// The runner script used when service_runner.exe is missing (repo_root/runner, with the
+// data directory at repo_root/data).
+fn python_runner_script(data_root: &std::path::Path) -> Option<PathBuf> {
+    let script = data_root.parent()?.join("runner").join("service_runner.py");
+    script.exists().then_some(script)
+}
+
+/// Readiness of the Python fallback used to run the service runner in dev mode.
+#[derive(Debug, serde::Serialize)]
+struct PythonEnvironment {
+    /// Interpreter that was probed
+    interpreter: String,
+    /// Output of `--version` (e.g. "Python 3.12.4"); None when it could not be run
+    version: Option<String>,
+    /// Path of runner/service_runner.py, when found
+    script_path: Option<String>,
+    /// Whether the compiled service_runner.exe exists (the fallback is then unused)
+    compiled_runner_present: bool,
+    /// Runner dependencies that failed to import (empty when imports were not checked)
+    missing_modules: Vec<String>,
+    /// Whether `start_service_run` can start the runner one way or the other
+    ready: bool,
+    /// Human-readable problems found
+    problems: Vec<String>,
+}
+
+/// Checks whether the Python runner fallback used by `pnpm tauri dev` can start.
+///
+/// Runs the interpreter with `--version`, looks for runner/service_runner.py and, when
+/// `check_imports` is set, runs a short probe that reports which of the runner's
+/// dependencies can't be found.
+///
+/// # Arguments
+/// * `interpreter` - Interpreter to probe (defaults to `python` from PATH, as the runner uses)
+/// * `check_imports` - Also verify the runner's key imports
+#[tauri::command]
+async fn check_python_environment(
+    state: tauri::State<'_, AppState>,
+    interpreter: Option<String>,
+    check_imports: Option<bool>,
+) -> Result<PythonEnvironment, String> {
+    let data_dir = state.data_dir();
+    let interpreter = interpreter
+        .map(|i| i.trim().to_string())
+        .filter(|i| !i.is_empty())
+        .unwrap_or_else(|| PYTHON_INTERPRETER.to_string());
+    let compiled_runner_present = data_dir
+        .join("resources")
+        .join("bin")
+        .join("service_runner.exe")
+        .is_file();
+    let script = python_runner_script(&data_dir);
+    let check_imports = check_imports.unwrap_or(false);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut problems = Vec::new();
+        let version = match StdCommand::new(&interpreter).arg("--version").output() {
+            // Python 2 printed its version to stderr.
+            Ok(out) if out.status.success() => Some(
+                String::from_utf8_lossy(if out.stdout.is_empty() { &out.stderr } else { &out.stdout })
+                    .trim()
+                    .to_string(),
+            ),
+            Ok(out) => {
+                problems.push(format!(
+                    "{} --version failed: {}",
+                    interpreter,
+                    String::from_utf8_lossy(&out.stderr).trim()
+                ));
+                None
+            }
+            Err(e) => {
+                problems.push(format!("Failed to run {}: {}", interpreter, e));
+                None
+            }
+        };
+        if script.is_none() {
+            problems.push(
+                "Runner script not found; expected <repo>/runner/service_runner.py".to_string(),
+            );
+        }
+
+        let mut missing_modules = Vec::new();
+        if check_imports && version.is_some() {
+            let probe = "import importlib.util, json, sys\n\
+                         print(json.dumps([m for m in sys.argv[1:] if importlib.util.find_spec(m) is None]))";
+            match StdCommand::new(&interpreter)
+                .arg("-c")
+                .arg(probe)
+                .args(RUNNER_PYTHON_MODULES)
+                .output()
+            {
+                Ok(out) if out.status.success() => {
+                    missing_modules = serde_json::from_slice(&out.stdout).unwrap_or_default();
+                    if !missing_modules.is_empty() {
+                        problems.push(format!(
+                            "Missing Python modules: {} (pip install -r runner/requirements.txt)",
+                            missing_modules.join(", ")
+                        ));
+                    }
+                }
+                Ok(out) => problems.push(format!(
+                    "Import probe failed: {}",
+                    String::from_utf8_lossy(&out.stderr).trim()
+                )),
+                Err(e) => problems.push(format!("Failed to run import probe: {}", e)),
+            }
+        }
+
+        let fallback_ready = version.is_some() && script.is_some() && missing_modules.is_empty();
+        PythonEnvironment {
+            interpreter,
+            version,
+            script_path: script.map(|p| p.to_string_lossy().to_string()),
+            compiled_runner_present,
+            missing_modules,
+            ready: compiled_runner_present || fallback_ready,
+            problems,
+        }
+    })
+    .await
+    .map_err(|e| format!("Python environment check failed: {}", e))
+}
+
+/// How the runner process was launched, as recorded by `start_service_run`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct RunInvocation {
+    /// Executable that was started (service_runner.exe or the Python interpreter)
+    program: String,
+    /// Full argument list, including the plan file and any `--resume-from`
+    args: Vec<String>,
+    /// Environment variables set on top of the app's own environment
+    env: std::collections::BTreeMap<String, String>,
+    /// Working directory of the runner process
+    working_dir: Option<String>,
+}
+
+// Sidecar file next to a plan file that holds its `RunInvocation`.
+fn run_invocation_path(plan_file: &std::path::Path) -> PathBuf {
+    plan_file.with_extension("invocation.json")
+}
+
+// Save how `cmd` is about to be launched next to the plan file. Rewritten on every
+// restart, so it always describes the latest attempt. Failures are only logged.
+fn record_run_invocation(cmd: &StdCommand, plan_file: &std::path::Path) {
+    let lossy = |s: &std::ffi::OsStr| s.to_string_lossy().to_string();
+    let invocation = RunInvocation {
+        program: lossy(cmd.get_program()),
+        args: cmd.get_args().map(lossy).collect(),
+        env: cmd
+            .get_envs()
+            .filter_map(|(k, v)| Some((lossy(k), lossy(v?))))
+            .collect(),
+        // Commands inherit the app's working directory unless one is set explicitly.
+        working_dir: cmd
+            .get_current_dir()
+            .map(|d| d.to_path_buf())
+            .or_else(|| std::env::current_dir().ok())
+            .map(|d| d.to_string_lossy().to_string()),
+    };
+    let result = serde_json::to_string_pretty(&invocation)
+        .map_err(|e| e.to_string())
+        .and_then(|text| crate::fs_utils::write_atomic(&run_invocation_path(plan_file), text));
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to record runner invocation: {}", e);
+    }
+}
+
+/// Returns how the runner was launched for a plan file returned by `start_service_run`.
+///
+/// Includes the resolved runner path, the full argument list, the environment overlay
+/// and the working directory, for debugging runs and attaching to bug reports.
+#[tauri::command]
+fn get_run_invocation(plan_file: String) -> Result<RunInvocation, String> {
+    let path = run_invocation_path(std::path::Path::new(&plan_file));
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| format!("No runner invocation recorded for {}: {}", plan_file, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("Invalid runner invocation file: {}", e))
+}
+
+/// How often a crashed runner is respawned when `auto_restart_runner` is enabled.
+const MAX_RUNNER_RESTARTS: u32 = 2;
+
 /// Starts the Python service runner executable and streams stderr lines as Tauri events.
 /// Frontend listens to `service_runner_line` (payload: {stream, line}) and
 /// `service_runner_done` (payload: { final_report, plan_file, log_file }).
+/// With `auto_restart_runner` enabled, a runner that crashes before finishing the plan
+/// is respawned with `--resume-from <index>` (at most `MAX_RUNNER_RESTARTS` times) and
+/// `runner_restarted` (payload: { attempt, max_restarts, resume_from, total, plan_file })
+/// is emitted.
 /// Returns the plan file path (for reference) immediately after spawning.
 #[tauri::command]
 fn start_service_run(
@@ -106,7 +394,8 @@ fn start_service_run(
     plan_json: String,
 ) -> Result<String, String> {
     // Resolve runner path
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let runner_exe: PathBuf = data_root
         .join("resources")
         .join("bin")
@@ -114,19 +403,11 @@ fn start_service_run(
 
     // Dev fallback: if the compiled runner is missing, try to run the Python script directly.
     // This makes `pnpm tauri dev` usable without PyInstaller.
-    let mut use_python_fallback = false;
     let mut python_script_path: Option<PathBuf> = None;
     if !runner_exe.exists() {
-        // Try to infer repo root from data_root (repo_root/data)
-        if let Some(repo_root) = data_root.parent() {
-            let script = repo_root.join("runner").join("service_runner.py");
-            if script.exists() {
-                use_python_fallback = true;
-                python_script_path = Some(script);
-            }
-        }
+        python_script_path = python_runner_script(data_root);
 
-        if !use_python_fallback {
+        if python_script_path.is_none() {
             return Err(format!(
                 "service_runner.exe not found at {} and Python fallback script was not located. \
                  Expected script path: <repo>/runner/service_runner.py",
@@ -149,83 +430,168 @@ fn start_service_run(
         return Err(format!("Failed to write plan file: {e}"));
     }
     let log_file = plan_file.with_extension("log.txt");
+    let heartbeat_file = plan_file.with_extension("heartbeat");
     let plan_file_for_return = plan_file.clone();
 
+    // Track the run so commands like `open_active_run_log` can find its files.
+    let active_run = state.active_run.clone();
+    let stop_requested = Arc::new(AtomicBool::new(false));
+    {
+        let mut guard = active_run
+            .lock()
+            .map_err(|_| "Active run state is unavailable".to_string())?;
+        if state.operations.is_running(crate::migration::MIGRATION_OP) {
+            let _ = std::fs::remove_file(&plan_file);
+            return Err("Cannot start a run while the data directory is being migrated".into());
+        }
+        *guard = Some(ActiveRun {
+            plan_file: plan_file.clone(),
+            log_file: log_file.clone(),
+            heartbeat_file: heartbeat_file.clone(),
+            pid: None,
+            stop_requested: stop_requested.clone(),
+        });
+    }
+
+    // Optionally keep the machine awake so long unattended runs are not interrupted by sleep
+    let app_settings = crate::settings::read_app_settings(data_root).ok();
+    let setting_enabled = |key: &str| {
+        app_settings
+            .as_ref()
+            .and_then(|v| v.get(key).and_then(|b| b.as_bool()))
+            .unwrap_or(false)
+    };
+    let prevent_sleep = setting_enabled("prevent_sleep_during_run");
+    // Optionally respawn the runner after a crash, resuming after the completed tasks
+    let max_restarts = if setting_enabled("auto_restart_runner") {
+        MAX_RUNNER_RESTARTS
+    } else {
+        0
+    };
+
     let app_handle = app.clone();
     let runner_exe_clone = runner_exe.clone();
     let python_script_clone = python_script_path.clone();
     std::thread::spawn(move || {
-        // Choose command: exe or python fallback
-        let spawn_result = if let Some(script) = python_script_clone.as_ref() {
-            // Prefer PY or PYTHON from PATH; use "python" here
-            StdCommand::new("python")
-                .arg(script)
-                .arg(&plan_file)
-                .arg("--log-file")
-                .arg(&log_file)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-        } else {
-            StdCommand::new(&runner_exe_clone)
-                .arg(&plan_file)
-                .arg("--log-file")
-                .arg(&log_file)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-        };
+        // Held for the lifetime of this thread; dropping it (on any return path) restores sleep.
+        let _keep_awake = prevent_sleep.then(KeepAwakeGuard::new);
 
-        let mut child = match spawn_result {
-            Ok(c) => c,
-            Err(e) => {
-                let which = if python_script_clone.is_some() {
-                    format!(
-                        "Failed to spawn Python runner (python {}): {e}",
-                        python_script_clone.unwrap().display()
-                    )
-                } else {
-                    format!(
-                        "Failed to spawn runner EXE ({}): {e}",
-                        runner_exe_clone.display()
-                    )
-                };
-                let _ = app_handle.emit(
-                    "service_runner_line",
-                    serde_json::json!({"stream":"stderr","line": which}),
-                );
-                return;
+        // (completed, total) task counts from the latest PROGRESS_JSON line
+        let progress = Arc::new(Mutex::new((0usize, 0usize)));
+        let mut restarts = 0u32;
+        let mut resume_from = 0usize;
+
+        let final_stdout = loop {
+            // Choose command: exe or python fallback
+            let mut cmd = if let Some(script) = python_script_clone.as_ref() {
+                let mut c = StdCommand::new(PYTHON_INTERPRETER);
+                c.arg(script);
+                c
+            } else {
+                StdCommand::new(&runner_exe_clone)
+            };
+            cmd.arg(&plan_file).arg("--log-file").arg(&log_file);
+            cmd.arg("--heartbeat-file").arg(&heartbeat_file);
+            if resume_from > 0 {
+                cmd.arg("--resume-from").arg(resume_from.to_string());
             }
-        };
+            record_run_invocation(&cmd, &plan_file);
+            let spawn_result = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn();
 
-        // Stream stderr lines (Python logging)
-        if let Some(stderr) = child.stderr.take() {
-            let app_stderr = app_handle.clone();
-            std::thread::spawn(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines() {
-                    match line {
-                        Ok(l) => {
-                            let _ = app_stderr.emit(
-                                "service_runner_line",
-                                serde_json::json!({"stream":"stderr","line": l}),
-                            );
-                        }
-                        Err(_) => break,
+            let mut child = match spawn_result {
+                Ok(c) => c,
+                Err(e) => {
+                    let which = if python_script_clone.is_some() {
+                        format!(
+                            "Failed to spawn Python runner (python {}): {e}",
+                            python_script_clone.unwrap().display()
+                        )
+                    } else {
+                        format!(
+                            "Failed to spawn runner EXE ({}): {e}",
+                            runner_exe_clone.display()
+                        )
+                    };
+                    let _ = app_handle.emit(
+                        "service_runner_line",
+                        serde_json::json!({"stream":"stderr","line": which}),
+                    );
+                    if let Ok(mut guard) = active_run.lock() {
+                        *guard = None;
                     }
+                    return;
+                }
+            };
+            if let Ok(mut guard) = active_run.lock() {
+                if let Some(run) = guard.as_mut() {
+                    run.pid = Some(child.id());
                 }
+            }
+
+            // Stream stderr lines (Python logging)
+            let stderr_thread = child.stderr.take().map(|stderr| {
+                let app_stderr = app_handle.clone();
+                let progress = progress.clone();
+                std::thread::spawn(move || {
+                    let reader = BufReader::new(stderr);
+                    for line in reader.lines() {
+                        match line {
+                            Ok(l) => {
+                                if let Some(counts) = parse_progress_counts(&l) {
+                                    if let Ok(mut p) = progress.lock() {
+                                        *p = counts;
+                                    }
+                                }
+                                let _ = app_stderr.emit(
+                                    "service_runner_line",
+                                    serde_json::json!({"stream":"stderr","line": l}),
+                                );
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                })
             });
-        }
 
-        // Collect stdout after process exits (used mainly for final JSON)
-        let mut final_stdout = String::new();
-        if let Some(stdout) = child.stdout.take() {
-            // It's fine to read after wait if output is small; read concurrently anyway to be safe
-            let mut buf_reader = BufReader::new(stdout);
-            let _ = buf_reader.read_to_string(&mut final_stdout);
-        }
+            // Collect stdout after process exits (used mainly for final JSON)
+            let mut final_stdout = String::new();
+            if let Some(stdout) = child.stdout.take() {
+                // It's fine to read after wait if output is small; read concurrently anyway to be safe
+                let mut buf_reader = BufReader::new(stdout);
+                let _ = buf_reader.read_to_string(&mut final_stdout);
+            }
+
+            let status = child.wait();
+            if let Some(handle) = stderr_thread {
+                let _ = handle.join();
+            }
+
+            // A crash is a failed exit before every task reported its result
+            let (completed, total) = progress.lock().map(|p| *p).unwrap_or((0, 0));
+            let crashed = status.map(|s| !s.success()).unwrap_or(true);
+            let stopped = stop_requested.load(Ordering::SeqCst);
+            if crashed && !stopped && completed < total && restarts < max_restarts {
+                restarts += 1;
+                resume_from = completed;
+                let _ = app_handle.emit(
+                    "runner_restarted",
+                    serde_json::json!({
+                        "attempt": restarts,
+                        "max_restarts": max_restarts,
+                        "resume_from": resume_from,
+                        "total": total,
+                        "plan_file": plan_file,
+                    }),
+                );
+                continue;
+            }
+            break final_stdout;
+        };
 
-        let _ = child.wait();
+        if let Ok(mut guard) = active_run.lock() {
+            *guard = None;
+        }
+        let _ = std::fs::remove_file(&heartbeat_file);
 
         // Attempt to parse final JSON
         let final_report = match serde_json::from_str::<serde_json::Value>(&final_stdout) {
@@ -245,6 +611,168 @@ fn start_service_run(
     Ok(plan_file_for_return.to_string_lossy().to_string())
 }
 
+// Extract (completed, total) from a runner `PROGRESS_JSON:` log line.
+fn parse_progress_counts(line: &str) -> Option<(usize, usize)> {
+    let payload = &line[line.find("PROGRESS_JSON:")? + "PROGRESS_JSON:".len()..];
+    let value: serde_json::Value = serde_json::from_str(payload.trim()).ok()?;
+    let completed = value.get("completed")?.as_u64()? as usize;
+    let total = value.get("total")?.as_u64()? as usize;
+    Some((completed, total))
+}
+
+/// Opens the execution log of the service run currently in progress.
+///
+/// The log path is taken from `AppState`, so the frontend doesn't need to track it.
+/// Returns an error when no run is active or the runner hasn't created the log yet.
+#[tauri::command]
+fn open_active_run_log(state: tauri::State<AppState>) -> Result<String, String> {
+    let log_file = state
+        .active_run
+        .lock()
+        .map_err(|_| "Active run state is unavailable".to_string())?
+        .as_ref()
+        .map(|run| run.log_file.clone())
+        .ok_or_else(|| "No service run is active".to_string())?;
+    if !log_file.is_file() {
+        return Err(format!(
+            "Run log has not been created yet: {}",
+            log_file.display()
+        ));
+    }
+
+    #[cfg(target_os = "windows")]
+    let opener = "explorer.exe";
+    #[cfg(target_os = "macos")]
+    let opener = "open";
+    #[cfg(target_os = "linux")]
+    let opener = "xdg-open";
+
+    StdCommand::new(opener)
+        .arg(&log_file)
+        .spawn()
+        .map_err(|e| format!("Failed to open log: {}", e))?;
+    Ok(log_file.to_string_lossy().to_string())
+}
+
+/// Seconds since the runner of the active service run last touched its heartbeat file.
+///
+/// The runner refreshes the file every few seconds, so a value well above that
+/// (e.g. 60s) while a run is active means the runner may be stuck. Returns None when
+/// the runner hasn't written its first heartbeat yet, and an error when no run is active.
+#[tauri::command]
+fn get_runner_heartbeat(state: tauri::State<AppState>) -> Result<Option<u64>, String> {
+    let heartbeat_file = state
+        .active_run
+        .lock()
+        .map_err(|_| "Active run state is unavailable".to_string())?
+        .as_ref()
+        .map(|run| run.heartbeat_file.clone())
+        .ok_or_else(|| "No service run is active".to_string())?;
+    Ok(heartbeat_age(&heartbeat_file))
+}
+
+// Seconds since `heartbeat_file` was last modified; None when it doesn't exist yet.
+fn heartbeat_age(heartbeat_file: &std::path::Path) -> Option<u64> {
+    let modified = std::fs::metadata(heartbeat_file)
+        .and_then(|m| m.modified())
+        .ok()?;
+    // A clock skew into the future counts as a fresh heartbeat
+    Some(
+        SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default()
+            .as_secs(),
+    )
+}
+
+/// The service run still in progress, as reported by `recover_active_run`.
+#[derive(Debug, serde::Serialize)]
+struct RecoveredRun {
+    plan_file: String,
+    log_file: String,
+    heartbeat_file: String,
+    /// Process id of the current runner instance, once spawned
+    pid: Option<u32>,
+    /// Seconds since the last runner heartbeat (None before the first one)
+    heartbeat_age_seconds: Option<u64>,
+    /// Whether the runner process still exists
+    alive: bool,
+    /// Whether the runner was terminated by this call
+    stopped: bool,
+}
+
+/// Finds the service run in progress after the frontend lost track of it (e.g. a reload).
+///
+/// Runner output keeps being emitted as `service_runner_line` / `service_runner_done`
+/// events, so the frontend only needs to subscribe again; the log file holds everything
+/// emitted before that. With `stop`, the runner and its child processes are terminated
+/// and not restarted; `service_runner_done` still fires once it has exited.
+///
+/// # Returns
+/// The run's files and liveness, or None when no run is active
+#[tauri::command]
+fn recover_active_run(
+    state: tauri::State<AppState>,
+    stop: Option<bool>,
+) -> Result<Option<RecoveredRun>, String> {
+    let Some(run) = state
+        .active_run
+        .lock()
+        .map_err(|_| "Active run state is unavailable".to_string())?
+        .clone()
+    else {
+        return Ok(None);
+    };
+
+    let mut alive = run.pid.is_some_and(process_alive);
+    let mut stopped = false;
+    if stop.unwrap_or(false) {
+        run.stop_requested.store(true, Ordering::SeqCst);
+        if let Some(pid) = run.pid.filter(|_| alive) {
+            if !terminate_process_tree(pid) {
+                return Err(format!("Failed to stop the runner (pid {})", pid));
+            }
+            stopped = true;
+            alive = false;
+        }
+    }
+
+    Ok(Some(RecoveredRun {
+        plan_file: run.plan_file.to_string_lossy().to_string(),
+        log_file: run.log_file.to_string_lossy().to_string(),
+        heartbeat_file: run.heartbeat_file.to_string_lossy().to_string(),
+        pid: run.pid,
+        heartbeat_age_seconds: heartbeat_age(&run.heartbeat_file),
+        alive,
+        stopped,
+    }))
+}
+
+fn process_alive(pid: u32) -> bool {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut sys = sysinfo::System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+    sys.process(pid).is_some()
+}
+
+// Force-kill a process together with the tools it launched.
+#[cfg(windows)]
+fn terminate_process_tree(pid: u32) -> bool {
+    StdCommand::new("taskkill.exe")
+        .args(["/PID", &pid.to_string(), "/T", "/F"])
+        .output()
+        .map(|out| out.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(not(windows))]
+fn terminate_process_tree(pid: u32) -> bool {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut sys = sysinfo::System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+    sys.process(pid).is_some_and(|p| p.kill())
+}
+
 /// Main entry point for the Tauri application.
 ///
 /// This function sets up the Tauri application with all necessary plugins,
@@ -255,20 +783,24 @@ fn start_service_run(
 /// Panics if the Tauri application fails to run
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    use std::sync::Arc;
-
     // Resolve and ensure the data directory structure exists
     let data_root = crate::paths::resolve_data_dir();
     if let Err(e) = crate::paths::ensure_structure(&data_root) {
         eprintln!("Failed to ensure data structure at {:?}: {}", data_root, e);
     }
+    for check in writable_dir_checks(&data_root) {
+        if let Some(err) = check.error {
+            eprintln!(
+                "Directory not writable ({}): {} - {}",
+                check.name, check.path, err
+            );
+        }
+    }
 
     // Build the Tauri application with plugins and state
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init()) // Shell plugin for running external commands
-        .manage(AppState {
-            data_dir: Arc::new(data_root), // Manage application state with data directory
-        })
+        .manage(AppState::new(data_root)) // Manage application state with data directory
         .plugin(tauri_plugin_opener::init()) // Opener plugin for opening files/URLs
         .plugin(tauri_plugin_dialog::init()) // Dialog plugin for file/folder dialogs
         .plugin(tauri_plugin_notification::init()) // Notification plugin for desktop toasts
@@ -276,47 +808,143 @@ pub fn run() {
             // List of all Tauri commands exposed to the frontend
             greet,
             launch_shortcut,
+            list_shortcut_macros,
+            save_shortcut_macro,
+            remove_shortcut_macro,
+            run_shortcut_macro,
             get_data_dirs,
+            check_writable_dirs,
+            check_python_environment,
+            migrate_data_dir,
             start_service_run,
+            get_run_invocation,
+            open_active_run_log,
+            get_runner_heartbeat,
+            recover_active_run,
+            cancel_all_operations,
             list_programs,
             save_program,
+            check_program_duplicate,
+            list_broken_entries,
+            prune_broken_entries,
             remove_program,
             launch_program,
             get_tool_statuses,
+            get_tool_statuses_grouped,
+            set_tool_override,
+            clear_tool_override,
+            run_tool,
+            list_tool_companions,
+            get_plan_tool_dependencies,
             list_scripts,
             save_script,
             remove_script,
             run_script,
+            script_requires_elevation,
+            preview_script,
+            check_script_syntax,
+            cache_script,
+            refresh_cached_script,
             suggest_logo_from_exe,
             read_image_as_data_url,
             get_system_info,
+            get_system_info_fast,
+            get_system_extra,
+            diff_system_info,
+            get_reliability_history,
+            get_gpu_report,
+            get_machine_fingerprint,
+            get_bitlocker_status,
+            get_public_network_info,
+            is_elevated,
+            format_duration_human,
+            format_boot_time,
+            get_system_summary_text,
+            record_system_snapshot,
+            load_system_history,
+            sample_disk_io,
+            start_system_monitor,
+            stop_system_monitor,
             load_app_settings,
             save_app_settings,
+            get_setting,
+            set_setting,
+            get_last_network_path,
+            set_last_network_path,
+            list_recent_network_paths,
+            start_watching_settings,
+            stop_watching_settings,
             make_portable_path,
             resolve_portable_path,
+            export_settings_profile,
+            import_settings_profile,
             save_report,
+            preview_report_folder_name,
             list_reports,
+            list_reports_paged,
             load_report,
             load_report_from_path,
             delete_report,
+            repair_report_metadata,
             open_report_folder,
+            verify_report,
+            verify_all_reports,
+            cancel_verify_all_reports,
+            tail_report_log,
+            read_report_log_tail,
+            search_report_log,
+            get_report_content_hash,
+            find_duplicate_reports,
+            format_report_json,
+            diff_reports,
+            export_report_zip,
+            export_reports_csv,
+            compute_plan_progress_weights,
+            import_report,
+            set_report_notes,
+            compress_report_logs,
+            add_report_note,
             // Network report sharing
             save_report_to_network,
+            sync_reports_to_network,
+            copy_file_to_network,
             list_network_reports,
+            find_reports_missing_on_network,
+            delete_network_report,
             test_network_path,
-            open_absolute_path
+            canonicalize_unc_path,
+            open_absolute_path,
+            generate_report_qr,
+            // Report documentation
+            capture_screenshot,
+            get_clipboard_text,
+            set_clipboard_text,
+            // Power management
+            get_power_plan,
+            set_power_plan,
+            get_sleep_settings,
+            set_sleep_settings,
+            check_pending_reboot,
+            schedule_restart,
+            schedule_shutdown,
+            // Process management
+            kill_processes_by_name,
+            close_browser,
+            get_top_processes,
+            // Benchmarks
+            benchmark_disk,
+            benchmark_memory,
+            detect_thermal_throttling,
+            // Software audit
+            list_installed_fonts,
+            get_environment_variables,
+            get_windows_activation_status,
+            list_network_shares
         ])
         .setup(|app| {
             // Setup function called after the app is initialized
             // Configure WebView2 user data folder for persistence in portable mode
-            if let Some(data_dir_str) = app
-                .state::<AppState>()
-                .inner()
-                .clone()
-                .data_dir
-                .as_ref()
-                .to_str()
-            {
+            if let Some(data_dir_str) = app.state::<AppState>().data_dir().to_str() {
                 let webview_profile = std::path::Path::new(data_dir_str).join("webview_profile");
                 if std::fs::create_dir_all(&webview_profile).is_ok() {
                     std::env::set_var("WEBVIEW2_USER_DATA_FOLDER", &webview_profile);
@@ -324,6 +952,12 @@ pub fn run() {
             }
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Stop any background copies, listings or sweeps before the process exits
+            if let tauri::RunEvent::Exit = event {
+                app.state::<AppState>().operations.cancel_all();
+            }
+        });
 }
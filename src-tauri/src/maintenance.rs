@@ -0,0 +1,99 @@
+//! Maintenance commands spanning saved programs and scripts.
+//!
+//! Responsibilities:
+//! - Find program and script entries whose files no longer exist (e.g. after tools
+//!   were moved or deleted on the USB drive)
+//! - Prune those dead entries in one pass
+use std::{collections::HashSet, path::Path};
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{programs, scripts, state::AppState};
+
+/// A saved program or script whose file is missing.
+#[derive(Debug, Clone, Serialize)]
+pub struct BrokenEntry {
+    /// "program" or "script"
+    pub kind: String,
+    pub id: Uuid,
+    pub name: String,
+    /// Absolute path that was checked
+    pub resolved_path: String,
+}
+
+#[tauri::command]
+/// List programs whose executable and file-source scripts whose script file is missing.
+///
+/// Paths are resolved the same way as in `list_programs` and `list_scripts`.
+pub fn list_broken_entries(state: tauri::State<AppState>) -> Result<Vec<BrokenEntry>, String> {
+    Ok(find_broken_entries(&state.data_dir()))
+}
+
+#[tauri::command]
+/// Remove every entry reported by `list_broken_entries` from `programs.json` and `scripts.json`.
+///
+/// With `dry_run` set nothing is written, so the returned list can be reviewed first.
+/// Returns the entries that were (or would be) removed.
+pub fn prune_broken_entries(
+    state: tauri::State<AppState>,
+    dry_run: bool,
+) -> Result<Vec<BrokenEntry>, String> {
+    let data_root = state.data_dir();
+    let broken = find_broken_entries(&data_root);
+    if dry_run || broken.is_empty() {
+        return Ok(broken);
+    }
+
+    let ids_of = |kind: &str| -> HashSet<Uuid> {
+        broken
+            .iter()
+            .filter(|e| e.kind == kind)
+            .map(|e| e.id)
+            .collect()
+    };
+    let (program_ids, script_ids) = (ids_of("program"), ids_of("script"));
+
+    if !program_ids.is_empty() {
+        let path = programs::programs_json_path(&data_root);
+        let mut list = programs::read_programs_file(&path);
+        list.retain(|p| !program_ids.contains(&p.id));
+        programs::write_programs_file(&path, &list)?;
+    }
+    if !script_ids.is_empty() {
+        let path = scripts::scripts_json_path(&data_root);
+        let mut list = scripts::read_scripts_file(&path);
+        list.retain(|s| !script_ids.contains(&s.id));
+        scripts::write_scripts_file(&path, &list)?;
+    }
+    Ok(broken)
+}
+
+// Collect broken programs first, then broken scripts, each in stored order.
+fn find_broken_entries(data_root: &Path) -> Vec<BrokenEntry> {
+    let programs = programs::read_programs_file(&programs::programs_json_path(data_root));
+    let scripts = scripts::read_scripts_file(&scripts::scripts_json_path(data_root));
+
+    let broken_programs = programs.into_iter().filter_map(|p| {
+        let resolved = programs::resolve_exe_path(data_root, &p.exe_path);
+        (!Path::new(&resolved).is_file()).then(|| BrokenEntry {
+            kind: "program".into(),
+            id: p.id,
+            name: p.name,
+            resolved_path: resolved,
+        })
+    });
+    let broken_scripts = scripts
+        .into_iter()
+        .filter(|s| s.source == "file")
+        .filter_map(|s| {
+            let resolved = scripts::resolve_script_path(data_root, &s.path);
+            (!resolved.is_file()).then(|| BrokenEntry {
+                kind: "script".into(),
+                id: s.id,
+                name: s.name,
+                resolved_path: resolved.to_string_lossy().to_string(),
+            })
+        });
+    broken_programs.chain(broken_scripts).collect()
+}
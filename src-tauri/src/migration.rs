@@ -0,0 +1,240 @@
+//! Relocation of the portable data directory.
+//!
+//! Copies the whole data tree to a new location, verifies the copy, switches the
+//! running app over to it and optionally removes the old tree.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::state::AppState;
+
+/// Operation kind registered while a migration copies the data tree; service runs
+/// refuse to start until it finishes.
+pub(crate) const MIGRATION_OP: &str = "data_migration";
+
+/// Folders that are not migrated: the WebView profile is locked by the running
+/// app and is recreated on the next start.
+const SKIPPED_DIRS: &[&str] = &["webview_profile"];
+
+/// Outcome of `migrate_data_dir`.
+#[derive(Debug, Serialize)]
+pub struct MigrationSummary {
+    pub old_path: String,
+    pub new_path: String,
+    pub files_copied: u64,
+    pub bytes_copied: u64,
+    /// True when the old data directory was removed after migrating (apart from the
+    /// in-use WebView profile folder)
+    pub old_removed: bool,
+    /// Non-fatal problems, e.g. files of the old tree that could not be deleted
+    pub warnings: Vec<String>,
+}
+
+#[tauri::command]
+/// Move the data directory to `new_path` and switch the running app to it.
+///
+/// The target must not exist or must be an empty directory. Every file is copied and
+/// its size verified before the app's data directory is updated. Refuses to run while
+/// a service run is active, and no run can start until the migration is done.
+///
+/// The new location is recorded in `data_location.txt` next to the executable so it is
+/// used on later starts too. When that file can't be written (or `AUTOSERVICE_DATA_DIR`
+/// overrides it), the old tree is kept even if `remove_old` is set, and a warning says so.
+///
+/// # Arguments
+/// * `new_path` - Destination directory for the data tree
+/// * `remove_old` - Delete the old data directory after a successful migration
+pub async fn migrate_data_dir(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    new_path: String,
+    remove_old: Option<bool>,
+) -> Result<MigrationSummary, String> {
+    // Registered under the run lock so `start_service_run` sees either the run or the
+    // migration, never neither.
+    let _op = {
+        let run = state
+            .active_run
+            .lock()
+            .map_err(|_| "Active run state is unavailable".to_string())?;
+        if run.is_some() {
+            return Err("Cannot migrate the data directory while a service run is active".into());
+        }
+        if state.operations.is_running(MIGRATION_OP) {
+            return Err("A data directory migration is already in progress".into());
+        }
+        state.operations.register(MIGRATION_OP)
+    };
+
+    let old_root = state.data_dir().as_ref().clone();
+    let new_root = PathBuf::from(new_path.trim());
+    if new_path.trim().is_empty() || !new_root.is_absolute() {
+        return Err("The new data directory must be an absolute path".into());
+    }
+    if new_root.starts_with(&old_root) || old_root.starts_with(&new_root) {
+        return Err(
+            "The new data directory must not be inside the current one (or vice versa)".into(),
+        );
+    }
+    if new_root.exists() {
+        let empty = fs::read_dir(&new_root)
+            .map(|mut entries| entries.next().is_none())
+            .map_err(|e| format!("Cannot read {}: {}", new_root.display(), e))?;
+        if !empty {
+            return Err(format!(
+                "Target directory is not empty: {}",
+                new_root.display()
+            ));
+        }
+    }
+
+    let (old, new) = (old_root.clone(), new_root.clone());
+    let (files_copied, bytes_copied) =
+        tauri::async_runtime::spawn_blocking(move || -> Result<(u64, u64), String> {
+            let mut totals = (0u64, 0u64);
+            copy_tree(&old, &new, true, &mut totals)?;
+            verify_tree(&old, &new, true)?;
+            crate::paths::ensure_structure(&new)
+                .map_err(|e| format!("Failed to prepare {}: {}", new.display(), e))?;
+            Ok(totals)
+        })
+        .await
+        .map_err(|e| format!("Migration worker failed: {}", e))??;
+
+    let mut warnings = Vec::new();
+    let persisted = match persist_data_dir(&new_root) {
+        Ok(()) => true,
+        Err(e) => {
+            warnings.push(e);
+            false
+        }
+    };
+    state.set_data_dir(new_root.clone());
+    crate::settings::restart_settings_watcher(&app, &state);
+
+    let mut old_removed = false;
+    if remove_old.unwrap_or(false) {
+        if persisted {
+            remove_tree_except_skipped(&old_root, &mut warnings);
+            old_removed = warnings.is_empty();
+        } else {
+            warnings.push(format!(
+                "Kept the old data directory because the new location is not remembered \
+                 across restarts: {}",
+                old_root.display()
+            ));
+        }
+    }
+
+    Ok(MigrationSummary {
+        old_path: old_root.to_string_lossy().to_string(),
+        new_path: new_root.to_string_lossy().to_string(),
+        files_copied,
+        bytes_copied,
+        old_removed,
+        warnings,
+    })
+}
+
+// Record `new_root` in the pointer file that `paths::resolve_data_dir` reads at startup.
+fn persist_data_dir(new_root: &Path) -> Result<(), String> {
+    if let Some(env_dir) = std::env::var_os("AUTOSERVICE_DATA_DIR") {
+        if Path::new(&env_dir) != new_root {
+            return Err(format!(
+                "AUTOSERVICE_DATA_DIR ({}) overrides the new location on the next start",
+                Path::new(&env_dir).display()
+            ));
+        }
+    }
+    let pointer = crate::paths::data_dir_pointer_path()
+        .ok_or("Cannot locate the executable folder to record the new location")?;
+    crate::fs_utils::write_atomic(&pointer, new_root.to_string_lossy().as_bytes()).map_err(|e| {
+        format!(
+            "Failed to record the new location in {}: {}",
+            pointer.display(),
+            e
+        )
+    })
+}
+
+// Recursively copy `src` into `dst`, accumulating (files, bytes).
+fn copy_tree(src: &Path, dst: &Path, top: bool, totals: &mut (u64, u64)) -> Result<(), String> {
+    fs::create_dir_all(dst).map_err(|e| format!("Cannot create {}: {}", dst.display(), e))?;
+    let entries = fs::read_dir(src).map_err(|e| format!("Cannot read {}: {}", src.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Cannot read {}: {}", src.display(), e))?;
+        let path = entry.path();
+        let name = entry.file_name();
+        if top && SKIPPED_DIRS.iter().any(|s| name == *s) {
+            continue;
+        }
+        let target = dst.join(&name);
+        if path.is_dir() {
+            copy_tree(&path, &target, false, totals)?;
+        } else {
+            let bytes = fs::copy(&path, &target).map_err(|e| {
+                format!(
+                    "Failed to copy {} -> {}: {}",
+                    path.display(),
+                    target.display(),
+                    e
+                )
+            })?;
+            totals.0 += 1;
+            totals.1 += bytes;
+        }
+    }
+    Ok(())
+}
+
+// Check every file under `src` exists under `dst` with the same size.
+fn verify_tree(src: &Path, dst: &Path, top: bool) -> Result<(), String> {
+    let entries = fs::read_dir(src).map_err(|e| format!("Cannot read {}: {}", src.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        if top && SKIPPED_DIRS.iter().any(|s| name == *s) {
+            continue;
+        }
+        let target = dst.join(&name);
+        if path.is_dir() {
+            verify_tree(&path, &target, false)?;
+            continue;
+        }
+        let src_len = fs::metadata(&path).map(|m| m.len()).ok();
+        let dst_len = fs::metadata(&target).map(|m| m.len()).ok();
+        if dst_len.is_none() || src_len != dst_len {
+            return Err(format!(
+                "Verification failed for {}: copy is missing or has a different size",
+                target.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Delete the old data tree, leaving folders the running app still holds open.
+fn remove_tree_except_skipped(root: &Path, warnings: &mut Vec<String>) {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(e) => {
+            warnings.push(format!("Cannot read {}: {}", root.display(), e));
+            return;
+        }
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if SKIPPED_DIRS.iter().any(|s| entry.file_name() == *s) {
+            continue;
+        }
+        let result = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        if let Err(e) = result {
+            warnings.push(format!("Could not remove {}: {}", path.display(), e));
+        }
+    }
+}
@@ -72,6 +72,13 @@ pub struct SystemInfo {
     /// Optional bucket for platform-specific extra information gathered via shell commands.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extra: Option<ExtraInfo>,
+    /// Unit of the sensor, CPU and battery temperature values: "C" (default) or "F"
+    #[serde(default = "default_temperature_unit")]
+    pub temperature_unit: String,
+}
+
+fn default_temperature_unit() -> String {
+    "C".to_string()
 }
 
 /// Detailed information about the system's central processing unit(s).
@@ -90,6 +97,10 @@ pub struct CpuInfo {
     pub num_logical_cpus: usize,
     /// Detailed information for each CPU core
     pub cores: Vec<CpuCoreInfo>,
+    /// CPU temperature picked from the hardware sensors by label (see
+    /// `CPU_SENSOR_LABELS` in system.rs); None when no sensor was recognised
+    #[serde(default)]
+    pub cpu_temperature_c: Option<f32>,
 }
 
 /// Information about an individual CPU core.
@@ -146,6 +157,14 @@ pub struct DiskInfo {
     pub read_bytes: u64,
     /// Total bytes written since boot
     pub written_bytes: u64,
+    /// Overall SMART verdict from smartctl ("PASSED" or "FAILED"); None when smartctl
+    /// isn't available or the drive doesn't report it
+    #[serde(default)]
+    pub smart_health: Option<String>,
+    /// Raw SMART attributes from smartctl's JSON output (ATA attribute table or NVMe
+    /// health log)
+    #[serde(default)]
+    pub smart_attributes: Option<serde_json::Value>,
 }
 
 /// Network interface information and statistics.
@@ -172,6 +191,12 @@ pub struct NetworkInfo {
     pub errors_rx: u64,
     /// Number of transmit errors
     pub errors_tx: u64,
+    /// Default gateway of the interface (Windows only; lowest-metric default route)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_gateway: Option<String>,
+    /// DNS servers configured on the interface (Windows only)
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
 }
 
 /// Graphics processing unit information.
@@ -192,6 +217,45 @@ pub struct GpuInfo {
     pub driver_info: Option<String>,
     /// Graphics backend (e.g., "Vulkan", "OpenGL", "DirectX")
     pub backend: Option<String>,
+    /// Dedicated video memory in bytes (WMI `AdapterRAM`, or nvidia-smi when larger)
+    #[serde(default)]
+    pub vram_bytes: Option<u64>,
+    /// Current GPU load percentage (NVIDIA GPUs only, via nvidia-smi)
+    #[serde(default)]
+    pub utilization_percent: Option<f32>,
+}
+
+/// Consolidated per-GPU details returned by `get_gpu_report`.
+/// Merges the wgpu adapter, Windows `Win32_VideoController` data and nvidia-smi stats.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuReport {
+    /// GPU model name
+    pub name: String,
+    /// Adapter details from wgpu, when the GPU was enumerated there
+    pub adapter: Option<GpuInfo>,
+    /// Dedicated video memory in bytes from WMI (capped at 4 GiB by `AdapterRAM`)
+    pub vram_bytes: Option<u64>,
+    /// Driver version reported by Windows (e.g., "31.0.15.3713")
+    pub driver_version: Option<String>,
+    /// Current video mode (e.g., "2560 x 1440 x 4294967296 colors")
+    pub video_mode: Option<String>,
+    /// Live statistics from nvidia-smi (NVIDIA GPUs only)
+    pub nvidia: Option<NvidiaGpuStats>,
+}
+
+/// Live NVIDIA GPU statistics read from nvidia-smi.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NvidiaGpuStats {
+    /// GPU utilization percentage
+    pub utilization_percent: Option<f32>,
+    /// Used video memory in MiB
+    pub memory_used_mb: Option<u64>,
+    /// Total video memory in MiB
+    pub memory_total_mb: Option<u64>,
+    /// GPU core temperature in Celsius
+    pub temperature_c: Option<f32>,
+    /// NVIDIA driver version
+    pub driver_version: Option<String>,
 }
 
 /// Hardware sensor information, typically temperature readings.
@@ -200,7 +264,7 @@ pub struct GpuInfo {
 pub struct SensorInfo {
     /// Sensor label/description (e.g., "CPU Package", "GPU Core")
     pub label: String,
-    /// Temperature reading in Celsius
+    /// Temperature reading in Celsius (Fahrenheit when `SystemInfo.temperature_unit` is "F")
     pub temperature_c: f32,
 }
 
@@ -232,7 +296,7 @@ pub struct BatteryInfo {
     pub energy_full_design_wh: Option<f32>,
     /// Current voltage in volts
     pub voltage_v: Option<f32>,
-    /// Battery temperature in Celsius
+    /// Battery temperature in Celsius (Fahrenheit when `SystemInfo.temperature_unit` is "F")
     pub temperature_c: Option<f32>,
     /// Estimated time to full charge in seconds
     pub time_to_full_sec: Option<u64>,
@@ -312,6 +376,9 @@ pub struct ExtraInfo {
     /// List of installed Windows hotfixes/updates
     #[serde(default)]
     pub hotfixes: Vec<String>,
+    /// Installed hotfixes with their description and install date, newest first
+    #[serde(default)]
+    pub hotfixes_detailed: Vec<Hotfix>,
     /// List of video controller descriptions
     #[serde(default)]
     pub video_controllers: Vec<String>,
@@ -342,6 +409,185 @@ pub struct ExtraInfo {
     /// Computer system information as JSON objects
     #[serde(default)]
     pub computer_system: Vec<serde_json::Value>,
+    /// Whether Windows has a reboot pending; None when the registry checks failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_reboot: Option<bool>,
+    /// Why a reboot is pending (see `check_pending_reboot`)
+    #[serde(default)]
+    pub pending_reboot_reasons: Vec<String>,
+    /// Windows license status with the partial product key,
+    /// e.g. "Licensed (key ending 3V66T)"; None when the query failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub windows_activation: Option<String>,
+}
+
+/// An installed Windows update as reported by `Get-HotFix`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hotfix {
+    /// KB identifier (e.g., "KB5034441")
+    pub hotfix_id: String,
+    /// Update type (e.g., "Security Update", "Update")
+    pub description: Option<String>,
+    /// Install date as YYYY-MM-DD, when Windows recorded one
+    pub installed_on: Option<String>,
+}
+
+/// A trimmed system sample stored by `record_system_snapshot` for trend sparklines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    /// When the sample was taken (seconds since the Unix epoch)
+    pub timestamp: u64,
+    /// Average usage across all logical CPUs (0-100)
+    pub cpu_usage_percent: f32,
+    /// Used memory in bytes
+    pub memory_used: u64,
+    /// Total memory in bytes
+    pub memory_total: u64,
+    /// Hottest sensor reading in Celsius; None when no sensor reports a temperature
+    pub max_temperature_c: Option<f32>,
+}
+
+/// Differences between two `SystemInfo` snapshots (later minus earlier).
+/// Produced by `diff_system_info` to show what changed during a long session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemInfoDiff {
+    /// Uptime difference in seconds (negative if the machine rebooted in between)
+    pub uptime_delta_seconds: i64,
+    /// True when the boot times differ by more than 60 seconds, i.e. the machine restarted
+    /// between snapshots (boot time is derived from uptime, so small jitter is ignored)
+    pub rebooted: bool,
+    /// Change in used memory in bytes
+    pub memory_used_delta: i64,
+    /// Change in available memory in bytes
+    pub memory_available_delta: i64,
+    /// Change in used swap in bytes
+    pub swap_used_delta: i64,
+    /// Free-space changes for disks present in both snapshots
+    pub disks: Vec<DiskDelta>,
+    /// Mount points present only in the later snapshot
+    pub added_disks: Vec<String>,
+    /// Mount points present only in the earlier snapshot
+    pub removed_disks: Vec<String>,
+    /// Network interfaces present only in the later snapshot
+    pub added_networks: Vec<String>,
+    /// Network interfaces present only in the earlier snapshot
+    pub removed_networks: Vec<String>,
+    /// Temperature changes for sensors present in both snapshots
+    pub temperatures: Vec<TemperatureDelta>,
+}
+
+/// Free-space change of a single disk between two snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskDelta {
+    /// Mount point identifying the disk (e.g., "C:\\")
+    pub mount_point: String,
+    /// Change in available space in bytes (negative means the disk filled up)
+    pub available_delta: i64,
+}
+
+/// Read/write rate of a single disk, as returned by `sample_disk_io`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskIoRate {
+    /// Disk name as reported by the OS
+    pub name: String,
+    /// Mount point identifying the disk (e.g., "C:\\")
+    pub mount_point: String,
+    /// Bytes read per second over the sample interval
+    pub read_bytes_per_sec: f64,
+    /// Bytes written per second over the sample interval
+    pub written_bytes_per_sec: f64,
+}
+
+/// Temperature change of a single sensor between two snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemperatureDelta {
+    /// Sensor label
+    pub label: String,
+    /// Temperature in the earlier snapshot (Celsius)
+    pub before_c: f32,
+    /// Temperature in the later snapshot (Celsius)
+    pub after_c: f32,
+    /// `after_c - before_c`
+    pub delta_c: f32,
+}
+
+/// Windows Reliability Monitor history returned by `get_reliability_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityHistory {
+    /// System stability index over time, oldest first
+    pub stability_index: Vec<StabilityPoint>,
+    /// Reliability events, newest first
+    pub events: Vec<ReliabilityEvent>,
+}
+
+/// A single stability index reading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StabilityPoint {
+    /// ISO 8601 timestamp of the reading
+    pub time: String,
+    /// Stability index from 1 (unstable) to 10 (stable)
+    pub index: f64,
+}
+
+/// A Reliability Monitor event (crash, hang, hardware error, install, ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReliabilityEvent {
+    /// ISO 8601 timestamp of the event
+    pub time: String,
+    /// Category: "app_crash", "app_hang", "hardware_failure", "unexpected_shutdown",
+    /// "install", "windows_update" or "other"
+    pub kind: String,
+    /// Event source name
+    pub source: String,
+    /// Product the event relates to, if any
+    pub product: String,
+    /// Event identifier
+    pub event_id: i64,
+    /// Event message text
+    pub message: String,
+}
+
+/// Stable hardware identifier returned by `get_machine_fingerprint`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MachineFingerprint {
+    /// SHA-256 (hex) over the available components
+    pub fingerprint: String,
+    /// Motherboard serial number
+    pub motherboard_serial: Option<String>,
+    /// System product UUID
+    pub product_uuid: Option<String>,
+    /// Serial number of the disk holding the system drive
+    pub disk_serial: Option<String>,
+    /// Processor ID reported by WMI
+    pub cpu_id: Option<String>,
+    /// Components included in the hash ("motherboard_serial", "product_uuid", ...)
+    pub components_used: Vec<String>,
+}
+
+/// BitLocker state of one volume, as returned by `get_bitlocker_status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitLockerVolume {
+    /// Drive letter (e.g., "C:"), or the volume ID for volumes without one
+    pub drive: String,
+    /// Whether BitLocker protection is on
+    pub protection_on: bool,
+    /// Percentage of the volume that is encrypted (0-100), if known
+    pub encryption_percentage: Option<f64>,
+    /// "locked", "unlocked" or "unknown"
+    pub lock_status: String,
+}
+
+/// Public (WAN) address details, as returned by `get_public_network_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicNetworkInfo {
+    /// Public IP address seen by the lookup service
+    pub public_ip: String,
+    /// Internet provider / organisation (e.g., "AS1221 Telstra Limited")
+    pub isp: Option<String>,
+    /// City reported by the lookup service
+    pub city: Option<String>,
+    /// Country reported by the lookup service
+    pub country: Option<String>,
 }
 
 /// Information about an installed program or application.
@@ -366,6 +612,9 @@ pub struct ProgramEntry {
     /// Number of times the program has been launched from the app
     #[serde(default)]
     pub launch_count: u32,
+    /// Window state to launch with: "normal" (default), "minimized" or "maximized"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_state: Option<String>,
 }
 
 /// Disk-persisted version of program information.
@@ -387,6 +636,9 @@ pub struct ProgramDiskEntry {
     /// Persisted launch counter (default to 0 when missing in older files)
     #[serde(default)]
     pub launch_count: u32,
+    /// Window state to launch with ("normal", "minimized" or "maximized")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_state: Option<String>,
 }
 
 /// Status information for external tools used by the application.
@@ -405,6 +657,52 @@ pub struct ToolStatus {
     /// Hint or instruction for installing the tool if missing
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hint: Option<String>,
+    /// Tool category ("cleanup", "antivirus", "benchmark", "diagnostic" or "driver")
+    #[serde(default)]
+    pub category: String,
+}
+
+/// Tool statuses of one category, as returned by `get_tool_statuses_grouped`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCategoryGroup {
+    /// Category name
+    pub category: String,
+    /// Number of tools in this category that were found
+    pub present: usize,
+    /// Number of tools in this category
+    pub total: usize,
+    /// Tools in this category, in the same order as `get_tool_statuses`
+    pub tools: Vec<ToolStatus>,
+}
+
+/// An external tool required by a service plan, with its availability.
+/// Returned by `get_plan_tool_dependencies` as a pre-flight check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanToolDependency {
+    /// Tool key (as used by `get_tool_statuses`)
+    pub key: String,
+    /// Display name of the tool
+    pub name: String,
+    /// Task types in the plan that need this tool
+    pub task_types: Vec<String>,
+    /// Whether the tool executable was found
+    pub exists: bool,
+    /// Resolved path to the tool executable (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Result of running a built-in tool with `run_tool`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolRunOutput {
+    /// Exit code; None when the process was killed (timeout or cancellation)
+    pub code: Option<i32>,
+    /// Captured standard output (lossily decoded, capped at 1 MiB)
+    pub stdout: String,
+    /// Captured standard error (lossily decoded, capped at 1 MiB)
+    pub stderr: String,
+    /// True when the tool was killed for exceeding the timeout
+    pub timed_out: bool,
 }
 
 /// Information about a script that can be executed by the application.
@@ -439,3 +737,12 @@ pub struct ScriptEntry {
     #[serde(default, skip_serializing)]
     pub path_exists: bool,
 }
+
+/// A named sequence of shortcut ids launched together by `run_shortcut_macro`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutMacro {
+    /// Display name (unique, case-insensitive)
+    pub name: String,
+    /// Shortcut ids (as accepted by `launch_shortcut`) in launch order
+    pub ids: Vec<String>,
+}
@@ -0,0 +1,116 @@
+//! Registry of cancellable background operations.
+//!
+//! Long-running commands (report sweeps, network copies and listings, samplers)
+//! register themselves here while their worker runs. Each registration hands out
+//! a [`CancelToken`] the worker polls between units of work, and a guard that
+//! unregisters the operation when dropped. `cancel_all_operations` and the app's
+//! exit handler use the registry to stop anything still running.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::state::AppState;
+
+/// Shared flag a worker polls to learn it should stop.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Returns true once cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Requests cancellation.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Tracks background operations currently in flight.
+#[derive(Default)]
+pub struct OperationRegistry {
+    next_id: AtomicU64,
+    ops: Mutex<HashMap<u64, (String, CancelToken)>>,
+}
+
+impl OperationRegistry {
+    /// Registers a new operation of the given kind (e.g. "network_copy").
+    ///
+    /// The returned guard exposes the operation's token and unregisters it on drop.
+    pub fn register(self: &Arc<Self>, kind: &str) -> OperationGuard {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let token = CancelToken::default();
+        if let Ok(mut ops) = self.ops.lock() {
+            ops.insert(id, (kind.to_string(), token.clone()));
+        }
+        OperationGuard {
+            registry: Arc::clone(self),
+            id,
+            token,
+        }
+    }
+
+    /// Cancels every registered operation of `kind`, returning how many were signalled.
+    pub fn cancel_kind(&self, kind: &str) -> usize {
+        self.ops
+            .lock()
+            .map(|ops| {
+                ops.values()
+                    .filter(|(k, _)| k == kind)
+                    .inspect(|(_, token)| token.cancel())
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
+    /// Returns true while at least one operation of `kind` is registered.
+    pub fn is_running(&self, kind: &str) -> bool {
+        self.ops
+            .lock()
+            .map(|ops| ops.values().any(|(k, _)| k == kind))
+            .unwrap_or(false)
+    }
+
+    /// Cancels every registered operation, returning how many were signalled.
+    pub fn cancel_all(&self) -> usize {
+        self.ops
+            .lock()
+            .map(|ops| {
+                ops.values().for_each(|(_, token)| token.cancel());
+                ops.len()
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Registration handle for a running operation; unregisters on drop.
+pub struct OperationGuard {
+    registry: Arc<OperationRegistry>,
+    id: u64,
+    token: CancelToken,
+}
+
+impl OperationGuard {
+    /// Token the worker should poll for cancellation.
+    pub fn token(&self) -> CancelToken {
+        self.token.clone()
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        if let Ok(mut ops) = self.registry.ops.lock() {
+            ops.remove(&self.id);
+        }
+    }
+}
+
+#[tauri::command]
+/// Cancel every background operation still running (copies, listings, sweeps).
+///
+/// Workers stop at their next cancellation check. Returns the number of
+/// operations that were signalled.
+pub fn cancel_all_operations(state: tauri::State<AppState>) -> Result<usize, String> {
+    Ok(state.operations.cancel_all())
+}
@@ -9,6 +9,17 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// File next to the executable recording a data directory moved by `migrate_data_dir`.
+pub const DATA_DIR_POINTER_FILE: &str = "data_location.txt";
+
+/// Path of the data directory pointer file next to the running executable.
+pub fn data_dir_pointer_path() -> Option<PathBuf> {
+    env::current_exe()
+        .ok()?
+        .parent()
+        .map(|dir| dir.join(DATA_DIR_POINTER_FILE))
+}
+
 /// Checks whether a path exists and is a directory.
 fn exists_dir(p: &Path) -> bool {
     p.is_dir()
@@ -19,11 +30,13 @@ fn exists_dir(p: &Path) -> bool {
 /// Resolution order:
 /// 1. **Environment variable override** via `AUTOSERVICE_DATA_DIR`.
 ///    - Useful for both dev and prod custom setups.
-/// 2. **Sibling `data` folder** located next to the executable.
+/// 2. **Pointer file** (`data_location.txt`) next to the executable.
+///    - Written when the data directory is moved with `migrate_data_dir`.
+/// 3. **Sibling `data` folder** located next to the executable.
 ///    - Default deployment mode when running from a USB.
-/// 3. **Dev fallback**: repo root `data` folder.
+/// 4. **Dev fallback**: repo root `data` folder.
 ///    - Uses `CARGO_MANIFEST_DIR` (which points to `src-tauri`).
-/// 4. **Last resort**: `./data` under the current working directory.
+/// 5. **Last resort**: `./data` under the current working directory.
 ///
 /// # Returns
 /// A [`PathBuf`] pointing to the resolved `data` directory.
@@ -37,7 +50,17 @@ pub fn resolve_data_dir() -> PathBuf {
         }
     }
 
-    // 2) Sibling 'data' folder next to executable (USB deployment)
+    // 2) Location recorded by a data directory migration
+    if let Some(pointer) = data_dir_pointer_path() {
+        if let Ok(text) = std::fs::read_to_string(pointer) {
+            let p = PathBuf::from(text.trim());
+            if !text.trim().is_empty() && exists_dir(&p) {
+                return p;
+            }
+        }
+    }
+
+    // 3) Sibling 'data' folder next to executable (USB deployment)
     if let Ok(exe) = env::current_exe() {
         if let Some(dir) = exe.parent() {
             let p = dir.join("data");
@@ -47,7 +70,7 @@ pub fn resolve_data_dir() -> PathBuf {
         }
     }
 
-    // 3) Dev fallback: repo root data folder (src-tauri is one level below root).
+    // 4) Dev fallback: repo root data folder (src-tauri is one level below root).
     // CARGO_MANIFEST_DIR resolves to the src-tauri directory at compile time.
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     if let Some(root) = manifest_dir.parent() {
@@ -55,7 +78,7 @@ pub fn resolve_data_dir() -> PathBuf {
         return p; // may not exist yet — handled later
     }
 
-    // 4) Last resort: current dir /data
+    // 5) Last resort: current dir /data
     env::current_dir()
         .unwrap_or_else(|_| PathBuf::from("."))
         .join("data")
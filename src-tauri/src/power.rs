@@ -0,0 +1,524 @@
+//! Power management commands (Windows `powercfg`).
+//!
+//! Responsibilities:
+//! - Report the available power plans and which one is active
+//! - Switch the active plan by friendly name or GUID
+//! - Read and change display/sleep timeouts of the active plan
+//! - Keep the machine awake while a service run is in progress
+//! - Detect whether Windows has a reboot pending
+//! - Schedule (or abort) a delayed restart or shutdown
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// Well-known GUIDs of the built-in Windows power plans.
+const PLAN_BALANCED: &str = "381b4222-f694-41f0-9685-ff5bb260df2e";
+const PLAN_HIGH_PERFORMANCE: &str = "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c";
+const PLAN_POWER_SAVER: &str = "a1841308-3541-4fab-bc81-f71556f20b4a";
+
+/// A power plan as listed by `powercfg /list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerPlan {
+    pub guid: String,
+    pub name: String,
+    pub active: bool,
+}
+
+/// The active power plan together with every plan available on the machine.
+#[derive(Debug, Serialize)]
+pub struct PowerPlanInfo {
+    pub active: Option<PowerPlan>,
+    pub plans: Vec<PowerPlan>,
+}
+
+#[tauri::command]
+/// Return the active power plan and the list of available plans. Windows-only.
+pub async fn get_power_plan() -> Result<PowerPlanInfo, String> {
+    tauri::async_runtime::spawn_blocking(read_power_plans)
+        .await
+        .map_err(|e| format!("Power plan worker failed: {}", e))?
+}
+
+fn read_power_plans() -> Result<PowerPlanInfo, String> {
+    let output = run_powercfg(&["/list"])?;
+    let plans = parse_power_plans(&output);
+    let active = plans.iter().find(|p| p.active).cloned();
+    Ok(PowerPlanInfo { active, plans })
+}
+
+#[tauri::command]
+/// Switch the active power plan.
+///
+/// # Arguments
+/// * `plan` - "balanced", "high performance", "power saver", or a plan GUID
+///
+/// # Returns
+/// The power plan state after switching
+pub async fn set_power_plan(plan: String) -> Result<PowerPlanInfo, String> {
+    let guid = resolve_plan_guid(&plan)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        run_powercfg(&["/setactive", &guid])?;
+        read_power_plans()
+    })
+    .await
+    .map_err(|e| format!("Power plan worker failed: {}", e))?
+}
+
+/// Display and sleep timeouts in minutes (0 means never).
+///
+/// As input to `set_sleep_settings`, a missing field leaves that timeout unchanged.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SleepSettings {
+    pub display_timeout: Option<u32>,
+    pub sleep_timeout: Option<u32>,
+}
+
+/// Timeouts of the active plan when plugged in (`ac`) and on battery (`dc`).
+#[derive(Debug, Serialize)]
+pub struct SleepSettingsState {
+    pub ac: SleepSettings,
+    pub dc: SleepSettings,
+}
+
+#[tauri::command]
+/// Return the active plan's display and sleep timeouts. Windows-only.
+pub async fn get_sleep_settings() -> Result<SleepSettingsState, String> {
+    tauri::async_runtime::spawn_blocking(read_sleep_settings)
+        .await
+        .map_err(|e| format!("Sleep settings worker failed: {}", e))?
+}
+
+fn read_sleep_settings() -> Result<SleepSettingsState, String> {
+    let (display_ac, display_dc) = query_timeout("SUB_VIDEO", "VIDEOIDLE")?;
+    let (sleep_ac, sleep_dc) = query_timeout("SUB_SLEEP", "STANDBYIDLE")?;
+    Ok(SleepSettingsState {
+        ac: SleepSettings {
+            display_timeout: display_ac,
+            sleep_timeout: sleep_ac,
+        },
+        dc: SleepSettings {
+            display_timeout: display_dc,
+            sleep_timeout: sleep_dc,
+        },
+    })
+}
+
+#[tauri::command]
+/// Change the active plan's display and/or sleep timeouts via `powercfg /change`.
+///
+/// Values are in minutes (0 = never) and are applied to both AC and battery power.
+///
+/// # Returns
+/// The timeouts read back after applying the change
+pub async fn set_sleep_settings(settings: SleepSettings) -> Result<SleepSettingsState, String> {
+    if settings.display_timeout.is_none() && settings.sleep_timeout.is_none() {
+        return Err("No sleep settings provided".into());
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Some(minutes) = settings.display_timeout {
+            let value = minutes.to_string();
+            run_powercfg(&["/change", "monitor-timeout-ac", &value])?;
+            run_powercfg(&["/change", "monitor-timeout-dc", &value])?;
+        }
+        if let Some(minutes) = settings.sleep_timeout {
+            let value = minutes.to_string();
+            run_powercfg(&["/change", "standby-timeout-ac", &value])?;
+            run_powercfg(&["/change", "standby-timeout-dc", &value])?;
+        }
+        read_sleep_settings()
+    })
+    .await
+    .map_err(|e| format!("Sleep settings worker failed: {}", e))?
+}
+
+/// Result of `check_pending_reboot`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingReboot {
+    pub pending: bool,
+    /// Human-readable description of each indicator that is set
+    pub reasons: Vec<String>,
+}
+
+/// Registry indicators of a pending reboot: (key, value or `None` for the key itself, reason).
+pub(crate) const PENDING_REBOOT_INDICATORS: &[(&str, Option<&str>, &str)] = &[
+    (
+        r"HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\Component Based Servicing\RebootPending",
+        None,
+        "Component Based Servicing has a reboot pending",
+    ),
+    (
+        r"HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired",
+        None,
+        "Windows Update requires a reboot",
+    ),
+    (
+        r"HKLM\SYSTEM\CurrentControlSet\Control\Session Manager",
+        Some("PendingFileRenameOperations"),
+        "File rename operations are waiting for a reboot",
+    ),
+];
+
+#[tauri::command]
+/// Check the well-known registry indicators for a pending reboot.
+///
+/// Looks at Component Based Servicing `RebootPending`, Windows Update `RebootRequired`
+/// and the Session Manager `PendingFileRenameOperations` value. Windows-only.
+pub async fn check_pending_reboot() -> Result<PendingReboot, String> {
+    // Each indicator is a `reg.exe` call, so keep them off the async runtime.
+    tauri::async_runtime::spawn_blocking(|| {
+        let mut reasons = Vec::new();
+        for (key, value, reason) in PENDING_REBOOT_INDICATORS {
+            if registry_entry_exists(key, *value)? {
+                reasons.push(reason.to_string());
+            }
+        }
+        Ok::<_, String>(PendingReboot {
+            pending: !reasons.is_empty(),
+            reasons,
+        })
+    })
+    .await
+    .map_err(|e| format!("Pending reboot check failed: {}", e))?
+}
+
+/// Longest delay accepted by `shutdown /t` (ten years, in seconds).
+const MAX_SHUTDOWN_DELAY_SECS: u32 = 315_360_000;
+/// Longest comment accepted by `shutdown /c`.
+const MAX_SHUTDOWN_MESSAGE_LEN: usize = 512;
+
+#[tauri::command]
+/// Schedule a restart with `shutdown /r /t <delay_seconds> /c <message>`.
+///
+/// With `abort` set, cancels a pending restart or shutdown (`shutdown /a`) instead.
+/// Requires the app to be elevated; scheduling is refused while a service run is active.
+pub fn schedule_restart(
+    state: tauri::State<AppState>,
+    delay_seconds: u32,
+    message: Option<String>,
+    abort: bool,
+) -> Result<(), String> {
+    schedule_power_action(&state, "/r", delay_seconds, message, abort)
+}
+
+#[tauri::command]
+/// Schedule a shutdown with `shutdown /s /t <delay_seconds> /c <message>`.
+///
+/// Same rules as `schedule_restart`, including `abort`.
+pub fn schedule_shutdown(
+    state: tauri::State<AppState>,
+    delay_seconds: u32,
+    message: Option<String>,
+    abort: bool,
+) -> Result<(), String> {
+    schedule_power_action(&state, "/s", delay_seconds, message, abort)
+}
+
+// Validate and run `shutdown.exe` for a restart (`/r`) or shutdown (`/s`), or abort.
+fn schedule_power_action(
+    state: &AppState,
+    mode: &str,
+    delay_seconds: u32,
+    message: Option<String>,
+    abort: bool,
+) -> Result<(), String> {
+    if !cfg!(windows) {
+        return Err("Scheduling a restart or shutdown is only supported on Windows".into());
+    }
+    if !crate::system::is_elevated() {
+        return Err(
+            "Administrator privileges are required; restart AutoService as administrator".into(),
+        );
+    }
+    if abort {
+        return run_shutdown(&["/a"]);
+    }
+
+    let run_active = state
+        .active_run
+        .lock()
+        .map(|run| run.is_some())
+        .unwrap_or(false);
+    if run_active {
+        return Err("Cannot schedule a restart or shutdown while a service run is active".into());
+    }
+    if delay_seconds > MAX_SHUTDOWN_DELAY_SECS {
+        return Err(format!(
+            "Delay must be at most {} seconds",
+            MAX_SHUTDOWN_DELAY_SECS
+        ));
+    }
+    let message = message.unwrap_or_default();
+    let message = message.trim();
+    if message.chars().count() > MAX_SHUTDOWN_MESSAGE_LEN {
+        return Err(format!(
+            "Message must be at most {} characters",
+            MAX_SHUTDOWN_MESSAGE_LEN
+        ));
+    }
+
+    let delay = delay_seconds.to_string();
+    let mut args = vec![mode, "/t", delay.as_str()];
+    if !message.is_empty() {
+        args.extend(["/c", message]);
+    }
+    run_shutdown(&args)
+}
+
+// Read a timeout setting of the current scheme, returning (AC, DC) in minutes.
+fn query_timeout(subgroup: &str, setting: &str) -> Result<(Option<u32>, Option<u32>), String> {
+    let output = run_powercfg(&["/query", "SCHEME_CURRENT", subgroup, setting])?;
+    Ok(parse_ac_dc_minutes(&output))
+}
+
+// `powercfg /query` prints the AC and DC indexes (seconds, hex) as the last two
+// hex values of the output; labels are localized so only the values are used.
+fn parse_ac_dc_minutes(output: &str) -> (Option<u32>, Option<u32>) {
+    let values: Vec<u32> = output
+        .split_whitespace()
+        .filter_map(|t| t.strip_prefix("0x"))
+        .filter_map(|hex| u32::from_str_radix(hex, 16).ok())
+        .collect();
+    match values.as_slice() {
+        [.., ac, dc] => (Some(ac / 60), Some(dc / 60)),
+        _ => (None, None),
+    }
+}
+
+// Map a friendly plan name or GUID to the GUID `powercfg /setactive` expects.
+fn resolve_plan_guid(plan: &str) -> Result<String, String> {
+    let key = plan.trim().to_ascii_lowercase().replace(['-', '_'], " ");
+    match key.as_str() {
+        "balanced" => Ok(PLAN_BALANCED.into()),
+        "high performance" | "performance" => Ok(PLAN_HIGH_PERFORMANCE.into()),
+        "power saver" | "powersaver" => Ok(PLAN_POWER_SAVER.into()),
+        _ if is_guid(plan.trim()) => Ok(plan.trim().to_ascii_lowercase()),
+        _ => Err(format!(
+            "Unknown power plan '{}' (expected balanced, high performance, power saver or a GUID)",
+            plan.trim()
+        )),
+    }
+}
+
+fn is_guid(s: &str) -> bool {
+    s.len() == 36
+        && s.chars().enumerate().all(|(i, c)| {
+            matches!(i, 8 | 13 | 18 | 23) == (c == '-') && (c == '-' || c.is_ascii_hexdigit())
+        })
+}
+
+// Parse `powercfg /list` lines such as
+// `Power Scheme GUID: 381b4222-...-ff5bb260df2e  (Balanced) *`.
+// Only the GUID and parenthesised name are relied on, so localized output works too.
+fn parse_power_plans(output: &str) -> Vec<PowerPlan> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let guid = line.split_whitespace().find(|t| is_guid(t))?;
+            let rest = &line[line.find(guid)? + guid.len()..];
+            let name = match (rest.find('('), rest.rfind(')')) {
+                (Some(start), Some(end)) if end > start => rest[start + 1..end].trim().to_string(),
+                _ => String::new(),
+            };
+            Some(PowerPlan {
+                guid: guid.to_ascii_lowercase(),
+                name,
+                active: rest.trim_end().ends_with('*'),
+            })
+        })
+        .collect()
+}
+
+/// Keeps the system and display awake until dropped.
+///
+/// Uses `SetThreadExecutionState`, which is scoped to the calling thread, so the
+/// guard must be created and dropped on the thread that lives for the run.
+pub(crate) struct KeepAwakeGuard {
+    _private: (),
+}
+
+impl KeepAwakeGuard {
+    pub(crate) fn new() -> Self {
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::System::Power::{
+                SetThreadExecutionState, ES_CONTINUOUS, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED,
+            };
+            // SAFETY: plain Win32 call with no pointers involved.
+            unsafe {
+                SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED);
+            }
+        }
+        KeepAwakeGuard { _private: () }
+    }
+}
+
+impl Drop for KeepAwakeGuard {
+    fn drop(&mut self) {
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::System::Power::{SetThreadExecutionState, ES_CONTINUOUS};
+            // SAFETY: plain Win32 call with no pointers involved.
+            unsafe {
+                SetThreadExecutionState(ES_CONTINUOUS);
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+fn run_powercfg(args: &[&str]) -> Result<String, String> {
+    let output = std::process::Command::new("powercfg")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run powercfg: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let msg = if stderr.trim().is_empty() {
+            stdout
+        } else {
+            stderr
+        };
+        return Err(format!("powercfg failed: {}", msg.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(windows)]
+fn run_shutdown(args: &[&str]) -> Result<(), String> {
+    let output = std::process::Command::new("shutdown.exe")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run shutdown.exe: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let msg = if stderr.trim().is_empty() {
+            stdout
+        } else {
+            stderr
+        };
+        return Err(format!("shutdown failed: {}", msg.trim()));
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn run_shutdown(_args: &[&str]) -> Result<(), String> {
+    Err("Scheduling a restart or shutdown is only supported on Windows".into())
+}
+
+// Whether a registry key (or, with `value`, a value under it) exists, via `reg query`.
+#[cfg(windows)]
+fn registry_entry_exists(key: &str, value: Option<&str>) -> Result<bool, String> {
+    let mut cmd = std::process::Command::new("reg.exe");
+    cmd.args(["query", key]);
+    if let Some(value) = value {
+        cmd.args(["/v", value]);
+    }
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run reg.exe: {}", e))?;
+    Ok(output.status.success())
+}
+
+#[cfg(not(windows))]
+fn registry_entry_exists(_key: &str, _value: Option<&str>) -> Result<bool, String> {
+    Err("Pending reboot detection is only supported on Windows".into())
+}
+
+#[cfg(not(windows))]
+fn run_powercfg(_args: &[&str]) -> Result<String, String> {
+    Err("Power plan management is only supported on Windows".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_guid() {
+        let cases = [
+            ("381b4222-f694-41f0-9685-ff5bb260df2e", true),
+            ("8C5E7FDA-E8BF-4A96-9A85-A6E23A8C635C", true),
+            ("381b4222f694-41f0-9685-ff5bb260df2e-", false),
+            ("381b4222-f694-41f0-9685-ff5bb260df2", false),
+            ("381b4222-f694-41f0-9685-ff5bb260df2e0", false),
+            ("g81b4222-f694-41f0-9685-ff5bb260df2e", false),
+            ("(Balanced)", false),
+            ("", false),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(is_guid(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_power_plans() {
+        let english = "Existing Power Schemes (* Active)\r\n\
+            -----------------------------------\r\n\
+            Power Scheme GUID: 381b4222-f694-41f0-9685-ff5bb260df2e  (Balanced) *\r\n\
+            Power Scheme GUID: 8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c  (High performance)\r\n\
+            Power Scheme GUID: a1841308-3541-4fab-bc81-f71556f20b4a  (Power saver)\r\n";
+        let german = "Vorhandene Energieschemas (* Aktiv)\n\
+            -----------------------------------\n\
+            GUID des Energieschemas: 381B4222-F694-41F0-9685-FF5BB260DF2E  (Ausbalanciert)\n\
+            GUID des Energieschemas: 8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c  (Höchstleistung (Kopie)) *\n";
+        let cases: [(&str, &[(&str, &str, bool)]); 3] = [
+            (
+                english,
+                &[
+                    (PLAN_BALANCED, "Balanced", true),
+                    (PLAN_HIGH_PERFORMANCE, "High performance", false),
+                    (PLAN_POWER_SAVER, "Power saver", false),
+                ],
+            ),
+            (
+                german,
+                &[
+                    (PLAN_BALANCED, "Ausbalanciert", false),
+                    (PLAN_HIGH_PERFORMANCE, "Höchstleistung (Kopie)", true),
+                ],
+            ),
+            ("", &[]),
+        ];
+        for (output, expected) in cases {
+            let plans: Vec<(String, String, bool)> = parse_power_plans(output)
+                .into_iter()
+                .map(|p| (p.guid, p.name, p.active))
+                .collect();
+            let expected: Vec<(String, String, bool)> = expected
+                .iter()
+                .map(|(guid, name, active)| (guid.to_string(), name.to_string(), *active))
+                .collect();
+            assert_eq!(plans, expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_ac_dc_minutes() {
+        let english = "Power Scheme GUID: 381b4222-f694-41f0-9685-ff5bb260df2e  (Balanced)\r\n\
+            Subgroup GUID: 7516b95f-f776-4464-8c53-06167f40cc99  (Display)\r\n\
+            Power Setting GUID: 3c0bc021-c8a8-4e07-a973-6b14cbcb2b7e  (Turn off display after)\r\n\
+            Minimum Possible Setting: 0x00000000\r\n\
+            Maximum Possible Setting: 0xffffffff\r\n\
+            Possible Settings increment: 0x00000001\r\n\
+            Possible Settings units: Seconds\r\n\
+            Current AC Power Setting Index: 0x00000258\r\n\
+            Current DC Power Setting Index: 0x0000012c\r\n";
+        let german = "Minimale mögliche Einstellung: 0x00000000\n\
+            Maximale mögliche Einstellung: 0xffffffff\n\
+            Index der aktuellen Wechselstromeinstellung: 0x00000000\n\
+            Index der aktuellen Gleichstromeinstellung: 0x00000e10\n";
+        let cases = [
+            (english, (Some(10), Some(5))),
+            (german, (Some(0), Some(60))),
+            ("Current AC Power Setting Index: 0x00000258", (None, None)),
+            ("The system cannot find the file specified.", (None, None)),
+            ("", (None, None)),
+        ];
+        for (output, expected) in cases {
+            assert_eq!(parse_ac_dc_minutes(output), expected, "{:?}", output);
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Process management commands.
+//!
+//! Responsibilities:
+//! - Find running processes by name pattern (case-insensitive, `*`/`?` wildcards)
+//! - Terminate lingering tool instances while refusing to touch critical
+//!   system processes or AutoService itself
+//! - Close a web browser (main and helper processes), gracefully first
+//! - Report the processes using the most CPU or memory
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+/// Processes that must never be terminated by name (lowercase, without `.exe`).
+const PROTECTED_PROCESSES: &[&str] = &[
+    "system",
+    "system idle process",
+    "registry",
+    "memory compression",
+    "smss",
+    "csrss",
+    "wininit",
+    "winlogon",
+    "services",
+    "lsass",
+    "lsaiso",
+    "svchost",
+    "dwm",
+    "fontdrvhost",
+    "explorer",
+    "autoservice",
+];
+
+/// Supported browsers and the exact process names (lowercase, without `.exe`) that
+/// belong to them. Names are matched exactly so similarly named processes are left alone.
+const BROWSER_PROCESSES: &[(&str, &[&str])] = &[
+    ("chrome", &["chrome"]),
+    ("edge", &["msedge"]),
+    ("firefox", &["firefox", "plugin-container"]),
+    ("brave", &["brave"]),
+    ("opera", &["opera", "opera_crashreporter"]),
+    ("vivaldi", &["vivaldi"]),
+];
+
+/// How long `close_browser` waits for a graceful close before giving up (or force-killing).
+const BROWSER_CLOSE_GRACE: Duration = Duration::from_secs(5);
+
+/// Largest `limit` accepted by `get_top_processes`.
+const MAX_TOP_PROCESSES: usize = 200;
+
+/// A process identified by pid and executable name.
+#[derive(Debug, Serialize)]
+pub struct ProcessRef {
+    pub pid: u32,
+    pub name: String,
+}
+
+/// Outcome of `kill_processes_by_name`.
+#[derive(Debug, Serialize)]
+pub struct KillProcessesResult {
+    /// Processes that were terminated
+    pub killed: Vec<ProcessRef>,
+    /// Matching processes that could not be terminated (e.g. access denied)
+    pub failed: Vec<ProcessRef>,
+    /// Matching processes skipped because they are critical or our own
+    pub protected: Vec<ProcessRef>,
+}
+
+#[tauri::command]
+/// Terminate every process whose name matches `pattern`.
+///
+/// Matching is case-insensitive against the process name with or without its
+/// `.exe` suffix; `*` and `?` act as wildcards (e.g. `"adwcleaner*"`). Critical
+/// system processes and the AutoService process itself are never killed.
+///
+/// # Returns
+/// The processes that were killed, failed to be killed, or were protected
+pub async fn kill_processes_by_name(pattern: String) -> Result<KillProcessesResult, String> {
+    let matcher = build_matcher(&pattern)?;
+    // Refreshing the full process list is slow, so keep it off the async runtime.
+    tauri::async_runtime::spawn_blocking(move || kill_matching_processes(&matcher))
+        .await
+        .map_err(|e| format!("Kill processes worker failed: {}", e))
+}
+
+fn kill_matching_processes(matcher: &regex::Regex) -> KillProcessesResult {
+    let own_pid = sysinfo::get_current_pid().ok();
+
+    let mut sys = System::new();
+    sys.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut result = KillProcessesResult {
+        killed: Vec::new(),
+        failed: Vec::new(),
+        protected: Vec::new(),
+    };
+    for (pid, process) in sys.processes() {
+        let name = process.name().to_string_lossy().to_string();
+        let lower = name.to_ascii_lowercase();
+        let stem = lower.strip_suffix(".exe").unwrap_or(&lower);
+        if !matcher.is_match(&lower) && !matcher.is_match(stem) {
+            continue;
+        }
+        let entry = ProcessRef {
+            pid: pid.as_u32(),
+            name,
+        };
+        if Some(*pid) == own_pid || PROTECTED_PROCESSES.contains(&stem) {
+            result.protected.push(entry);
+        } else if process.kill() {
+            result.killed.push(entry);
+        } else {
+            result.failed.push(entry);
+        }
+    }
+    result
+}
+
+/// Outcome of `close_browser`.
+#[derive(Debug, Serialize)]
+pub struct CloseBrowserResult {
+    /// Processes that exited after the graceful close request
+    pub closed: Vec<ProcessRef>,
+    /// Processes that were force-killed
+    pub killed: Vec<ProcessRef>,
+    /// Processes still running afterwards
+    pub remaining: Vec<ProcessRef>,
+}
+
+#[tauri::command]
+/// Close a web browser and its helper processes.
+///
+/// `name` is a browser key ("chrome", "edge", "firefox", "brave", "opera", "vivaldi") or
+/// its executable name. The browser is first asked to close (like clicking the window's
+/// close button); processes still running after a few seconds are force-killed when
+/// `force` is set, otherwise reported as remaining.
+pub async fn close_browser(name: String, force: bool) -> Result<CloseBrowserResult, String> {
+    // Waiting for the browser to exit blocks, so keep it off the async runtime.
+    tauri::async_runtime::spawn_blocking(move || close_browser_blocking(&name, force))
+        .await
+        .map_err(|e| format!("Close browser worker failed: {}", e))?
+}
+
+fn close_browser_blocking(name: &str, force: bool) -> Result<CloseBrowserResult, String> {
+    let key = name.trim().to_ascii_lowercase();
+    let key = key.strip_suffix(".exe").unwrap_or(&key);
+    let (_, stems) = BROWSER_PROCESSES
+        .iter()
+        .find(|(browser, stems)| *browser == key || stems.contains(&key))
+        .ok_or_else(|| format!("Unsupported browser: {}", name.trim()))?;
+
+    let mut sys = System::new();
+    let initial = find_browser_processes(&mut sys, stems);
+    if initial.is_empty() {
+        return Ok(CloseBrowserResult {
+            closed: Vec::new(),
+            killed: Vec::new(),
+            remaining: Vec::new(),
+        });
+    }
+    request_close(&initial);
+
+    // Wait for the initial processes to exit, polling until the grace period ends.
+    let started = Instant::now();
+    let mut running: HashSet<u32>;
+    loop {
+        std::thread::sleep(Duration::from_millis(250));
+        running = find_browser_processes(&mut sys, stems)
+            .iter()
+            .map(|p| p.pid)
+            .collect();
+        let any_left = initial.iter().any(|p| running.contains(&p.pid));
+        if !any_left || started.elapsed() >= BROWSER_CLOSE_GRACE {
+            break;
+        }
+    }
+
+    let mut result = CloseBrowserResult {
+        closed: Vec::new(),
+        killed: Vec::new(),
+        remaining: Vec::new(),
+    };
+    for entry in initial {
+        if !running.contains(&entry.pid) {
+            result.closed.push(entry);
+        } else if force
+            && sys
+                .process(Pid::from_u32(entry.pid))
+                .is_some_and(|process| process.kill())
+        {
+            result.killed.push(entry);
+        } else {
+            result.remaining.push(entry);
+        }
+    }
+    Ok(result)
+}
+
+// Running processes whose name (without `.exe`) is exactly one of `stems`.
+fn find_browser_processes(sys: &mut System, stems: &[&str]) -> Vec<ProcessRef> {
+    let own_pid = sysinfo::get_current_pid().ok();
+    sys.refresh_processes(ProcessesToUpdate::All, true);
+    sys.processes()
+        .iter()
+        .filter(|(pid, _)| Some(**pid) != own_pid)
+        .filter_map(|(pid, process)| {
+            let name = process.name().to_string_lossy().to_string();
+            let lower = name.to_ascii_lowercase();
+            let stem = lower.strip_suffix(".exe").unwrap_or(&lower);
+            stems.contains(&stem).then(|| ProcessRef {
+                pid: pid.as_u32(),
+                name,
+            })
+        })
+        .collect()
+}
+
+// Ask processes to close without forcing them: `taskkill` without `/F` on Windows
+// (sends WM_CLOSE to their windows), SIGTERM elsewhere.
+#[cfg(windows)]
+fn request_close(processes: &[ProcessRef]) {
+    let mut cmd = std::process::Command::new("taskkill.exe");
+    for process in processes {
+        cmd.arg("/PID").arg(process.pid.to_string());
+    }
+    // Helper processes without windows refuse a graceful close; that is expected.
+    let _ = cmd.output();
+}
+
+#[cfg(not(windows))]
+fn request_close(processes: &[ProcessRef]) {
+    let mut sys = System::new();
+    sys.refresh_processes(ProcessesToUpdate::All, true);
+    for process in processes {
+        if let Some(p) = sys.process(Pid::from_u32(process.pid)) {
+            let _ = p.kill_with(sysinfo::Signal::Term);
+        }
+    }
+}
+
+// Translate a wildcard pattern into an anchored, case-insensitive regex.
+fn build_matcher(pattern: &str) -> Result<regex::Regex, String> {
+    let pattern = pattern.trim();
+    if pattern.chars().all(|c| c == '*' || c == '?') {
+        return Err("Process name pattern must contain at least one literal character".into());
+    }
+    let escaped = regex::escape(&pattern.to_ascii_lowercase())
+        .replace(r"\*", ".*")
+        .replace(r"\?", ".");
+    regex::Regex::new(&format!("^{}$", escaped)).map_err(|e| format!("Invalid pattern: {}", e))
+}
+
+/// Resource usage of one process, as returned by `get_top_processes`.
+#[derive(Debug, Serialize)]
+pub struct ProcessUsage {
+    pub pid: u32,
+    pub name: String,
+    /// CPU usage as a share of the whole machine (0–100, like Task Manager)
+    pub cpu_percent: f32,
+    /// Resident memory in bytes
+    pub memory_bytes: u64,
+    /// Bytes read from disk during the sampling interval
+    pub disk_read: u64,
+    /// Bytes written to disk during the sampling interval
+    pub disk_written: u64,
+}
+
+#[tauri::command]
+/// List the processes using the most CPU or memory.
+///
+/// Processes are sampled twice, `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL` apart, so CPU
+/// percentages reflect actual load instead of reading zero. Disk figures cover the same
+/// interval.
+///
+/// # Arguments
+/// * `limit` - Maximum number of processes to return (defaults to 10, at most 200)
+/// * `sort_by` - "cpu" (default) or "memory"
+pub async fn get_top_processes(
+    limit: Option<usize>,
+    sort_by: Option<String>,
+) -> Result<Vec<ProcessUsage>, String> {
+    let limit = limit.unwrap_or(10).clamp(1, MAX_TOP_PROCESSES);
+    let by_memory = match sort_by.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("cpu") => false,
+        Some("memory") => true,
+        Some(other) => return Err(format!("Unknown sort order: {}", other)),
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let refresh = ProcessRefreshKind::nothing()
+            .with_cpu()
+            .with_memory()
+            .with_disk_usage();
+        let mut sys = System::new();
+        sys.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+
+        // sysinfo reports CPU per core (up to 100% × cores); scale to the whole machine.
+        let cores = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1) as f32;
+        let mut usage: Vec<ProcessUsage> = sys
+            .processes()
+            .iter()
+            .map(|(pid, process)| {
+                let disk = process.disk_usage();
+                ProcessUsage {
+                    pid: pid.as_u32(),
+                    name: process.name().to_string_lossy().to_string(),
+                    cpu_percent: process.cpu_usage() / cores,
+                    memory_bytes: process.memory(),
+                    disk_read: disk.read_bytes,
+                    disk_written: disk.written_bytes,
+                }
+            })
+            .collect();
+        if by_memory {
+            usage.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes));
+        } else {
+            usage.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent));
+        }
+        usage.truncate(limit);
+        usage
+    })
+    .await
+    .map_err(|e| format!("Process sampling failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_matcher() {
+        // Names are matched lowercased, as `kill_matching_processes` does.
+        let cases = [
+            ("adwcleaner*", "adwcleaner", true),
+            ("adwcleaner*", "adwcleaner_8.4.exe", true),
+            ("AdwCleaner*", "adwcleaner.exe", true),
+            ("adwcleaner*", "myadwcleaner", false),
+            ("notepad", "notepad", true),
+            ("notepad", "notepad++", false),
+            ("note?ad", "notepad", true),
+            ("note?ad", "noteppad", false),
+            ("*cleaner", "ccleaner", true),
+            ("c.exe", "cxexe", false),
+            ("  chrome  ", "chrome", true),
+            ("a+b", "a+b", true),
+            ("a+b", "aab", false),
+        ];
+        for (pattern, name, expected) in cases {
+            let matcher = build_matcher(pattern).unwrap();
+            assert_eq!(
+                matcher.is_match(name),
+                expected,
+                "{:?} vs {:?}",
+                pattern,
+                name
+            );
+        }
+
+        for pattern in ["", "   ", "*", "?*?"] {
+            assert!(build_matcher(pattern).is_err(), "{:?} accepted", pattern);
+        }
+    }
+}
@@ -6,20 +6,29 @@
 //! - Resolve and launch Windows executables
 //! - Provide summarized availability ("tool statuses") for key utilities
 use std::{
+    collections::HashMap,
     fs,
+    io::Read,
     path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    time::{Duration, Instant},
 };
 use uuid::Uuid;
 
 use crate::icons::get_logo_from_exe;
-use crate::models::{ProgramDiskEntry, ProgramEntry, ToolStatus};
+use crate::models::{
+    PlanToolDependency, ProgramDiskEntry, ProgramEntry, ToolCategoryGroup, ToolRunOutput,
+    ToolStatus,
+};
 use crate::{paths, state::AppState};
 
 #[tauri::command]
 /// Load saved programs, normalize paths relative to the data directory,
 /// and annotate each entry with whether its executable currently exists.
 pub fn list_programs(state: tauri::State<AppState>) -> Result<Vec<ProgramEntry>, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let settings_path = programs_json_path(data_root);
     let mut list = read_programs_file(&settings_path);
     let mut changed = false;
@@ -49,26 +58,30 @@ pub fn list_programs(state: tauri::State<AppState>) -> Result<Vec<ProgramEntry>,
 /// - Derives a logo from the executable if none was provided.
 /// - Normalizes `exe_path` to be relative to the data directory when possible.
 /// - Preserves `launch_count` on updates (frontend does not send it).
+/// - Never blocks on duplicates, but returns the existing entry that points at the same
+///   executable (if any) so the UI can warn about it.
 pub fn save_program(
     state: tauri::State<AppState>,
     mut program: ProgramEntry,
-) -> Result<(), String> {
-    let settings_path = programs_json_path(state.data_dir.as_path());
+) -> Result<Option<ProgramEntry>, String> {
+    let settings_path = programs_json_path(&state.data_dir());
     // Best-effort: extract an icon from the referenced executable when missing.
     if program.logo_data_url.is_empty() {
-        if let Ok(Some(url)) = get_logo_from_exe(state.data_dir.as_path(), &program.exe_path) {
+        if let Ok(Some(url)) = get_logo_from_exe(&state.data_dir(), &program.exe_path) {
             program.logo_data_url = url;
         }
     }
     let exe_p = std::path::PathBuf::from(&program.exe_path);
     if exe_p.is_absolute() {
-        let data_root = state.data_dir.as_path();
+        let data_dir = state.data_dir();
+        let data_root = data_dir.as_path();
         // Persist relative paths to keep storage portable across machines.
         if let Ok(stripped) = exe_p.strip_prefix(data_root) {
             program.exe_path = stripped.to_string_lossy().to_string();
         }
     }
     let mut list = read_programs_file(&settings_path);
+    let duplicate = find_duplicate_program(&state.data_dir(), &list, &program).cloned();
     match list.iter_mut().find(|p| p.id == program.id) {
         Some(existing) => {
             // Preserve `launch_count` unless explicitly provided (frontend doesn't send it).
@@ -77,13 +90,54 @@ pub fn save_program(
         }
         None => list.push(program),
     }
-    write_programs_file(&settings_path, &list)
+    write_programs_file(&settings_path, &list)?;
+    Ok(duplicate)
+}
+
+#[tauri::command]
+/// Find a saved program (other than `program` itself) whose executable resolves to the
+/// same file as `program.exe_path`.
+///
+/// Lets the UI ask "this tool is already added" before saving.
+pub fn check_program_duplicate(
+    state: tauri::State<AppState>,
+    program: ProgramEntry,
+) -> Result<Option<ProgramEntry>, String> {
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
+    let list = read_programs_file(&programs_json_path(data_root));
+    Ok(find_duplicate_program(data_root, &list, &program).cloned())
+}
+
+// First entry with a different id whose resolved executable path matches `program`'s.
+fn find_duplicate_program<'a>(
+    data_root: &Path,
+    list: &'a [ProgramEntry],
+    program: &ProgramEntry,
+) -> Option<&'a ProgramEntry> {
+    let key = exe_identity(data_root, &program.exe_path)?;
+    list.iter()
+        .filter(|p| p.id != program.id)
+        .find(|p| exe_identity(data_root, &p.exe_path).as_deref() == Some(key.as_str()))
+}
+
+// Comparable identity of an executable path: resolved against the data directory,
+// canonicalized when the file exists and lowercased (Windows paths are case-insensitive).
+fn exe_identity(data_root: &Path, exe_path: &str) -> Option<String> {
+    if exe_path.trim().is_empty() {
+        return None;
+    }
+    let full = resolve_exe_path(data_root, exe_path.trim());
+    let resolved = fs::canonicalize(&full)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or(full);
+    Some(resolved.replace('/', "\\").to_lowercase())
 }
 
 #[tauri::command]
 /// Remove a program by its `id` from `programs.json`.
 pub fn remove_program(state: tauri::State<AppState>, id: Uuid) -> Result<(), String> {
-    let settings_path = programs_json_path(state.data_dir.as_path());
+    let settings_path = programs_json_path(&state.data_dir());
     let mut list = read_programs_file(&settings_path);
     list.retain(|p| p.id != id);
     write_programs_file(&settings_path, &list)
@@ -100,15 +154,16 @@ pub fn launch_program(state: tauri::State<AppState>, program: ProgramEntry) -> R
     }
     #[cfg(windows)]
     {
-        use std::process::Command;
-        let exe_full = resolve_exe_path(state.data_dir.as_path(), &program.exe_path);
+        let exe_full = resolve_exe_path(&state.data_dir(), &program.exe_path);
         if !Path::new(&exe_full).is_file() {
             return Err(format!("Executable not found: {}", exe_full));
         }
+        let window_style = window_style(program.window_state.as_deref())?;
         // Use PowerShell Start-Process to decouple from the current process and avoid blocking.
         let ps = format!(
-            "Start-Process -FilePath \"{}\"",
-            exe_full.replace('`', "``").replace('"', "`\"")
+            "Start-Process -FilePath \"{}\" -WindowStyle {}",
+            exe_full.replace('`', "``").replace('"', "`\""),
+            window_style
         );
         // Spawn the process first; if successful, increment and persist the launch counter.
         // Note: arguments are escaped for PowerShell to handle paths with special characters.
@@ -118,7 +173,7 @@ pub fn launch_program(state: tauri::State<AppState>, program: ProgramEntry) -> R
             .map_err(|e| format!("Failed to start program: {}", e))
             .and_then(|_| {
                 // Increment `launch_count` and persist to disk.
-                let settings_path = programs_json_path(state.data_dir.as_path());
+                let settings_path = programs_json_path(&state.data_dir());
                 let mut list = read_programs_file(&settings_path);
                 if let Some(p) = list.iter_mut().find(|p| p.id == program.id) {
                     // Saturating add to avoid overflow on long-lived installs.
@@ -129,15 +184,32 @@ pub fn launch_program(state: tauri::State<AppState>, program: ProgramEntry) -> R
     }
 }
 
+// Map a `ProgramEntry::window_state` value to a `Start-Process -WindowStyle` argument.
+#[cfg(windows)]
+fn window_style(window_state: Option<&str>) -> Result<&'static str, String> {
+    match window_state
+        .map(|s| s.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        None | Some("") | Some("normal") => Ok("Normal"),
+        Some("minimized") => Ok("Minimized"),
+        Some("maximized") => Ok("Maximized"),
+        Some(other) => Err(format!(
+            "Invalid window state '{}': expected normal, minimized or maximized",
+            other
+        )),
+    }
+}
+
 // Build the full path to the persisted programs index JSON within the settings directory.
-fn programs_json_path(data_root: &Path) -> PathBuf {
+pub(crate) fn programs_json_path(data_root: &Path) -> PathBuf {
     let (_reports, _programs, settings, _resources) = paths::subdirs(data_root);
     settings.join("programs.json")
 }
 
 // Resolve an executable path to an absolute string, checking both the data root and the
 // `programs` subdirectory. If the provided path is already absolute, return it unchanged.
-fn resolve_exe_path(data_root: &Path, exe_path: &str) -> String {
+pub(crate) fn resolve_exe_path(data_root: &Path, exe_path: &str) -> String {
     let p = PathBuf::from(exe_path);
     if p.is_absolute() {
         return exe_path.to_string();
@@ -161,11 +233,112 @@ fn resolve_exe_path(data_root: &Path, exe_path: &str) -> String {
 ///
 /// Frontend uses this to determine which global tools (e.g., virus scanners) are available.
 /// Matches saved entries via a simple fuzzy search over name/description/path, resolves paths,
-/// and reports existence alongside an optional executable hint for the user.
+/// and reports existence alongside an optional executable hint for the user. Paths set with
+/// `set_tool_override` take precedence when the file exists.
 #[tauri::command]
 pub fn get_tool_statuses(state: tauri::State<AppState>) -> Result<Vec<ToolStatus>, String> {
+    Ok(collect_tool_statuses(&state.data_dir()))
+}
+
+/// Return the tool statuses grouped by category, with per-category present/total counts.
+///
+/// Categories come from the built-in tool list and can be overridden per tool key via the
+/// `tool_categories` setting (e.g. `{"sdi": "diagnostic"}`). Groups are ordered cleanup,
+/// antivirus, benchmark, diagnostic, driver, followed by any custom categories.
+#[tauri::command]
+pub fn get_tool_statuses_grouped(
+    state: tauri::State<AppState>,
+) -> Result<Vec<ToolCategoryGroup>, String> {
+    let data_dir = state.data_dir();
+    let overrides = crate::settings::read_app_settings(&data_dir)
+        .ok()
+        .and_then(|v| v.get("tool_categories").cloned());
+
+    let mut groups: Vec<ToolCategoryGroup> = TOOL_CATEGORIES
+        .iter()
+        .map(|c| ToolCategoryGroup {
+            category: c.to_string(),
+            present: 0,
+            total: 0,
+            tools: Vec::new(),
+        })
+        .collect();
+    for mut status in collect_tool_statuses(&data_dir) {
+        if let Some(category) = overrides
+            .as_ref()
+            .and_then(|o| o.get(&status.key))
+            .and_then(|c| c.as_str())
+            .map(|c| c.trim().to_lowercase())
+            .filter(|c| !c.is_empty())
+        {
+            status.category = category;
+        }
+        let idx = match groups.iter().position(|g| g.category == status.category) {
+            Some(idx) => idx,
+            None => {
+                groups.push(ToolCategoryGroup {
+                    category: status.category.clone(),
+                    present: 0,
+                    total: 0,
+                    tools: Vec::new(),
+                });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[idx];
+        group.total += 1;
+        if status.exists {
+            group.present += 1;
+        }
+        group.tools.push(status);
+    }
+    groups.retain(|g| g.total > 0);
+    Ok(groups)
+}
+
+/// List the other executables in a tool's install folder (e.g. `freshclam.exe` next to
+/// `clamscan.exe`).
+///
+/// The tool is resolved the same way as in `get_tool_statuses`. Returns absolute paths
+/// sorted by file name, excluding the tool itself, or an empty list when the tool is not found.
+#[tauri::command]
+pub fn list_tool_companions(
+    state: tauri::State<AppState>,
+    tool_key: String,
+) -> Result<Vec<String>, String> {
+    let data_dir = state.data_dir();
+    let Some(tool_path) = find_known_tool(&data_dir, &tool_key) else {
+        return Ok(Vec::new());
+    };
+    let Some(folder) = tool_path.parent() else {
+        return Ok(Vec::new());
+    };
+    let entries = fs::read_dir(folder).map_err(|e| format!("Failed to read tool folder: {}", e))?;
+
+    let mut companions: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| p.is_file() && lower_name(p) != lower_name(&tool_path))
+        .filter(|p| {
+            p.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+        })
+        .collect();
+    companions.sort_by_key(|p| lower_name(p));
+    Ok(companions
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect())
+}
+
+// Lowercased file name, for case-insensitive comparisons of Windows paths.
+fn lower_name(path: &Path) -> Option<std::ffi::OsString> {
+    path.file_name().map(|n| n.to_ascii_lowercase())
+}
+
+// Resolve the status of every `KNOWN_TOOLS` entry against the saved programs.
+fn collect_tool_statuses(data_root: &Path) -> Vec<ToolStatus> {
     // Load saved programs and resolve existence for each entry.
-    let data_root = state.data_dir.as_path();
     let settings_path = programs_json_path(data_root);
     let mut list = read_programs_file(&settings_path);
     for p in &mut list {
@@ -173,53 +346,386 @@ pub fn get_tool_statuses(state: tauri::State<AppState>) -> Result<Vec<ToolStatus
         p.exe_exists = Path::new(&full).is_file();
     }
 
-    // Define a minimal set of known tool keys so pages can query consistently.
-    // Keep names aligned with the Settings REQUIRED list.
-    let required: &[(&str, &str, &str)] = &[
-        ("ccleaner", "CCleaner", "CCleaner.exe"),
-        ("bleachbit", "BleachBit", "bleachbit.exe"),
-        ("adwcleaner", "AdwCleaner", "adwcleaner.exe"),
-        ("clamav", "ClamAV", "clamscan.exe"),
-        ("kvrt", "KVRT", "KVRT.exe"),
-        ("trellix_stinger", "Trellix Stinger", "stinger64.exe"),
-        ("defender", "Windows Defender (MpCmdRun)", "MpCmdRun.exe"),
-        ("furmark2", "Furmark 2", "FurMark.exe"),
-        ("smartctl", "smartctl", "smartctl.exe"),
-        ("prime95", "Prime95", "prime95.exe"),
-        ("sdi", "Snappy Driver Installer", "SDI.exe"),
-        ("gsmartcontrol", "GSmartControl", "gsmartcontrol.exe"),
-    ];
-
-    let mut out = Vec::with_capacity(required.len());
-    for (key, name, hint) in required.iter().copied() {
-        // Simple fuzzy match against saved entries by key or display name.
-        let mut path: Option<String> = None;
-        let mut exists = false;
-        for p in &list {
-            let hay = format!("{} {} {}", p.name, p.description, p.exe_path).to_lowercase();
-            if hay.contains(key) || hay.contains(name.to_lowercase().as_str()) {
-                let full = resolve_exe_path(data_root, &p.exe_path);
-                exists = Path::new(&full).is_file();
-                path = Some(full);
-                break;
-            }
-        }
-
+    let overrides = read_tool_path_overrides(data_root);
+    let mut out = Vec::with_capacity(KNOWN_TOOLS.len());
+    for (key, name, hint, category) in KNOWN_TOOLS.iter().copied() {
+        let path = find_tool_path(data_root, &list, &overrides, key, name);
         out.push(ToolStatus {
             key: key.to_string(),
             name: name.to_string(),
-            exists,
+            exists: path.as_deref().is_some_and(|p| Path::new(p).is_file()),
             path,
             hint: Some(hint.to_string()),
+            category: category.to_string(),
         });
     }
+    out
+}
+
+#[tauri::command]
+/// List the external tools a service plan needs, with their availability.
+///
+/// Scans the plan's tasks (`{"tasks": [...]}` or a bare task array) for task types that
+/// depend on a tool and resolves each tool the same way as `get_tool_statuses`. A task's
+/// own `executable_path` takes precedence, since that is what the runner will use.
+/// Tools with alternatives (e.g. FurMark 1/2) count as present if any alternative is.
+pub fn get_plan_tool_dependencies(
+    state: tauri::State<AppState>,
+    plan_json: String,
+) -> Result<Vec<PlanToolDependency>, String> {
+    let plan: serde_json::Value =
+        serde_json::from_str(&plan_json).map_err(|e| format!("Invalid plan JSON: {}", e))?;
+    let tasks = plan
+        .get("tasks")
+        .and_then(|t| t.as_array())
+        .or_else(|| plan.as_array())
+        .ok_or_else(|| "Plan has no task list".to_string())?;
+
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
+    let list = read_programs_file(&programs_json_path(data_root));
+    let overrides = read_tool_path_overrides(data_root);
+
+    let mut out: Vec<PlanToolDependency> = Vec::new();
+    for task in tasks {
+        let task_type = task.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        let Some((_, keys)) = TASK_TOOL_KEYS.iter().find(|(t, _)| *t == task_type) else {
+            continue;
+        };
+        let key = keys[0];
+        let explicit = task
+            .get("executable_path")
+            .and_then(|p| p.as_str())
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(|p| resolve_exe_path(data_root, p));
+        let path = explicit.or_else(|| {
+            let found: Vec<String> = keys
+                .iter()
+                .filter_map(|k| {
+                    find_tool_path(data_root, &list, &overrides, k, tool_display_name(k))
+                })
+                .collect();
+            // Prefer an alternative that actually exists on disk.
+            found
+                .iter()
+                .find(|p| Path::new(p).is_file())
+                .or(found.first())
+                .cloned()
+        });
+        let exists = path.as_deref().is_some_and(|p| Path::new(p).is_file());
+
+        match out.iter_mut().find(|d| d.key == key) {
+            Some(dep) => {
+                if !dep.task_types.iter().any(|t| t == task_type) {
+                    dep.task_types.push(task_type.to_string());
+                }
+                if !dep.exists && exists {
+                    dep.exists = true;
+                    dep.path = path;
+                }
+            }
+            None => out.push(PlanToolDependency {
+                key: key.to_string(),
+                name: tool_display_name(key).to_string(),
+                task_types: vec![task_type.to_string()],
+                exists,
+                path,
+            }),
+        }
+    }
     Ok(out)
 }
 
+// Known tool keys so pages can query consistently: (key, display name, exe hint, category).
+// Keep names aligned with the Settings REQUIRED list.
+const KNOWN_TOOLS: &[(&str, &str, &str, &str)] = &[
+    ("ccleaner", "CCleaner", "CCleaner.exe", "cleanup"),
+    ("bleachbit", "BleachBit", "bleachbit.exe", "cleanup"),
+    ("adwcleaner", "AdwCleaner", "adwcleaner.exe", "antivirus"),
+    ("clamav", "ClamAV", "clamscan.exe", "antivirus"),
+    ("kvrt", "KVRT", "KVRT.exe", "antivirus"),
+    (
+        "trellix_stinger",
+        "Trellix Stinger",
+        "stinger64.exe",
+        "antivirus",
+    ),
+    (
+        "defender",
+        "Windows Defender (MpCmdRun)",
+        "MpCmdRun.exe",
+        "antivirus",
+    ),
+    ("furmark2", "Furmark 2", "FurMark.exe", "benchmark"),
+    ("smartctl", "smartctl", "smartctl.exe", "diagnostic"),
+    ("prime95", "Prime95", "prime95.exe", "benchmark"),
+    ("sdi", "Snappy Driver Installer", "SDI.exe", "driver"),
+    (
+        "gsmartcontrol",
+        "GSmartControl",
+        "gsmartcontrol.exe",
+        "diagnostic",
+    ),
+];
+
+// Display order of the built-in tool categories.
+const TOOL_CATEGORIES: &[&str] = &["cleanup", "antivirus", "benchmark", "diagnostic", "driver"];
+
+// Runner task types that need an external tool, mapped to the tool keys that satisfy
+// them (first key is the primary one). Mirrors the `toolKeys` of the service handlers.
+const TASK_TOOL_KEYS: &[(&str, &[&str])] = &[
+    ("bleachbit_clean", &["bleachbit"]),
+    ("adwcleaner_clean", &["adwcleaner"]),
+    ("furmark_stress_test", &["furmark2", "furmark"]),
+    ("heavyload_stress_test", &["heavyload"]),
+    ("smartctl_report", &["smartctl", "gsmartcontrol"]),
+    ("kvrt_scan", &["kvrt"]),
+    ("trellix_stinger_scan", &["trellix_stinger"]),
+    ("iperf_test", &["iperf3"]),
+    ("whynotwin11_check", &["whynotwin11"]),
+    ("drivecleanup_clean", &["drivecleanup"]),
+];
+
+// Display name for a tool key, falling back to the key itself for tools not in
+// `KNOWN_TOOLS`.
+fn tool_display_name(key: &str) -> &str {
+    KNOWN_TOOLS
+        .iter()
+        .find(|(k, _, _, _)| *k == key)
+        .map(|(_, name, _, _)| *name)
+        .unwrap_or(key)
+}
+
+/// Path of a built-in tool (a `KNOWN_TOOLS` key) when it is saved and exists on disk.
+pub(crate) fn find_known_tool(data_root: &Path, key: &str) -> Option<PathBuf> {
+    let list = read_programs_file(&programs_json_path(data_root));
+    let overrides = read_tool_path_overrides(data_root);
+    find_tool_path(data_root, &list, &overrides, key, tool_display_name(key))
+        .map(PathBuf::from)
+        .filter(|p| p.is_file())
+}
+
+// Simple fuzzy match of a tool against saved entries by key or display name.
+// Returns the resolved path of the first matching entry. A `tool_path_overrides`
+// entry wins when it points at an existing file.
+fn find_tool_path(
+    data_root: &Path,
+    list: &[ProgramEntry],
+    overrides: &HashMap<String, String>,
+    key: &str,
+    name: &str,
+) -> Option<String> {
+    if let Some(path) = overrides.get(key).filter(|p| Path::new(p).is_file()) {
+        return Some(path.clone());
+    }
+    let name = name.to_lowercase();
+    list.iter()
+        .find(|p| {
+            let hay = format!("{} {} {}", p.name, p.description, p.exe_path).to_lowercase();
+            hay.contains(key) || hay.contains(name.as_str())
+        })
+        .map(|p| resolve_exe_path(data_root, &p.exe_path))
+}
+
+/// Timeout used by `run_tool` when none is given.
+const DEFAULT_TOOL_TIMEOUT_SECS: u64 = 300;
+/// Longest timeout `run_tool` accepts.
+const MAX_TOOL_TIMEOUT_SECS: u64 = 4 * 60 * 60;
+/// How much of each output stream `run_tool` keeps.
+const MAX_TOOL_OUTPUT_BYTES: usize = 1024 * 1024;
+/// How long `run_tool` waits for the output readers once the tool has exited or been killed.
+const TOOL_OUTPUT_GRACE: Duration = Duration::from_secs(5);
+
+#[tauri::command]
+/// Run a built-in tool and capture its output.
+///
+/// The executable is resolved like in `get_tool_statuses` (override first, then the saved
+/// programs). The tool is killed when it runs longer than the timeout or when
+/// `cancel_all_operations` is called.
+///
+/// # Arguments
+/// * `tool_key` - A built-in tool key (e.g. "smartctl")
+/// * `args` - Command-line arguments, passed as-is without a shell
+/// * `timeout_seconds` - Kill the tool after this long (default 300, max 4 hours)
+pub async fn run_tool(
+    state: tauri::State<'_, AppState>,
+    tool_key: String,
+    args: Vec<String>,
+    timeout_seconds: Option<u64>,
+) -> Result<ToolRunOutput, String> {
+    let timeout_seconds = timeout_seconds.unwrap_or(DEFAULT_TOOL_TIMEOUT_SECS);
+    if timeout_seconds == 0 || timeout_seconds > MAX_TOOL_TIMEOUT_SECS {
+        return Err(format!(
+            "Timeout must be between 1 and {} seconds",
+            MAX_TOOL_TIMEOUT_SECS
+        ));
+    }
+    let exe = find_known_tool(&state.data_dir(), &tool_key)
+        .ok_or_else(|| format!("{} was not found", tool_display_name(&tool_key)))?;
+
+    let op = state.operations.register("tool_run");
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut cmd = Command::new(&exe);
+        cmd.args(&args);
+        if let Some(dir) = exe.parent() {
+            cmd.current_dir(dir);
+        }
+        let mut child = cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to start {}: {}", exe.display(), e))?;
+
+        // Drain both pipes on their own threads so a chatty tool can't block on a full pipe.
+        let stdout = child.stdout.take().map(CapturedStream::spawn);
+        let stderr = child.stderr.take().map(CapturedStream::spawn);
+
+        let deadline = Instant::now() + Duration::from_secs(timeout_seconds);
+        let cancel = op.token();
+        let mut timed_out = false;
+        let status = loop {
+            if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+                break Some(status);
+            }
+            timed_out = Instant::now() >= deadline;
+            if timed_out || cancel.is_cancelled() {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        };
+
+        // A child process of the tool can inherit the pipes and keep them open after the
+        // tool itself is gone, so the readers only get a short grace period.
+        let drain_deadline = Instant::now() + TOOL_OUTPUT_GRACE;
+        let collect = |stream: Option<CapturedStream>| {
+            stream
+                .map(|s| s.collect(drain_deadline))
+                .unwrap_or_default()
+        };
+        Ok(ToolRunOutput {
+            code: status.and_then(|s| s.code()),
+            stdout: collect(stdout),
+            stderr: collect(stderr),
+            timed_out,
+        })
+    })
+    .await
+    .map_err(|e| format!("Tool run failed: {}", e))?
+}
+
+// Output read from one of a tool's pipes by a background thread.
+struct CapturedStream {
+    kept: Arc<Mutex<Vec<u8>>>,
+    done: mpsc::Receiver<()>,
+}
+
+impl CapturedStream {
+    fn spawn(reader: impl Read + Send + 'static) -> Self {
+        let kept = Arc::new(Mutex::new(Vec::new()));
+        let (tx, done) = mpsc::channel();
+        let shared = Arc::clone(&kept);
+        std::thread::spawn(move || {
+            read_capped(reader, &shared);
+            let _ = tx.send(());
+        });
+        Self { kept, done }
+    }
+
+    // Wait for the reader until `deadline`, then return whatever it has read so far.
+    fn collect(self, deadline: Instant) -> String {
+        let _ = self
+            .done
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()));
+        let kept = self.kept.lock().map(|k| k.clone()).unwrap_or_default();
+        String::from_utf8_lossy(&kept).to_string()
+    }
+}
+
+// Read a stream to the end into `kept`, keeping at most `MAX_TOOL_OUTPUT_BYTES`.
+fn read_capped(mut reader: impl Read, kept: &Mutex<Vec<u8>>) {
+    let mut buf = [0u8; 8192];
+    while let Ok(n) = reader.read(&mut buf) {
+        if n == 0 {
+            break;
+        }
+        let Ok(mut kept) = kept.lock() else {
+            break;
+        };
+        let room = MAX_TOOL_OUTPUT_BYTES.saturating_sub(kept.len());
+        kept.extend_from_slice(&buf[..n.min(room)]);
+    }
+}
+
+// The `tool_path_overrides` setting (tool key -> absolute path).
+fn read_tool_path_overrides(data_root: &Path) -> HashMap<String, String> {
+    crate::settings::read_app_settings(data_root)
+        .ok()
+        .and_then(|v| {
+            v.get("tool_path_overrides")
+                .and_then(|o| o.as_object())
+                .cloned()
+        })
+        .map(|map| {
+            map.into_iter()
+                .filter_map(|(key, path)| Some((key, path.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+/// Point a built-in tool at an exact executable, bypassing the fuzzy match against
+/// saved programs.
+///
+/// Stored in the `tool_path_overrides` setting and used by `get_tool_statuses` and
+/// everything else that resolves tools, for as long as the file exists.
+///
+/// # Arguments
+/// * `tool_key` - A built-in tool key (e.g. "kvrt")
+/// * `path` - Absolute path of an existing executable
+pub fn set_tool_override(
+    state: tauri::State<AppState>,
+    tool_key: String,
+    path: String,
+) -> Result<(), String> {
+    if !KNOWN_TOOLS.iter().any(|(k, _, _, _)| *k == tool_key) {
+        return Err(format!("Unknown tool: {}", tool_key));
+    }
+    let path = PathBuf::from(path.trim());
+    if !path.is_absolute() {
+        return Err("Tool path must be absolute".into());
+    }
+    if !path.is_file() {
+        return Err(format!("File not found: {}", path.display()));
+    }
+    crate::settings::set_tool_path_override(
+        &state.data_dir(),
+        &tool_key,
+        Some(&path.to_string_lossy()),
+    )
+    .map(|_| ())
+}
+
+#[tauri::command]
+/// Remove a tool's path override so it is matched against saved programs again.
+///
+/// # Returns
+/// Whether an override was set
+pub fn clear_tool_override(
+    state: tauri::State<AppState>,
+    tool_key: String,
+) -> Result<bool, String> {
+    crate::settings::set_tool_path_override(&state.data_dir(), &tool_key, None)
+}
+
 // Read `programs.json` into runtime `ProgramEntry` values.
 // Supports both the on-disk schema (`ProgramDiskEntry`) and the runtime schema for backward compatibility.
 // Note: `exe_exists` is computed at runtime and is always initialized to false here.
-fn read_programs_file(path: &Path) -> Vec<ProgramEntry> {
+pub(crate) fn read_programs_file(path: &Path) -> Vec<ProgramEntry> {
     if let Ok(data) = fs::read_to_string(path) {
         if let Ok(list) = serde_json::from_str::<Vec<ProgramDiskEntry>>(&data) {
             return list
@@ -233,6 +739,7 @@ fn read_programs_file(path: &Path) -> Vec<ProgramEntry> {
                     logo_data_url: d.logo_data_url,
                     exe_exists: false,
                     launch_count: d.launch_count,
+                    window_state: d.window_state,
                 })
                 .collect();
         }
@@ -244,14 +751,8 @@ fn read_programs_file(path: &Path) -> Vec<ProgramEntry> {
 }
 
 // Persist `ProgramEntry` values to `programs.json` using the portable on-disk schema.
-// Ensures the parent directory exists and pretty-prints the JSON for easier diffing.
-fn write_programs_file(path: &Path, list: &Vec<ProgramEntry>) -> Result<(), String> {
-    let parent = path
-        .parent()
-        .ok_or_else(|| "Invalid settings path".to_string())?;
-    if let Err(e) = fs::create_dir_all(parent) {
-        return Err(e.to_string());
-    }
+// Writes atomically (creating the parent directory) and pretty-prints the JSON for easier diffing.
+pub(crate) fn write_programs_file(path: &Path, list: &Vec<ProgramEntry>) -> Result<(), String> {
     let disk: Vec<ProgramDiskEntry> = list
         .iter()
         .map(|p| ProgramDiskEntry {
@@ -262,8 +763,9 @@ fn write_programs_file(path: &Path, list: &Vec<ProgramEntry>) -> Result<(), Stri
             exe_path: p.exe_path.clone(),
             logo_data_url: p.logo_data_url.clone(),
             launch_count: p.launch_count,
+            window_state: p.window_state.clone(),
         })
         .collect();
     let data = serde_json::to_string_pretty(&disk).map_err(|e| e.to_string())?;
-    fs::write(path, data).map_err(|e| e.to_string())
+    crate::fs_utils::write_atomic(path, data)
 }
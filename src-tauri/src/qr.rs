@@ -0,0 +1,84 @@
+//! QR code generation for sharing report locations.
+//!
+//! Encodes a network report path (UNC) or URL into a QR code so customers can
+//! scan it, returning a PNG data URL for display and optionally saving the PNG.
+use image::{ImageBuffer, Luma};
+use qrcode::{Color, EcLevel, QrCode};
+
+/// Pixel size of a single QR module in the rendered image.
+const MODULE_PX: u32 = 8;
+/// Quiet-zone width around the code, in modules (the spec recommends 4).
+const QUIET_ZONE: u32 = 4;
+
+#[tauri::command]
+/// Generate a QR code for a report's network path or URL.
+///
+/// # Arguments
+/// * `unc_or_url` - The UNC path or URL to encode (must not be empty)
+/// * `output_path` - Optional PNG file path to also write the image to
+/// * `error_correction` - Optional level: "L", "M" (default), "Q" or "H"
+///
+/// # Returns
+/// A `data:image/png;base64,...` URL of the rendered code
+pub fn generate_report_qr(
+    unc_or_url: String,
+    output_path: Option<String>,
+    error_correction: Option<String>,
+) -> Result<String, String> {
+    let content = unc_or_url.trim();
+    if content.is_empty() {
+        return Err("Nothing to encode: path or URL is empty".into());
+    }
+    let level = parse_ec_level(error_correction.as_deref())?;
+    let png = render_qr_png(content, level)?;
+
+    if let Some(path) = output_path.filter(|p| !p.trim().is_empty()) {
+        std::fs::write(&path, &png).map_err(|e| format!("Failed to write QR image: {}", e))?;
+    }
+
+    let b64 = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &png);
+    Ok(format!("data:image/png;base64,{}", b64))
+}
+
+// Map a user-facing error-correction name to the `qrcode` level (defaults to Medium).
+fn parse_ec_level(level: Option<&str>) -> Result<EcLevel, String> {
+    match level.map(|s| s.trim().to_ascii_uppercase()).as_deref() {
+        None | Some("") | Some("M") => Ok(EcLevel::M),
+        Some("L") => Ok(EcLevel::L),
+        Some("Q") => Ok(EcLevel::Q),
+        Some("H") => Ok(EcLevel::H),
+        Some(other) => Err(format!(
+            "Unknown error correction level '{}' (expected L, M, Q or H)",
+            other
+        )),
+    }
+}
+
+// Render the QR matrix into a grayscale PNG with a quiet zone.
+fn render_qr_png(content: &str, level: EcLevel) -> Result<Vec<u8>, String> {
+    let code = QrCode::with_error_correction_level(content.as_bytes(), level)
+        .map_err(|e| format!("Failed to encode QR code: {}", e))?;
+    let width = code.width() as u32;
+    let colors = code.to_colors();
+    let size = (width + QUIET_ZONE * 2) * MODULE_PX;
+
+    let img = ImageBuffer::from_fn(size, size, |x, y| {
+        let mx = (x / MODULE_PX) as i64 - QUIET_ZONE as i64;
+        let my = (y / MODULE_PX) as i64 - QUIET_ZONE as i64;
+        let inside = mx >= 0 && my >= 0 && mx < width as i64 && my < width as i64;
+        if inside && colors[(my as u32 * width + mx as u32) as usize] == Color::Dark {
+            Luma([0u8])
+        } else {
+            Luma([255u8])
+        }
+    });
+
+    let mut png_buffer = Vec::new();
+    image::DynamicImage::ImageLuma8(img)
+        .write_to(
+            &mut std::io::Cursor::new(&mut png_buffer),
+            image::ImageFormat::Png,
+        )
+        .map_err(|e| format!("PNG encode failed: {}", e))?;
+    Ok(png_buffer)
+}
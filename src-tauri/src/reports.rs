@@ -3,12 +3,15 @@
 /// Handles saving, loading, listing, and deleting service run reports in the data/reports directory.
 /// Each report is saved in a dedicated folder with a descriptive name including
 /// PC hostname, customer name (if available), and timestamp.
+use crate::operations::CancelToken;
 use crate::state::AppState;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::Emitter;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SaveReportRequest {
@@ -34,6 +37,9 @@ pub struct SaveReportResponse {
     pub report_folder: Option<String>,
     /// Error message if save failed
     pub error: Option<String>,
+    /// Set when the generated folder name was already taken and a `_N` suffix was
+    /// added to keep the reports apart
+    pub folder_conflict: bool,
 }
 
 /// Saves a service report to a dedicated folder in data/reports.
@@ -56,7 +62,8 @@ pub fn save_report(
     state: tauri::State<AppState>,
     request: SaveReportRequest,
 ) -> Result<SaveReportResponse, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let reports_dir = data_root.join("reports");
 
     // Ensure reports directory exists
@@ -65,6 +72,7 @@ pub fn save_report(
             success: false,
             report_folder: None,
             error: Some(format!("Failed to create reports directory: {}", e)),
+            folder_conflict: false,
         });
     }
 
@@ -74,6 +82,61 @@ pub fn save_report(
         .unwrap_or_default()
         .as_secs();
 
+    match write_report_folder(&reports_dir, &request, timestamp) {
+        Ok((report_folder, folder_conflict)) => Ok(SaveReportResponse {
+            success: true,
+            report_folder: Some(report_folder.to_string_lossy().to_string()),
+            error: None,
+            folder_conflict,
+        }),
+        Err(error) => Ok(SaveReportResponse {
+            success: false,
+            report_folder: None,
+            error: Some(error),
+            folder_conflict: false,
+        }),
+    }
+}
+
+/// Names to preview a report folder name for (see `preview_report_folder_name`)
+#[derive(Debug, Deserialize)]
+pub struct FolderNamePreviewRequest {
+    pub hostname: Option<String>,
+    pub customer_name: Option<String>,
+    pub technician_name: Option<String>,
+    /// Unix timestamp to use; defaults to now
+    pub timestamp: Option<u64>,
+}
+
+/// Returns the folder name `save_report` would generate, without creating anything
+///
+/// Runs the same sanitization as a real save so the UI can show how the
+/// host/customer/technician fields will appear. If a folder with that name already
+/// exists the actual save adds a `_N` suffix.
+#[tauri::command]
+pub fn preview_report_folder_name(request: FolderNamePreviewRequest) -> String {
+    let timestamp = request.timestamp.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    });
+    generate_folder_name(
+        request.hostname.as_deref(),
+        request.customer_name.as_deref(),
+        request.technician_name.as_deref(),
+        timestamp,
+    )
+}
+
+// Create a new report folder in `reports_dir` and write the report files into it.
+// Two saves in the same second get distinct folders (`..._2`, `..._3`, ...) instead of
+// the second one merging into the first; the returned flag says whether that happened.
+fn write_report_folder(
+    reports_dir: &Path,
+    request: &SaveReportRequest,
+    timestamp: u64,
+) -> Result<(PathBuf, bool), String> {
     let folder_name = generate_folder_name(
         request.hostname.as_deref(),
         request.customer_name.as_deref(),
@@ -81,26 +144,22 @@ pub fn save_report(
         timestamp,
     );
 
-    let report_folder = reports_dir.join(&folder_name);
-
     // Create report folder
-    if let Err(e) = fs::create_dir_all(&report_folder) {
-        return Ok(SaveReportResponse {
-            success: false,
-            report_folder: None,
-            error: Some(format!("Failed to create report folder: {}", e)),
-        });
+    let report_folder = create_unique_dir(reports_dir, &folder_name)
+        .map_err(|e| format!("Failed to create report folder: {}", e))?;
+    let folder_conflict = report_folder.file_name() != Some(std::ffi::OsStr::new(&folder_name));
+    if folder_conflict {
+        eprintln!(
+            "Warning: Report folder {} already exists, saving to {}",
+            folder_name,
+            report_folder.display()
+        );
     }
 
     // Save report.json
     let report_file = report_folder.join("report.json");
-    if let Err(e) = fs::write(&report_file, &request.report_json) {
-        return Ok(SaveReportResponse {
-            success: false,
-            report_folder: None,
-            error: Some(format!("Failed to write report.json: {}", e)),
-        });
-    }
+    fs::write(&report_file, &request.report_json)
+        .map_err(|e| format!("Failed to write report.json: {}", e))?;
 
     // Copy run plan if provided
     if let Some(plan_path) = &request.plan_file_path {
@@ -124,6 +183,11 @@ pub fn save_report(
         }
     }
 
+    // Derive a pass/fail outcome so listings don't need to parse report.json
+    let overall_status = serde_json::from_str::<serde_json::Value>(&request.report_json)
+        .ok()
+        .and_then(|v| compute_overall_status(&v));
+
     // Save metadata.json
     let metadata = serde_json::json!({
         "timestamp": timestamp,
@@ -131,6 +195,7 @@ pub fn save_report(
         "customer_name": request.customer_name,
         "technician_name": request.technician_name,
         "saved_at": chrono::Local::now().to_rfc3339(),
+        "overall_status": overall_status,
     });
 
     let metadata_file = report_folder.join("metadata.json");
@@ -141,11 +206,59 @@ pub fn save_report(
         eprintln!("Warning: Failed to write metadata.json: {}", e);
     }
 
-    Ok(SaveReportResponse {
-        success: true,
-        report_folder: Some(report_folder.to_string_lossy().to_string()),
-        error: None,
-    })
+    Ok((report_folder, folder_conflict))
+}
+
+// Create `dir/base`, or `dir/base_2`, `dir/base_3`, ... when it already exists.
+// `create_dir` fails if the folder exists, so concurrent callers never share a folder.
+fn create_unique_dir(dir: &Path, base: &str) -> io::Result<PathBuf> {
+    let mut path = dir.join(base);
+    let mut n = 1;
+    loop {
+        match fs::create_dir(&path) {
+            Ok(()) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                n += 1;
+                path = dir.join(format!("{}_{}", base, n));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// True if `name` is a single path component that cannot point outside its parent folder
+fn is_plain_file_name(name: &str) -> bool {
+    !(name.is_empty() || name.contains(['/', '\\', ':']) || name == "." || name == "..")
+}
+
+/// Rejects report folder names that are empty or would resolve outside the reports directory
+pub(crate) fn validate_report_folder_name(folder_name: &str) -> Result<(), String> {
+    if is_plain_file_name(folder_name) {
+        Ok(())
+    } else {
+        Err(format!("Invalid report folder name: {}", folder_name))
+    }
+}
+
+/// Computes an overall "success"/"failure" outcome from a runner report
+///
+/// A run is a failure when any task result has a failing status; otherwise it is a
+/// success. Falls back to the runner's own `overall_status` when there is no
+/// `results` array, and returns None when neither is present.
+fn compute_overall_status(report: &serde_json::Value) -> Option<String> {
+    if let Some(results) = report.get("results").and_then(|r| r.as_array()) {
+        let failed = results.iter().any(|r| {
+            matches!(
+                r.get("status").and_then(|s| s.as_str()),
+                Some("failure") | Some("error") | Some("fail")
+            )
+        });
+        return Some(if failed { "failure" } else { "success" }.to_string());
+    }
+    report
+        .get("overall_status")
+        .and_then(|s| s.as_str())
+        .map(|s| if s == "success" { "success" } else { "failure" }.to_string())
 }
 
 /// Metadata structure for saved reports
@@ -156,6 +269,12 @@ pub struct ReportMetadata {
     pub customer_name: Option<String>,
     pub technician_name: Option<String>,
     pub saved_at: String,
+    /// Overall run outcome computed at save time ("success" or "failure"); None for older reports
+    #[serde(default)]
+    pub overall_status: Option<String>,
+    /// Cached content hash of report.json + run_plan.json (see `get_report_content_hash`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 /// List item for a saved report
@@ -167,6 +286,9 @@ pub struct ReportListItem {
     pub has_report_json: bool,
     pub has_execution_log: bool,
     pub has_run_plan: bool,
+    /// Total size of the folder in bytes; only computed when a listing asks for it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
 }
 
 /// Lists all saved reports in the data/reports directory
@@ -176,69 +298,177 @@ pub struct ReportListItem {
 ///
 /// # Arguments
 /// * `state` - Application state containing data directory path
+/// * `status` - Optional filter on the saved `overall_status` ("success"/"failure")
 ///
 /// # Returns
 /// A vector of report list items with metadata
 #[tauri::command]
-pub fn list_reports(state: tauri::State<AppState>) -> Result<Vec<ReportListItem>, String> {
-    let data_root = state.data_dir.as_path();
-    let reports_dir = data_root.join("reports");
+pub fn list_reports(
+    state: tauri::State<AppState>,
+    status: Option<String>,
+) -> Result<Vec<ReportListItem>, String> {
+    let reports_dir = state.data_dir().join("reports");
+    let page = read_reports_page(&reports_dir, 0, usize::MAX, ReportSort::Timestamp)?;
+
+    // Apply the optional outcome filter
+    let reports = match status.as_deref() {
+        Some(wanted) => page
+            .items
+            .into_iter()
+            .filter(|r| {
+                r.metadata
+                    .as_ref()
+                    .and_then(|m| m.overall_status.as_deref())
+                    == Some(wanted)
+            })
+            .collect(),
+        None => page.items,
+    };
+    Ok(reports)
+}
+
+/// Maximum page size accepted by `list_reports_paged`
+const MAX_REPORT_PAGE_SIZE: usize = 500;
+
+/// One page of the saved report list
+#[derive(Debug, Serialize)]
+pub struct ReportPage {
+    pub items: Vec<ReportListItem>,
+    /// Number of report folders in total (across all pages)
+    pub total_count: usize,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// Sort order for report listings
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReportSort {
+    /// Newest first, by the timestamp in the folder name (folder mtime as fallback)
+    Timestamp,
+    /// Folder name, A to Z
+    Name,
+}
+
+/// Lists one page of the saved reports in data/reports
+///
+/// Only directory entries are read to count and sort the reports; metadata.json and
+/// the file checks are done just for the reports on the requested page, so this stays
+/// fast with thousands of report folders.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `offset` - Number of reports to skip
+/// * `limit` - Page size (1 to 500)
+/// * `sort_by` - "timestamp" (newest first, default) or "name"
+///
+/// # Returns
+/// The reports on the page plus the total report count
+#[tauri::command]
+pub fn list_reports_paged(
+    state: tauri::State<AppState>,
+    offset: usize,
+    limit: usize,
+    sort_by: Option<String>,
+) -> Result<ReportPage, String> {
+    if limit == 0 || limit > MAX_REPORT_PAGE_SIZE {
+        return Err(format!(
+            "Page size must be between 1 and {}",
+            MAX_REPORT_PAGE_SIZE
+        ));
+    }
+    let sort = match sort_by.as_deref().unwrap_or("timestamp") {
+        "timestamp" => ReportSort::Timestamp,
+        "name" => ReportSort::Name,
+        other => return Err(format!("Unknown sort order: {}", other)),
+    };
+    read_reports_page(&state.data_dir().join("reports"), offset, limit, sort)
+}
 
+// Sort all report folders by a key derived from directory entries alone, then build
+// list items (reading metadata.json) only for the requested window.
+fn read_reports_page(
+    reports_dir: &Path,
+    offset: usize,
+    limit: usize,
+    sort: ReportSort,
+) -> Result<ReportPage, String> {
     // Ensure reports directory exists
     if !reports_dir.exists() {
-        return Ok(Vec::new());
+        return Ok(ReportPage {
+            items: Vec::new(),
+            total_count: 0,
+            offset,
+            limit,
+        });
     }
 
-    let mut reports = Vec::new();
-
-    // Read directory entries
-    let entries = fs::read_dir(&reports_dir)
+    let entries = fs::read_dir(reports_dir)
         .map_err(|e| format!("Failed to read reports directory: {}", e))?;
 
-    for entry in entries {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
-
-        let path = entry.path();
-
-        // Only process directories (skip temporary JSON files)
-        if !path.is_dir() {
-            continue;
-        }
+    // (sort key, folder name, path); skip temporary JSON files and other non-directories
+    let mut folders: Vec<(String, String, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|entry| {
+            let folder_name = entry.file_name().to_string_lossy().to_string();
+            let key = match sort {
+                ReportSort::Timestamp => folder_sort_timestamp(&folder_name, &entry),
+                ReportSort::Name => folder_name.to_lowercase(),
+            };
+            (key, folder_name, entry.path())
+        })
+        .collect();
 
-        let folder_name = match path.file_name() {
-            Some(name) => name.to_string_lossy().to_string(),
-            None => continue,
-        };
+    match sort {
+        ReportSort::Timestamp => folders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1))),
+        ReportSort::Name => folders.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1))),
+    }
 
-        // Check for required files
-        let has_report_json = path.join("report.json").exists();
-        let has_execution_log = path.join("execution.log").exists();
-        let has_run_plan = path.join("run_plan.json").exists();
+    let total_count = folders.len();
+    let items = folders
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|(_, folder_name, path)| report_list_item(folder_name, &path))
+        .collect();
 
-        // Read metadata if available
-        let metadata = read_metadata(&path);
+    Ok(ReportPage {
+        items,
+        total_count,
+        offset,
+        limit,
+    })
+}
 
-        reports.push(ReportListItem {
-            folder_name,
-            folder_path: to_user_visible_path(&path),
-            metadata,
-            has_report_json,
-            has_execution_log,
-            has_run_plan,
-        });
+// Timestamp sort key of a report folder: the `YYYY-MM-DD_HH-MM-SS` part after `__` in
+// its name (ignoring any suffix added to make the name unique), else its mtime.
+fn folder_sort_timestamp(folder_name: &str, entry: &fs::DirEntry) -> String {
+    match folder_name_timestamp(folder_name) {
+        Some(ts) => ts.to_string(),
+        None => {
+            let modified = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            format_folder_timestamp(modified)
+        }
     }
+}
 
-    // Sort by timestamp (newest first)
-    reports.sort_by(|a, b| {
-        let a_time = a.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
-        let b_time = b.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
-        b_time.cmp(&a_time)
-    });
-
-    Ok(reports)
+// Build the list item for one report folder.
+fn report_list_item(folder_name: String, path: &Path) -> ReportListItem {
+    ReportListItem {
+        folder_name,
+        folder_path: to_user_visible_path(path),
+        metadata: read_metadata(path),
+        has_report_json: path.join("report.json").exists(),
+        has_execution_log: has_execution_log(path),
+        has_run_plan: path.join("run_plan.json").exists(),
+        size_bytes: None,
+    }
 }
 
 /// Loaded report data including JSON content and metadata
@@ -248,6 +478,18 @@ pub struct LoadedReport {
     pub execution_log: Option<String>,
     pub run_plan: Option<String>,
     pub metadata: ReportMetadata,
+    /// Free-form technician notes from notes.txt (optional)
+    pub tech_notes: Option<String>,
+    /// Timestamped follow-up notes from notes.json (see `add_report_note`)
+    pub notes: Vec<ReportNote>,
+}
+
+/// A timestamped technician note stored in a report's notes.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportNote {
+    /// When the note was added (RFC 3339, local time)
+    pub timestamp: String,
+    pub text: String,
 }
 
 /// Loads a specific report's data from disk
@@ -266,7 +508,8 @@ pub fn load_report(
     state: tauri::State<AppState>,
     folder_name: String,
 ) -> Result<LoadedReport, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let report_folder = data_root.join("reports").join(&folder_name);
 
     // Verify folder exists
@@ -286,15 +529,8 @@ pub fn load_report(
     let metadata = read_metadata(&report_folder)
         .ok_or_else(|| "metadata.json not found or invalid".to_string())?;
 
-    // Read execution.log (optional)
-    let execution_log = {
-        let log_path = report_folder.join("execution.log");
-        if log_path.exists() {
-            fs::read_to_string(&log_path).ok()
-        } else {
-            None
-        }
-    };
+    // Read execution.log or execution.log.gz (optional)
+    let execution_log = read_execution_log(&report_folder);
 
     // Read run_plan.json (optional)
     let run_plan = {
@@ -306,11 +542,17 @@ pub fn load_report(
         }
     };
 
+    // Read notes.txt and notes.json (optional)
+    let tech_notes = fs::read_to_string(report_folder.join("notes.txt")).ok();
+    let notes = read_report_notes(&report_folder);
+
     Ok(LoadedReport {
         report_json,
         execution_log,
         run_plan,
         metadata,
+        tech_notes,
+        notes,
     })
 }
 
@@ -336,14 +578,7 @@ pub fn load_report_from_path(folder_path: String) -> Result<LoadedReport, String
     let metadata = read_metadata(&report_folder)
         .ok_or_else(|| "metadata.json not found or invalid".to_string())?;
 
-    let execution_log = {
-        let log_path = report_folder.join("execution.log");
-        if log_path.exists() {
-            fs::read_to_string(&log_path).ok()
-        } else {
-            None
-        }
-    };
+    let execution_log = read_execution_log(&report_folder);
 
     let run_plan = {
         let plan_path = report_folder.join("run_plan.json");
@@ -354,14 +589,336 @@ pub fn load_report_from_path(folder_path: String) -> Result<LoadedReport, String
         }
     };
 
+    let tech_notes = fs::read_to_string(report_folder.join("notes.txt")).ok();
+    let notes = read_report_notes(&report_folder);
+
     Ok(LoadedReport {
         report_json,
         execution_log,
         run_plan,
         metadata,
+        tech_notes,
+        notes,
     })
 }
 
+/// File name of a compressed execution log (see `compress_report_logs`)
+const COMPRESSED_LOG_NAME: &str = "execution.log.gz";
+
+/// Logs smaller than this are not worth compressing by default
+const DEFAULT_LOG_COMPRESS_MIN_BYTES: u64 = 64 * 1024;
+
+/// Compresses a report's execution.log to execution.log.gz
+///
+/// Logs are usually the bulk of a report, so compressing them shrinks the folder and
+/// speeds up network copies. `load_report` and the listings read either form. Logs
+/// smaller than `min_bytes` (default 64 KiB) are left as they are.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `min_bytes` - Only compress logs at least this large
+///
+/// # Returns
+/// True if the log was compressed, false if there was nothing to do
+#[tauri::command]
+pub async fn compress_report_logs(
+    state: tauri::State<'_, AppState>,
+    folder_name: String,
+    min_bytes: Option<u64>,
+) -> Result<bool, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    let min_bytes = min_bytes.unwrap_or(DEFAULT_LOG_COMPRESS_MIN_BYTES);
+    tauri::async_runtime::spawn_blocking(move || compress_execution_log(&report_folder, min_bytes))
+        .await
+        .map_err(|e| format!("Log compression failed: {}", e))?
+}
+
+// Gzip `execution.log` into `execution.log.gz` and remove the original. The archive is
+// written under a temporary name first so an interrupted run never leaves a truncated
+// .gz next to (or instead of) the original log.
+fn compress_execution_log(report_folder: &Path, min_bytes: u64) -> Result<bool, String> {
+    let log_path = report_folder.join("execution.log");
+    let size = match fs::metadata(&log_path) {
+        Ok(m) => m.len(),
+        Err(_) => return Ok(false),
+    };
+    if size < min_bytes {
+        return Ok(false);
+    }
+
+    let gz_path = report_folder.join(COMPRESSED_LOG_NAME);
+    let tmp_path = report_folder.join(format!("{}.tmp", COMPRESSED_LOG_NAME));
+    let result = (|| -> io::Result<()> {
+        let mut input = fs::File::open(&log_path)?;
+        let output = fs::File::create(&tmp_path)?;
+        let mut encoder = flate2::write::GzEncoder::new(output, flate2::Compression::default());
+        io::copy(&mut input, &mut encoder)?;
+        encoder.finish()?.sync_all()?;
+        fs::rename(&tmp_path, &gz_path)
+    })();
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(format!("Failed to compress execution.log: {}", e));
+    }
+    fs::remove_file(&log_path).map_err(|e| format!("Failed to remove execution.log: {}", e))?;
+    Ok(true)
+}
+
+// Read a report's execution log, decompressing execution.log.gz when that is what exists.
+fn read_execution_log(report_folder: &Path) -> Option<String> {
+    let log_path = report_folder.join("execution.log");
+    if log_path.exists() {
+        return fs::read_to_string(&log_path).ok();
+    }
+    let file = fs::File::open(report_folder.join(COMPRESSED_LOG_NAME)).ok()?;
+    let mut text = String::new();
+    io::Read::read_to_string(&mut flate2::read::GzDecoder::new(file), &mut text).ok()?;
+    Some(text)
+}
+
+fn has_execution_log(report_folder: &Path) -> bool {
+    report_folder.join("execution.log").exists() || report_folder.join(COMPRESSED_LOG_NAME).exists()
+}
+
+/// Records technician notes on a report
+///
+/// Notes are stored in `notes.txt` inside the report folder (never in the folder
+/// name), so they travel with the report when it is copied to a network share.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `notes` - Note text to store
+/// * `append` - Append to existing notes on a new line instead of replacing them
+///
+/// # Returns
+/// The full notes text now stored for the report
+#[tauri::command]
+pub fn set_report_notes(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    notes: String,
+    append: Option<bool>,
+) -> Result<String, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    let notes_path = report_folder.join("notes.txt");
+
+    let combined = if append.unwrap_or(false) {
+        match fs::read_to_string(&notes_path) {
+            Ok(existing) if !existing.trim().is_empty() => {
+                format!("{}\n{}", existing.trim_end(), notes)
+            }
+            _ => notes,
+        }
+    } else {
+        notes
+    };
+
+    if combined.trim().is_empty() {
+        // Clearing notes removes the file rather than leaving an empty one behind.
+        if notes_path.exists() {
+            fs::remove_file(&notes_path).map_err(|e| format!("Failed to clear notes: {}", e))?;
+        }
+        return Ok(String::new());
+    }
+
+    crate::fs_utils::write_atomic(&notes_path, &combined)?;
+    Ok(combined)
+}
+
+/// Appends a timestamped note to a report
+///
+/// Notes are kept in a `notes.json` array inside the report folder (created on first
+/// use), leaving the machine-generated report.json untouched.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `note` - Note text to add
+///
+/// # Returns
+/// All notes now stored for the report, oldest first
+#[tauri::command]
+pub fn add_report_note(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    note: String,
+) -> Result<Vec<ReportNote>, String> {
+    validate_report_folder_name(&folder_name)?;
+    let note = note.trim();
+    if note.is_empty() {
+        return Err("Note cannot be empty".to_string());
+    }
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    append_report_note(&report_folder, note)
+}
+
+fn append_report_note(report_folder: &Path, note: &str) -> Result<Vec<ReportNote>, String> {
+    let notes_path = report_folder.join("notes.json");
+    let mut notes = if notes_path.exists() {
+        let text = fs::read_to_string(&notes_path)
+            .map_err(|e| format!("Failed to read notes.json: {}", e))?;
+        // Refuse to overwrite a notes file we can't parse rather than losing its notes.
+        serde_json::from_str::<Vec<ReportNote>>(&text)
+            .map_err(|e| format!("Failed to parse notes.json: {}", e))?
+    } else {
+        Vec::new()
+    };
+    notes.push(ReportNote {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        text: note.to_string(),
+    });
+    let text = serde_json::to_string_pretty(&notes).map_err(|e| e.to_string())?;
+    crate::fs_utils::write_atomic(&notes_path, text)?;
+    Ok(notes)
+}
+
+fn read_report_notes(report_folder: &Path) -> Vec<ReportNote> {
+    fs::read_to_string(report_folder.join("notes.json"))
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+/// Rebuilds a report's metadata.json when it is missing or unparseable
+///
+/// The timestamp and names are recovered from the folder name (the inverse of
+/// `generate_folder_name`): the hostname is the part before the first underscore, a
+/// trailing technician name is recognised when it matches one of the saved technician
+/// names, and the rest is the customer name with underscores read as spaces. The
+/// outcome is recomputed from report.json. A valid metadata.json is left untouched.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+///
+/// # Returns
+/// The existing or recovered metadata
+#[tauri::command]
+pub fn repair_report_metadata(
+    state: tauri::State<AppState>,
+    folder_name: String,
+) -> Result<ReportMetadata, String> {
+    validate_report_folder_name(&folder_name)?;
+    let data_dir = state.data_dir();
+    let report_folder = data_dir.join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    let technician_names: Vec<String> = crate::settings::read_app_settings(&data_dir)
+        .ok()
+        .and_then(|v| v.pointer("/business/technician_names").cloned())
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    repair_metadata_in(&report_folder, &technician_names)
+}
+
+// Return the folder's metadata, rebuilding and writing metadata.json if it can't be read.
+fn repair_metadata_in(
+    report_folder: &Path,
+    technician_names: &[String],
+) -> Result<ReportMetadata, String> {
+    if let Some(meta) = read_metadata(report_folder) {
+        return Ok(meta);
+    }
+    let folder_name = report_folder
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let timestamp = folder_name_timestamp(&folder_name)
+        .and_then(|ts| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d_%H-%M-%S").ok())
+        .and_then(|dt| u64::try_from(dt.and_utc().timestamp()).ok())
+        .or_else(|| {
+            fs::metadata(report_folder)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+        })
+        .unwrap_or(0);
+    let (hostname, customer_name, technician_name) =
+        parse_folder_names(&folder_name, technician_names);
+    let overall_status = fs::read_to_string(report_folder.join("report.json"))
+        .ok()
+        .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+        .and_then(|v| compute_overall_status(&v));
+    let saved_at = chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap_or_default()
+        .with_timezone(&chrono::Local)
+        .to_rfc3339();
+
+    let metadata = ReportMetadata {
+        timestamp,
+        hostname,
+        customer_name,
+        technician_name,
+        saved_at,
+        overall_status,
+        content_hash: None,
+    };
+    let text = serde_json::to_string_pretty(&metadata).map_err(|e| e.to_string())?;
+    crate::fs_utils::write_atomic(&report_folder.join("metadata.json"), text)?;
+    Ok(metadata)
+}
+
+// Split the name part of a report folder (before `__`) back into
+// (hostname, customer name, technician name). See `repair_report_metadata`.
+fn parse_folder_names(
+    folder_name: &str,
+    technician_names: &[String],
+) -> (Option<String>, Option<String>, Option<String>) {
+    let prefix = folder_name
+        .rsplit_once("__")
+        .map_or(folder_name, |(prefix, _)| prefix);
+    let (hostname, rest) = match prefix.strip_prefix("Unknown_PC") {
+        Some(rest) => (None, rest.trim_start_matches('_')),
+        None => match prefix.split_once('_') {
+            Some((host, rest)) => (Some(host.to_string()), rest),
+            None => (Some(prefix.to_string()), ""),
+        },
+    };
+
+    let mut customer_part = rest;
+    let mut technician_name = None;
+    for tech in technician_names {
+        let sanitized = sanitize_name(tech);
+        if sanitized.is_empty() {
+            continue;
+        }
+        if let Some(customer) = rest
+            .strip_suffix(sanitized.as_str())
+            .and_then(|r| r.strip_suffix('_'))
+        {
+            customer_part = customer;
+            technician_name = Some(tech.clone());
+            break;
+        }
+    }
+
+    let customer_name = match customer_part {
+        "" | "Report" => None,
+        part => Some(part.replace('_', " ")),
+    };
+    (
+        hostname.filter(|h| !h.is_empty()),
+        customer_name,
+        technician_name,
+    )
+}
+
 /// Deletes a report folder and all its contents
 ///
 /// Recursively removes the specified report folder from the data/reports directory.
@@ -374,7 +931,8 @@ pub fn load_report_from_path(folder_path: String) -> Result<LoadedReport, String
 /// True if deletion succeeded, error message otherwise
 #[tauri::command]
 pub fn delete_report(state: tauri::State<AppState>, folder_name: String) -> Result<bool, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let report_folder = data_root.join("reports").join(&folder_name);
 
     // Verify folder exists
@@ -410,7 +968,8 @@ pub fn open_report_folder(
     state: tauri::State<AppState>,
     folder_name: String,
 ) -> Result<bool, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let report_folder = data_root.join("reports").join(&folder_name);
 
     // Verify folder exists
@@ -452,18 +1011,1395 @@ pub fn open_report_folder(
     }
 }
 
-/// Helper function to read and parse metadata.json from a report folder
-fn read_metadata(report_folder: &PathBuf) -> Option<ReportMetadata> {
-    let metadata_path = report_folder.join("metadata.json");
-    if !metadata_path.exists() {
-        return None;
-    }
-
-    let content = fs::read_to_string(&metadata_path).ok()?;
-    serde_json::from_str(&content).ok()
+/// A single problem found while verifying a report folder
+#[derive(Debug, Serialize, Clone)]
+pub struct ReportIssue {
+    /// File the issue relates to (e.g. "report.json")
+    pub file: String,
+    /// "error" for corruption that breaks loading, "warning" for missing optional data
+    pub severity: String,
+    /// Human-readable description of the problem
+    pub message: String,
 }
 
-// ---------------------- Network report sharing ----------------------
+/// Result of verifying a report folder's internal consistency
+#[derive(Debug, Serialize, Clone)]
+pub struct ReportVerification {
+    pub folder_name: String,
+    /// True when no error-level issues were found
+    pub ok: bool,
+    pub issues: Vec<ReportIssue>,
+}
+
+/// Verifies a saved report's internal consistency
+///
+/// Checks that report.json parses, metadata.json parses and its timestamp matches
+/// the one encoded in the folder name, and that the log and run plan are non-empty
+/// when present.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder to verify
+///
+/// # Returns
+/// A structured list of issues per file
+#[tauri::command]
+pub fn verify_report(
+    state: tauri::State<AppState>,
+    folder_name: String,
+) -> Result<ReportVerification, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    Ok(verify_report_dir(&report_folder))
+}
+
+/// Runs the consistency checks used by `verify_report` against any report folder path
+pub(crate) fn verify_report_dir(report_folder: &Path) -> ReportVerification {
+    let folder_name = report_folder
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut issues = Vec::new();
+    let mut issue = |file: &str, severity: &str, message: String| {
+        issues.push(ReportIssue {
+            file: file.to_string(),
+            severity: severity.to_string(),
+            message,
+        });
+    };
+
+    // report.json must exist and contain valid JSON
+    match fs::read_to_string(report_folder.join("report.json")) {
+        Ok(text) if text.trim().is_empty() => {
+            issue("report.json", "error", "File is empty".into());
+        }
+        Ok(text) => {
+            if let Err(e) = serde_json::from_str::<serde_json::Value>(&text) {
+                issue("report.json", "error", format!("Invalid JSON: {}", e));
+            }
+        }
+        Err(e) => issue("report.json", "error", format!("Unreadable: {}", e)),
+    }
+
+    // metadata.json must parse and agree with the folder name's timestamp
+    match fs::read_to_string(report_folder.join("metadata.json")) {
+        Ok(text) => match serde_json::from_str::<ReportMetadata>(&text) {
+            Ok(meta) => {
+                if let Some(folder_ts) = folder_name_timestamp(&folder_name) {
+                    if folder_ts != format_folder_timestamp(meta.timestamp) {
+                        issue(
+                            "metadata.json",
+                            "warning",
+                            format!(
+                                "Timestamp {} does not match folder name timestamp {}",
+                                format_folder_timestamp(meta.timestamp),
+                                folder_ts
+                            ),
+                        );
+                    }
+                }
+            }
+            Err(e) => issue("metadata.json", "error", format!("Invalid metadata: {}", e)),
+        },
+        Err(e) => issue("metadata.json", "error", format!("Unreadable: {}", e)),
+    }
+
+    // Optional companions: must be non-empty when present
+    let log_path = report_folder.join("execution.log");
+    let log_path = if log_path.exists() {
+        log_path
+    } else {
+        report_folder.join(COMPRESSED_LOG_NAME)
+    };
+    match fs::metadata(&log_path) {
+        Ok(m) if m.len() == 0 => issue("execution.log", "error", "File is empty".into()),
+        Ok(_) => {}
+        Err(_) => issue(
+            "execution.log",
+            "warning",
+            "Execution log is missing".into(),
+        ),
+    }
+    match fs::read_to_string(report_folder.join("run_plan.json")) {
+        Ok(text) if text.trim().is_empty() => {
+            issue("run_plan.json", "error", "File is empty".into());
+        }
+        Ok(text) => {
+            if let Err(e) = serde_json::from_str::<serde_json::Value>(&text) {
+                issue("run_plan.json", "error", format!("Invalid JSON: {}", e));
+            }
+        }
+        Err(_) => issue("run_plan.json", "warning", "Run plan is missing".into()),
+    }
+
+    let ok = !issues.iter().any(|i| i.severity == "error");
+    ReportVerification {
+        folder_name,
+        ok,
+        issues,
+    }
+}
+
+/// A report folder that failed verification, as returned by `verify_all_reports`
+#[derive(Debug, Serialize)]
+pub struct ReportProblem {
+    pub folder: String,
+    pub issues: Vec<ReportIssue>,
+}
+
+/// Summary of a bulk verification sweep
+#[derive(Debug, Serialize)]
+pub struct VerifyAllSummary {
+    /// Number of report folders checked (less than the folder count when cancelled)
+    pub total: usize,
+    /// Number of checked folders without error-level issues
+    pub ok: usize,
+    pub problems: Vec<ReportProblem>,
+    /// True when the sweep stopped early via `cancel_verify_all_reports`
+    pub cancelled: bool,
+}
+
+/// Verifies every report folder in data/reports on a worker thread
+///
+/// The sweep can be stopped with `cancel_verify_all_reports`; in that case the
+/// results gathered so far are returned with `cancelled` set.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+///
+/// # Returns
+/// A summary of checked folders and any problems found
+#[tauri::command]
+pub async fn verify_all_reports(
+    state: tauri::State<'_, AppState>,
+) -> Result<VerifyAllSummary, String> {
+    let reports_dir = state.data_dir().join("reports");
+    let op = state.operations.register("verify_all_reports");
+    let cancel = op.token();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let _op = op;
+        let mut summary = VerifyAllSummary {
+            total: 0,
+            ok: 0,
+            problems: Vec::new(),
+            cancelled: false,
+        };
+        if !reports_dir.exists() {
+            return Ok(summary);
+        }
+        let entries = fs::read_dir(&reports_dir)
+            .map_err(|e| format!("Failed to read reports directory: {}", e))?;
+        for entry in entries.flatten() {
+            if cancel.is_cancelled() {
+                summary.cancelled = true;
+                break;
+            }
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let result = verify_report_dir(&path);
+            summary.total += 1;
+            if result.ok {
+                summary.ok += 1;
+            }
+            if !result.issues.is_empty() {
+                summary.problems.push(ReportProblem {
+                    folder: result.folder_name,
+                    issues: result.issues,
+                });
+            }
+        }
+        Ok(summary)
+    })
+    .await
+    .map_err(|e| format!("Verification worker failed: {}", e))?
+}
+
+/// Requests cancellation of an in-progress `verify_all_reports` sweep
+#[tauri::command]
+pub fn cancel_verify_all_reports(state: tauri::State<AppState>) -> Result<(), String> {
+    state.operations.cancel_kind("verify_all_reports");
+    Ok(())
+}
+
+/// The last lines of a report log, as returned by `tail_report_log`
+#[derive(Debug, Serialize)]
+pub struct ReportLogTail {
+    pub lines: Vec<String>,
+    /// Total size of the log file in bytes
+    pub file_size: u64,
+}
+
+/// Reads the last `lines` lines of a log inside a report folder
+///
+/// Reads backwards from the end of the file so multi-megabyte logs don't have
+/// to be loaded in full.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `lines` - Number of trailing lines to return
+/// * `log_name` - Log file inside the folder (defaults to `execution.log`, falling back
+///   to `execution.log.gz` once the log has been compressed)
+///
+/// # Returns
+/// The trailing lines (oldest first) and the total file size
+#[tauri::command]
+pub fn tail_report_log(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    lines: usize,
+    log_name: Option<String>,
+) -> Result<ReportLogTail, String> {
+    let log_path = report_log_path(&state, &folder_name, log_name.as_deref())?;
+    with_log_reader(&log_path, |reader| {
+        let file_size = io::Seek::seek(reader, io::SeekFrom::End(0))?;
+        let lines = read_last_lines(reader, lines)?;
+        Ok(ReportLogTail { lines, file_size })
+    })
+    .map_err(|e| format!("Failed to read log: {}", e))
+}
+
+/// Largest tail `read_report_log_tail` will return
+const MAX_LOG_TAIL_BYTES: u64 = 16 * 1024 * 1024;
+
+/// The end of a report's execution.log, as returned by `read_report_log_tail`
+#[derive(Debug, Serialize)]
+pub struct ReportLogChunk {
+    /// Complete lines from the end of the log
+    pub content: String,
+    /// Byte offset in the file where `content` starts (0 when the whole log was read)
+    pub start_offset: u64,
+    /// Total size of the log file in bytes
+    pub file_size: u64,
+}
+
+/// Reads the last `max_bytes` of a report's execution.log, trimmed to complete lines
+///
+/// Only the tail of the file is read, so the UI can show the recent log of a very large
+/// execution.log quickly and load the full log separately on request.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `max_bytes` - Upper bound on the bytes read from the end of the log (max 16 MiB)
+///
+/// # Returns
+/// The trailing lines, where they start in the file, and the total file size
+#[tauri::command]
+pub fn read_report_log_tail(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    max_bytes: u64,
+) -> Result<ReportLogChunk, String> {
+    if max_bytes == 0 || max_bytes > MAX_LOG_TAIL_BYTES {
+        return Err(format!(
+            "max_bytes must be between 1 and {}",
+            MAX_LOG_TAIL_BYTES
+        ));
+    }
+    let log_path = report_log_path(&state, &folder_name, None)?;
+    with_log_reader(&log_path, |reader| read_tail_bytes(reader, max_bytes))
+        .map_err(|e| format!("Failed to read log: {}", e))
+}
+
+// Read at most `max_bytes` from the end of the log, dropping a leading partial line when
+// the read started mid-file.
+fn read_tail_bytes(file: &mut dyn ReadSeek, max_bytes: u64) -> io::Result<ReportLogChunk> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let file_size = file.seek(SeekFrom::End(0))?;
+    let mut start = file_size.saturating_sub(max_bytes);
+    let mut buf = Vec::with_capacity((file_size - start) as usize);
+    file.seek(SeekFrom::Start(start))?;
+    (&mut *file).take(file_size - start).read_to_end(&mut buf)?;
+
+    // Unless we are at the start of the file or right after a newline, the first line is partial.
+    let mut skip = 0;
+    if start > 0 {
+        let mut prev = [0u8; 1];
+        file.seek(SeekFrom::Start(start - 1))?;
+        file.read_exact(&mut prev)?;
+        if prev[0] != b'\n' {
+            skip = buf
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(buf.len(), |i| i + 1);
+        }
+    }
+    start += skip as u64;
+    Ok(ReportLogChunk {
+        content: String::from_utf8_lossy(&buf[skip..]).into_owned(),
+        start_offset: start,
+        file_size,
+    })
+}
+
+/// A log opened by `with_log_reader`: the file itself or its decompressed contents
+trait ReadSeek: io::Read + io::Seek {}
+impl<T: io::Read + io::Seek> ReadSeek for T {}
+
+// Run `f` on a log file, decompressing it first when it is gzipped (`execution.log.gz`).
+fn with_log_reader<T>(
+    path: &Path,
+    f: impl FnOnce(&mut dyn ReadSeek) -> io::Result<T>,
+) -> io::Result<T> {
+    let file = fs::File::open(path)?;
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut data = Vec::new();
+        io::Read::read_to_end(&mut flate2::read::GzDecoder::new(file), &mut data)?;
+        f(&mut io::Cursor::new(data))
+    } else {
+        f(&mut io::BufReader::new(file))
+    }
+}
+
+/// Resolves a log file inside a report folder, rejecting folder or log names that escape it.
+/// The default `execution.log` falls back to `execution.log.gz` after compression.
+fn report_log_path(
+    state: &tauri::State<AppState>,
+    folder_name: &str,
+    log_name: Option<&str>,
+) -> Result<PathBuf, String> {
+    validate_report_folder_name(folder_name)?;
+    let log_name = log_name.unwrap_or("execution.log");
+    if !is_plain_file_name(log_name) {
+        return Err(format!("Invalid log name: {}", log_name));
+    }
+    let report_folder = state.data_dir().join("reports").join(folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    let log_path = report_folder.join(log_name);
+    if !log_path.is_file() {
+        let compressed = report_folder.join(COMPRESSED_LOG_NAME);
+        if log_name == "execution.log" && compressed.is_file() {
+            return Ok(compressed);
+        }
+        return Err(format!("{} not found in report folder", log_name));
+    }
+    Ok(log_path)
+}
+
+/// Upper bound on matches returned by `search_report_log`
+const MAX_LOG_MATCHES: usize = 500;
+
+/// A single match from `search_report_log`
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct LogMatch {
+    /// 1-based line number of the matching line
+    pub line_number: usize,
+    pub line: String,
+    /// Up to `context_lines` lines preceding the match
+    pub before: Vec<String>,
+    /// Up to `context_lines` lines following the match
+    pub after: Vec<String>,
+}
+
+/// Result of searching a report log
+#[derive(Debug, Serialize)]
+pub struct LogSearchResult {
+    pub matches: Vec<LogMatch>,
+    /// True when more matches existed than the returned bound
+    pub truncated: bool,
+}
+
+/// Searches a report's log for a query and returns matches with surrounding context
+///
+/// Matching is case-insensitive unless `case_sensitive` is set; with `regex` the
+/// query is treated as a regular expression. At most 500 matches are returned.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `query` - Text or pattern to search for
+/// * `context_lines` - Number of lines of context to include before/after each match
+/// * `regex` - Treat `query` as a regular expression
+/// * `case_sensitive` - Match case exactly
+/// * `log_name` - Log file inside the folder (defaults to `execution.log`, falling back
+///   to `execution.log.gz` once the log has been compressed)
+///
+/// # Returns
+/// The bounded list of matches with their line numbers
+#[tauri::command]
+pub fn search_report_log(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    query: String,
+    context_lines: Option<usize>,
+    regex: Option<bool>,
+    case_sensitive: Option<bool>,
+    log_name: Option<String>,
+) -> Result<LogSearchResult, String> {
+    if query.is_empty() {
+        return Err("Search query is empty".into());
+    }
+    let log_path = report_log_path(&state, &folder_name, log_name.as_deref())?;
+    let case_sensitive = case_sensitive.unwrap_or(false);
+
+    let matcher: Box<dyn Fn(&str) -> bool> = if regex.unwrap_or(false) {
+        let re = regex::RegexBuilder::new(&query)
+            .case_insensitive(!case_sensitive)
+            .build()
+            .map_err(|e| format!("Invalid regular expression: {}", e))?;
+        Box::new(move |line: &str| re.is_match(line))
+    } else if case_sensitive {
+        Box::new(move |line: &str| line.contains(&query))
+    } else {
+        let needle = query.to_lowercase();
+        Box::new(move |line: &str| line.to_lowercase().contains(&needle))
+    };
+
+    with_log_reader(&log_path, |reader| {
+        search_lines(
+            io::BufReader::new(reader),
+            matcher.as_ref(),
+            context_lines.unwrap_or(2),
+            MAX_LOG_MATCHES,
+        )
+    })
+    .map_err(|e| format!("Failed to read log: {}", e))
+}
+
+/// Streams lines from `reader`, collecting matches with before/after context
+fn search_lines<R: io::BufRead>(
+    reader: R,
+    matcher: &dyn Fn(&str) -> bool,
+    context: usize,
+    max_matches: usize,
+) -> io::Result<LogSearchResult> {
+    let mut before: std::collections::VecDeque<String> = std::collections::VecDeque::new();
+    let mut matches: Vec<LogMatch> = Vec::new();
+    // Indices into `matches` that still need trailing context lines
+    let mut open: Vec<usize> = Vec::new();
+    let mut truncated = false;
+
+    // Split on raw bytes so logs with stray non-UTF-8 output don't abort the search.
+    for (idx, raw) in reader.split(b'\n').enumerate() {
+        let raw = raw?;
+        let line = String::from_utf8_lossy(raw.strip_suffix(b"\r").unwrap_or(&raw)).to_string();
+
+        open.retain(|&m| {
+            let entry = &mut matches[m];
+            entry.after.push(line.clone());
+            entry.after.len() < context
+        });
+
+        if matcher(&line) {
+            if matches.len() >= max_matches {
+                truncated = true;
+                if open.is_empty() {
+                    break;
+                }
+            } else {
+                matches.push(LogMatch {
+                    line_number: idx + 1,
+                    line: line.clone(),
+                    before: before.iter().cloned().collect(),
+                    after: Vec::new(),
+                });
+                if context > 0 {
+                    open.push(matches.len() - 1);
+                }
+            }
+        } else if truncated && open.is_empty() {
+            break;
+        }
+
+        if context > 0 {
+            if before.len() == context {
+                before.pop_front();
+            }
+            before.push_back(line);
+        }
+    }
+
+    Ok(LogSearchResult { matches, truncated })
+}
+
+/// Reads the last `count` lines of a file by scanning backwards in fixed-size chunks
+fn read_last_lines(file: &mut dyn ReadSeek, count: usize) -> io::Result<Vec<String>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    const CHUNK: u64 = 8 * 1024;
+    let len = file.seek(SeekFrom::End(0))?;
+    if count == 0 || len == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Collect chunks from the end until we've seen more newlines than requested lines
+    // (one extra accounts for a trailing newline at EOF).
+    let mut buf: Vec<u8> = Vec::new();
+    let mut pos = len;
+    while pos > 0 && buf.iter().filter(|&&b| b == b'\n').count() <= count {
+        let start = pos.saturating_sub(CHUNK);
+        let mut chunk = vec![0u8; (pos - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&buf);
+        buf = chunk;
+        pos = start;
+    }
+
+    let text = String::from_utf8_lossy(&buf);
+    let all: Vec<&str> = text.lines().collect();
+    // When we stopped mid-file, the first line is likely partial; drop it if we have enough.
+    let skip = all.len().saturating_sub(count);
+    Ok(all[skip..].iter().map(|l| l.to_string()).collect())
+}
+
+/// Extracts the `YYYY-MM-DD_HH-MM-SS` suffix that `generate_folder_name` appends after `__`
+///
+/// A trailing `_N` added by `save_report` to keep same-second folders apart is ignored.
+fn folder_name_timestamp(folder_name: &str) -> Option<&str> {
+    let (_, suffix) = folder_name.rsplit_once("__")?;
+    let timestamp = suffix.get(..19)?;
+    let extra = &suffix[19..];
+    if !(extra.is_empty()
+        || extra
+            .strip_prefix('_')
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit())))
+    {
+        return None;
+    }
+    chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d_%H-%M-%S").ok()?;
+    Some(timestamp)
+}
+
+/// Formats a Unix timestamp the same way `generate_folder_name` does
+fn format_folder_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap_or_else(chrono::Utc::now)
+        .format("%Y-%m-%d_%H-%M-%S")
+        .to_string()
+}
+
+/// Computes (or returns the cached) content hash for a report
+///
+/// The hash covers report.json and run_plan.json in canonical form (object keys
+/// sorted), so reformatting or key reordering does not change it. The result is
+/// cached in metadata.json as `content_hash`.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `refresh` - Recompute even when a cached hash exists
+///
+/// # Returns
+/// The hex-encoded SHA-256 content hash
+#[tauri::command]
+pub fn get_report_content_hash(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    refresh: Option<bool>,
+) -> Result<String, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+    if !report_folder.is_dir() {
+        return Err(format!("Report folder not found: {}", folder_name));
+    }
+    report_content_hash(&report_folder, refresh.unwrap_or(false))
+}
+
+/// A set of report folders sharing identical content
+#[derive(Debug, Serialize)]
+pub struct DuplicateReportGroup {
+    pub hash: String,
+    pub folders: Vec<String>,
+}
+
+/// Groups local reports by content hash and returns groups with more than one folder
+///
+/// Hashes are computed on demand (and cached) for reports that don't have one yet.
+/// Reports whose report.json can't be read are skipped.
+#[tauri::command]
+pub fn find_duplicate_reports(
+    state: tauri::State<AppState>,
+) -> Result<Vec<DuplicateReportGroup>, String> {
+    let reports_dir = state.data_dir().join("reports");
+    if !reports_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(&reports_dir)
+        .map_err(|e| format!("Failed to read reports directory: {}", e))?;
+
+    let mut groups: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Ok(hash) = report_content_hash(&path, false) {
+            groups
+                .entry(hash)
+                .or_default()
+                .push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .filter(|(_, folders)| folders.len() > 1)
+        .map(|(hash, mut folders)| {
+            folders.sort();
+            DuplicateReportGroup { hash, folders }
+        })
+        .collect())
+}
+
+/// Returns the cached content hash from metadata.json, computing and caching it when needed
+fn report_content_hash(report_folder: &Path, refresh: bool) -> Result<String, String> {
+    if !refresh {
+        if let Some(hash) = read_metadata(report_folder).and_then(|m| m.content_hash) {
+            return Ok(hash);
+        }
+    }
+
+    let hash = hash_report_contents(report_folder)?;
+
+    // Cache in metadata.json, preserving any fields we don't model.
+    let metadata_path = report_folder.join("metadata.json");
+    if let Some(mut meta) = fs::read_to_string(&metadata_path)
+        .ok()
+        .and_then(|t| serde_json::from_str::<serde_json::Value>(&t).ok())
+    {
+        if let Some(obj) = meta.as_object_mut() {
+            obj.insert(
+                "content_hash".into(),
+                serde_json::Value::String(hash.clone()),
+            );
+            if let Ok(pretty) = serde_json::to_string_pretty(&meta) {
+                if let Err(e) = crate::fs_utils::write_atomic(&metadata_path, pretty) {
+                    eprintln!("Warning: Failed to cache content hash: {}", e);
+                }
+            }
+        }
+    }
+    Ok(hash)
+}
+
+// Hash report.json and run_plan.json in canonical form, without touching metadata.json.
+fn hash_report_contents(report_folder: &Path) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    for file in ["report.json", "run_plan.json"] {
+        // Label each part so moving content between files changes the hash.
+        hasher.update(file.as_bytes());
+        hasher.update([0u8]);
+        match fs::read_to_string(report_folder.join(file)) {
+            Ok(text) => {
+                let canonical = match serde_json::from_str::<serde_json::Value>(&text) {
+                    Ok(value) => canonical_json(&value),
+                    // Unparseable content still hashes, just byte-for-byte.
+                    Err(_) => text,
+                };
+                hasher.update(canonical.as_bytes());
+            }
+            Err(_) if file == "report.json" => {
+                return Err("report.json not found in report folder".to_string());
+            }
+            Err(_) => {}
+        }
+        hasher.update([0u8]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Serializes a JSON value with object keys sorted so equal data always yields equal text
+fn canonical_json(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let parts: Vec<String> = keys
+                .into_iter()
+                .map(|k| {
+                    format!(
+                        "{}:{}",
+                        serde_json::Value::String(k.clone()),
+                        canonical_json(&map[k])
+                    )
+                })
+                .collect();
+            format!("{{{}}}", parts.join(","))
+        }
+        serde_json::Value::Array(items) => {
+            let parts: Vec<String> = items.iter().map(canonical_json).collect();
+            format!("[{}]", parts.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Rewrites a report's report.json pretty-printed or minified
+///
+/// The JSON is reparsed and re-serialized without changing its data (serde_json is built
+/// with `preserve_order` and `float_roundtrip`, so key order and float values survive),
+/// then written atomically so a failure can't leave a truncated file behind.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder
+/// * `pretty` - Pretty-print when true, minify when false
+///
+/// # Returns
+/// The new size of report.json in bytes
+#[tauri::command]
+pub fn format_report_json(
+    state: tauri::State<AppState>,
+    folder_name: String,
+    pretty: bool,
+) -> Result<u64, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_path = state
+        .data_dir()
+        .join("reports")
+        .join(&folder_name)
+        .join("report.json");
+    if !report_path.is_file() {
+        return Err("report.json not found in report folder".to_string());
+    }
+    let text = fs::read_to_string(&report_path)
+        .map_err(|e| format!("Failed to read report.json: {}", e))?;
+    let formatted = reformat_json(&text, pretty)?;
+
+    crate::fs_utils::write_atomic(&report_path, &formatted)?;
+    Ok(formatted.len() as u64)
+}
+
+// Re-serialize JSON text pretty-printed or minified, keeping key order and exact floats.
+fn reformat_json(text: &str, pretty: bool) -> Result<String, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|e| format!("report.json is not valid JSON: {}", e))?;
+    if pretty {
+        serde_json::to_string_pretty(&value)
+    } else {
+        serde_json::to_string(&value)
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// One leaf value that differs between two reports
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct JsonChange {
+    /// JSON Pointer to the value (e.g. `/results/0/status`)
+    pub path: String,
+    /// Value in the first report (None when added)
+    pub old_value: Option<serde_json::Value>,
+    /// Value in the second report (None when removed)
+    pub new_value: Option<serde_json::Value>,
+}
+
+/// Structural difference between two reports' report.json
+#[derive(Debug, Serialize, Default)]
+pub struct ReportDiff {
+    /// Leaves only present in the second report
+    pub added: Vec<JsonChange>,
+    /// Leaves only present in the first report
+    pub removed: Vec<JsonChange>,
+    /// Leaves present in both with different values
+    pub changed: Vec<JsonChange>,
+}
+
+/// Compares the report.json of two saved reports
+///
+/// This is a plain JSON structural diff, so it works for any report schema. Objects
+/// are compared by key and arrays by index; empty objects and arrays count as leaves.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_a` - Report folder treated as "before"
+/// * `folder_b` - Report folder treated as "after"
+///
+/// # Returns
+/// The added, removed, and changed leaf paths with their old/new values
+#[tauri::command]
+pub fn diff_reports(
+    state: tauri::State<AppState>,
+    folder_a: String,
+    folder_b: String,
+) -> Result<ReportDiff, String> {
+    let reports_dir = state.data_dir().join("reports");
+    let load = |folder_name: &str| -> Result<serde_json::Value, String> {
+        validate_report_folder_name(folder_name)?;
+        let report_path = reports_dir.join(folder_name).join("report.json");
+        let text = fs::read_to_string(&report_path)
+            .map_err(|e| format!("Failed to read report.json of {}: {}", folder_name, e))?;
+        serde_json::from_str(&text)
+            .map_err(|e| format!("report.json of {} is not valid JSON: {}", folder_name, e))
+    };
+    let (a, b) = (load(&folder_a)?, load(&folder_b)?);
+
+    let mut diff = ReportDiff::default();
+    diff_json_values("", Some(&a), Some(&b), &mut diff);
+    Ok(diff)
+}
+
+// Recursively compare two JSON values at `path`, recording leaf differences in `diff`.
+// `None` means the value is absent on that side.
+fn diff_json_values(
+    path: &str,
+    a: Option<&serde_json::Value>,
+    b: Option<&serde_json::Value>,
+    diff: &mut ReportDiff,
+) {
+    use serde_json::Value;
+    let child = |key: &str| format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+    match (a, b) {
+        (Some(Value::Object(ma)), Some(Value::Object(mb))) if !ma.is_empty() || !mb.is_empty() => {
+            for (key, va) in ma {
+                diff_json_values(&child(key), Some(va), mb.get(key), diff);
+            }
+            for (key, vb) in mb.iter().filter(|(k, _)| !ma.contains_key(*k)) {
+                diff_json_values(&child(key), None, Some(vb), diff);
+            }
+        }
+        (Some(Value::Array(va)), Some(Value::Array(vb))) if !va.is_empty() || !vb.is_empty() => {
+            for i in 0..va.len().max(vb.len()) {
+                diff_json_values(&child(&i.to_string()), va.get(i), vb.get(i), diff);
+            }
+        }
+        (Some(va), Some(vb)) => {
+            if va != vb {
+                diff.changed.push(JsonChange {
+                    path: path.to_string(),
+                    old_value: Some(va.clone()),
+                    new_value: Some(vb.clone()),
+                });
+            }
+        }
+        (Some(va), None) => for_each_leaf(path, va, &mut |leaf_path, leaf| {
+            diff.removed.push(JsonChange {
+                path: leaf_path,
+                old_value: Some(leaf.clone()),
+                new_value: None,
+            })
+        }),
+        (None, Some(vb)) => for_each_leaf(path, vb, &mut |leaf_path, leaf| {
+            diff.added.push(JsonChange {
+                path: leaf_path,
+                old_value: None,
+                new_value: Some(leaf.clone()),
+            })
+        }),
+        (None, None) => {}
+    }
+}
+
+// Call `f` with the JSON Pointer and value of every leaf under `value`.
+fn for_each_leaf(
+    path: &str,
+    value: &serde_json::Value,
+    f: &mut dyn FnMut(String, &serde_json::Value),
+) {
+    let child = |key: &str| format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (key, v) in map {
+                for_each_leaf(&child(key), v, f);
+            }
+        }
+        serde_json::Value::Array(items) if !items.is_empty() => {
+            for (i, v) in items.iter().enumerate() {
+                for_each_leaf(&child(&i.to_string()), v, f);
+            }
+        }
+        leaf => f(path.to_string(), leaf),
+    }
+}
+
+/// Column headers written by `export_reports_csv`
+const REPORTS_CSV_HEADER: [&str; 6] = [
+    "hostname",
+    "customer_name",
+    "technician_name",
+    "saved_at",
+    "timestamp",
+    "folder_path",
+];
+
+/// Writes a CSV summary of every saved report
+///
+/// One row per report folder, newest first. Reports without a readable metadata.json
+/// are still listed, with empty metadata cells.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `dest_path` - CSV file to write (a directory gets `reports.csv` inside it)
+///
+/// # Returns
+/// The number of rows written, excluding the header
+#[tauri::command]
+pub fn export_reports_csv(
+    state: tauri::State<AppState>,
+    dest_path: String,
+) -> Result<usize, String> {
+    let mut csv_path = PathBuf::from(dest_path.trim());
+    if csv_path.as_os_str().is_empty() {
+        return Err("Destination path is required".to_string());
+    }
+    if csv_path.is_dir() {
+        csv_path = csv_path.join("reports.csv");
+    }
+
+    let reports_dir = state.data_dir().join("reports");
+    let page = read_reports_page(&reports_dir, 0, usize::MAX, ReportSort::Timestamp)?;
+
+    let mut csv = csv_row(REPORTS_CSV_HEADER.iter().map(|h| h.to_string()));
+    for item in &page.items {
+        let meta = item.metadata.as_ref();
+        csv.push_str(&csv_row([
+            meta.and_then(|m| m.hostname.clone()).unwrap_or_default(),
+            meta.and_then(|m| m.customer_name.clone())
+                .unwrap_or_default(),
+            meta.and_then(|m| m.technician_name.clone())
+                .unwrap_or_default(),
+            meta.map(|m| m.saved_at.clone()).unwrap_or_default(),
+            meta.map(|m| m.timestamp.to_string()).unwrap_or_default(),
+            item.folder_path.clone(),
+        ]));
+    }
+
+    crate::fs_utils::write_atomic(&csv_path, csv)?;
+    Ok(page.items.len())
+}
+
+// One CSV line (CRLF-terminated), quoting fields that contain commas, quotes or newlines.
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let quoted: Vec<String> = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect();
+    format!("{}\r\n", quoted.join(","))
+}
+
+/// Share of a plan's expected run time taken by one of its tasks
+#[derive(Debug, Serialize)]
+pub struct TaskProgressWeight {
+    /// Task type from the plan (e.g. "sfc_scan")
+    pub task_type: String,
+    /// Fraction of the whole plan; the weights of a plan sum to 1
+    pub weight: f64,
+    /// Median duration of past runs in seconds (None when the task has no history)
+    pub median_seconds: Option<f64>,
+}
+
+/// Weights each task of a run plan by its expected duration
+///
+/// Durations come from the `summary.duration_seconds` of matching results in saved
+/// reports, using the median per task type. Tasks with no history get the average of
+/// the known tasks (or an equal share when nothing is known), so a weighted progress
+/// bar advances smoothly instead of jumping per task.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `plan_json` - The run plan (an object with `tasks`, or a bare task array)
+///
+/// # Returns
+/// One weight per task, in plan order
+#[tauri::command]
+pub fn compute_plan_progress_weights(
+    state: tauri::State<AppState>,
+    plan_json: String,
+) -> Result<Vec<TaskProgressWeight>, String> {
+    let plan: serde_json::Value =
+        serde_json::from_str(&plan_json).map_err(|e| format!("Invalid plan JSON: {}", e))?;
+    let tasks = plan
+        .get("tasks")
+        .and_then(|t| t.as_array())
+        .or_else(|| plan.as_array())
+        .ok_or_else(|| "Plan has no task list".to_string())?;
+    let task_types: Vec<String> = tasks
+        .iter()
+        .map(|t| {
+            t.get("type")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        })
+        .collect();
+
+    let history = task_duration_history(&state.data_dir().join("reports"));
+    Ok(progress_weights(&task_types, &history))
+}
+
+// Past task durations in seconds by task type, gathered from every saved report.json.
+fn task_duration_history(reports_dir: &Path) -> HashMap<String, Vec<f64>> {
+    let mut history: HashMap<String, Vec<f64>> = HashMap::new();
+    let Ok(entries) = fs::read_dir(reports_dir) else {
+        return history;
+    };
+    for entry in entries.flatten() {
+        let Ok(text) = fs::read_to_string(entry.path().join("report.json")) else {
+            continue;
+        };
+        let Ok(report) = serde_json::from_str::<serde_json::Value>(&text) else {
+            continue;
+        };
+        let Some(results) = report.get("results").and_then(|r| r.as_array()) else {
+            continue;
+        };
+        for result in results {
+            let task_type = result.get("task_type").and_then(|t| t.as_str());
+            let seconds = result
+                .pointer("/summary/duration_seconds")
+                .and_then(|d| d.as_f64())
+                .filter(|d| d.is_finite() && *d > 0.0);
+            if let (Some(task_type), Some(seconds)) = (task_type, seconds) {
+                history
+                    .entry(task_type.to_string())
+                    .or_default()
+                    .push(seconds);
+            }
+        }
+    }
+    history
+}
+
+// Normalized weights for `task_types` from the median of their past durations.
+fn progress_weights(
+    task_types: &[String],
+    history: &HashMap<String, Vec<f64>>,
+) -> Vec<TaskProgressWeight> {
+    let medians: Vec<Option<f64>> = task_types
+        .iter()
+        .map(|t| history.get(t).and_then(|d| median(d)))
+        .collect();
+    let known: Vec<f64> = medians.iter().flatten().copied().collect();
+    let average = if known.is_empty() {
+        1.0
+    } else {
+        known.iter().sum::<f64>() / known.len() as f64
+    };
+    let total: f64 = medians.iter().map(|m| m.unwrap_or(average)).sum();
+
+    task_types
+        .iter()
+        .zip(medians)
+        .map(|(task_type, median_seconds)| TaskProgressWeight {
+            task_type: task_type.clone(),
+            weight: median_seconds.unwrap_or(average) / total,
+            median_seconds,
+        })
+        .collect()
+}
+
+fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    Some(if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    })
+}
+
+/// Result of exporting a report folder to a ZIP archive
+#[derive(Debug, Serialize)]
+pub struct ExportReportZipResponse {
+    /// Path of the written archive
+    pub archive_path: String,
+    /// Number of files stored in the archive
+    pub files: usize,
+    /// Set when the report is incomplete (e.g. report.json is missing)
+    pub warning: Option<String>,
+}
+
+/// Bundles a report folder into a single ZIP archive
+///
+/// Files are stored under a top-level `{folder_name}/` directory so the archive keeps
+/// the report's layout and name when imported again. A report without report.json is
+/// still exported, with a warning.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `folder_name` - Name of the report folder to export
+/// * `dest_path` - Archive path, or an existing directory to write `{folder_name}.zip` into
+///
+/// # Returns
+/// The archive path and file count, plus a warning for incomplete reports
+#[tauri::command]
+pub async fn export_report_zip(
+    state: tauri::State<'_, AppState>,
+    folder_name: String,
+    dest_path: String,
+) -> Result<ExportReportZipResponse, String> {
+    validate_report_folder_name(&folder_name)?;
+    let report_folder = state.data_dir().join("reports").join(&folder_name);
+
+    tauri::async_runtime::spawn_blocking(move || -> Result<ExportReportZipResponse, String> {
+        if !report_folder.is_dir() {
+            return Err(format!("Report folder not found: {}", folder_name));
+        }
+
+        let mut archive_path = PathBuf::from(dest_path.trim());
+        if archive_path.as_os_str().is_empty() {
+            return Err("Destination path is required".to_string());
+        }
+        if archive_path.is_dir() {
+            archive_path = archive_path.join(format!("{}.zip", folder_name));
+        } else if archive_path.extension().is_none() {
+            archive_path.set_extension("zip");
+        }
+        if archive_path.starts_with(&report_folder) {
+            return Err("The archive cannot be written inside the report folder".to_string());
+        }
+
+        let warning = (!report_folder.join("report.json").is_file()).then(|| {
+            "report.json is missing; the archive contains the remaining files".to_string()
+        });
+
+        let files =
+            write_report_zip(&report_folder, &folder_name, &archive_path).inspect_err(|_| {
+                let _ = fs::remove_file(&archive_path);
+            })?;
+
+        Ok(ExportReportZipResponse {
+            archive_path: to_user_visible_path(&archive_path),
+            files,
+            warning,
+        })
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+// Write every file below `report_folder` into a new archive at `archive_path`.
+fn write_report_zip(
+    report_folder: &Path,
+    folder_name: &str,
+    archive_path: &Path,
+) -> Result<usize, String> {
+    if let Some(parent) = archive_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create destination folder: {}", e))?;
+    }
+    let file = fs::File::create(archive_path)
+        .map_err(|e| format!("Failed to create {}: {}", archive_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let mut files = 0;
+    let mut pending = vec![report_folder.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries =
+            fs::read_dir(&dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let relative = path
+                .strip_prefix(report_folder)
+                .map_err(|e| e.to_string())?
+                .to_string_lossy()
+                .replace('\\', "/");
+            zip.start_file(format!("{}/{}", folder_name, relative), options)
+                .map_err(|e| format!("Failed to add {}: {}", relative, e))?;
+            let mut source = fs::File::open(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            io::copy(&mut source, &mut zip)
+                .map_err(|e| format!("Failed to add {}: {}", relative, e))?;
+            files += 1;
+        }
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to finish archive: {}", e))?;
+    Ok(files)
+}
+
+/// Imports a report from a ZIP archive (as written by `export_report_zip`) or a folder
+///
+/// The source must contain a readable report.json and metadata.json, either at its
+/// top level or in a single top-level folder. The report is copied into data/reports
+/// under its sanitized folder name; when that name is taken a numeric suffix is added.
+///
+/// # Arguments
+/// * `state` - Application state containing data directory path
+/// * `source_path` - Path of a `.zip` archive or a report folder
+///
+/// # Returns
+/// The list item of the imported report
+#[tauri::command]
+pub async fn import_report(
+    state: tauri::State<'_, AppState>,
+    source_path: String,
+) -> Result<ReportListItem, String> {
+    let source = PathBuf::from(source_path.trim());
+    let reports_dir = state.data_dir().join("reports");
+
+    tauri::async_runtime::spawn_blocking(move || import_report_from(&source, &reports_dir))
+        .await
+        .map_err(|e| format!("Import task failed: {}", e))?
+}
+
+// Copy the report at `source` (archive or folder) into a new folder under `reports_dir`.
+fn import_report_from(source: &Path, reports_dir: &Path) -> Result<ReportListItem, String> {
+    // Archives are extracted to a staging folder first so a bad archive never leaves
+    // partial reports behind.
+    let staging = if source.is_file() {
+        let staging =
+            std::env::temp_dir().join(format!("autoservice_import_{}", uuid::Uuid::new_v4()));
+        extract_zip(source, &staging).inspect_err(|_| {
+            let _ = fs::remove_dir_all(&staging);
+        })?;
+        Some(staging)
+    } else if source.is_dir() {
+        None
+    } else {
+        return Err(format!("Import source not found: {}", source.display()));
+    };
+
+    let result = (|| {
+        let root = staging.as_deref().unwrap_or(source);
+        let report_root = find_report_root(root)?;
+        validate_importable_report(&report_root)?;
+
+        // Archives use their top-level folder as the name; fall back to the file name.
+        let name_source = if report_root != root || staging.is_none() {
+            report_root.file_name()
+        } else {
+            source.file_stem()
+        };
+        let base = sanitize_folder_name(
+            &name_source
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        );
+        fs::create_dir_all(reports_dir)
+            .map_err(|e| format!("Failed to create reports directory: {}", e))?;
+        // The folder is created here, so the cleanup below only removes our own copy.
+        let dest = create_unique_dir(reports_dir, &base)
+            .map_err(|e| format!("Failed to create report folder: {}", e))?;
+        let folder_name = dest
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or(base);
+
+        copy_dir_recursive(
+            &report_root,
+            &dest,
+            None,
+            &CancelToken::default(),
+            &mut CopySummary::default(),
+            None,
+            &mut |_| {},
+        )
+        .map_err(|e| {
+            let _ = fs::remove_dir_all(&dest);
+            format!("Failed to copy report: {}", e)
+        })?;
+
+        Ok(ReportListItem {
+            folder_name,
+            folder_path: to_user_visible_path(&dest),
+            metadata: read_metadata(&dest),
+            has_report_json: dest.join("report.json").exists(),
+            has_execution_log: has_execution_log(&dest),
+            has_run_plan: dest.join("run_plan.json").exists(),
+            size_bytes: None,
+        })
+    })();
+
+    if let Some(staging) = staging {
+        let _ = fs::remove_dir_all(staging);
+    }
+    result
+}
+
+// Extract a ZIP archive into `dest` (entries escaping `dest` are rejected by the zip crate).
+fn extract_zip(archive_path: &Path, dest: &Path) -> Result<(), String> {
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("Failed to open {}: {}", archive_path.display(), e))?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| format!("Not a valid ZIP archive: {}", e))?;
+    archive
+        .extract(dest)
+        .map_err(|e| format!("Failed to extract archive: {}", e))
+}
+
+// The folder holding report.json: `root` itself or its only subfolder.
+fn find_report_root(root: &Path) -> Result<PathBuf, String> {
+    if root.join("report.json").is_file() {
+        return Ok(root.to_path_buf());
+    }
+    let subdirs: Vec<PathBuf> = fs::read_dir(root)
+        .map_err(|e| format!("Failed to read {}: {}", root.display(), e))?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    match subdirs.as_slice() {
+        [only] if only.join("report.json").is_file() => Ok(only.clone()),
+        _ => Err("No report.json found in the import source".to_string()),
+    }
+}
+
+// Require parseable report.json and metadata.json before importing.
+fn validate_importable_report(report_root: &Path) -> Result<(), String> {
+    let report = fs::read_to_string(report_root.join("report.json"))
+        .map_err(|e| format!("Failed to read report.json: {}", e))?;
+    serde_json::from_str::<serde_json::Value>(&report)
+        .map_err(|e| format!("report.json is not valid JSON: {}", e))?;
+    if !report_root.join("metadata.json").is_file() {
+        return Err("metadata.json is missing".to_string());
+    }
+    read_metadata(report_root)
+        .map(|_| ())
+        .ok_or_else(|| "metadata.json is not a valid report metadata file".to_string())
+}
+
+// Restrict a report folder name to safe characters, keeping the `__` timestamp separator.
+fn sanitize_folder_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(120)
+        .collect();
+    let trimmed = sanitized.trim_matches('_');
+    if trimmed.is_empty() {
+        "imported_report".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Helper function to read and parse metadata.json from a report folder
+fn read_metadata(report_folder: &Path) -> Option<ReportMetadata> {
+    let metadata_path = report_folder.join("metadata.json");
+    if !metadata_path.exists() {
+        return None;
+    }
+
+    let content = fs::read_to_string(&metadata_path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// ---------------------- Network report sharing ----------------------
 
 struct NetworkCopyLogger {
     path: Option<PathBuf>,
@@ -471,7 +2407,8 @@ struct NetworkCopyLogger {
 
 impl NetworkCopyLogger {
     fn new_from_state(state: &tauri::State<AppState>) -> Self {
-        let data_root = state.data_dir.as_path();
+        let data_dir = state.data_dir();
+        let data_root = data_dir.as_path();
         let logs_dir = data_root.join("logs");
         if let Err(e) = fs::create_dir_all(&logs_dir) {
             eprintln!(
@@ -544,6 +2481,26 @@ pub struct NetworkConfig {
     /// Optional save mode hint ("local"|"network"|"both") - not used by backend logic
     #[serde(default)]
     pub save_mode: Option<String>,
+    /// Deadline for the whole copy in seconds (defaults to 120)
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    /// Run `verify_report` first and refuse to copy a report with error-level issues
+    #[serde(default)]
+    pub validate_before_copy: bool,
+}
+
+/// Default deadline for copying a report to a share
+const NETWORK_COPY_TIMEOUT_SECS: u64 = 120;
+/// Default timeout for listing the reports on a share
+const NETWORK_LIST_TIMEOUT_SECS: u64 = 10;
+/// Default timeout for the share reachability test
+const NETWORK_TEST_TIMEOUT_SECS: u64 = 6;
+/// Default timeout for deleting a report from a share
+const NETWORK_DELETE_TIMEOUT_SECS: u64 = 60;
+
+// Caller-supplied timeout in seconds, or `default` when absent or zero.
+fn network_timeout(timeout_seconds: Option<u64>, default: u64) -> Duration {
+    Duration::from_secs(timeout_seconds.filter(|s| *s > 0).unwrap_or(default))
 }
 
 fn normalize_unc_path(unc: &str) -> String {
@@ -576,15 +2533,127 @@ fn normalize_unc_path(unc: &str) -> String {
     }
 }
 
+/// Counts of files handled by a network report copy
+#[derive(Debug, Default, Serialize)]
+pub struct CopySummary {
+    /// Files written to the destination
+    pub files_copied: usize,
+    /// Files skipped because an identical copy was already at the destination
+    pub files_skipped: usize,
+}
+
+// Largest modified-time difference still treated as "same file"; FAT and some SMB
+// servers only store timestamps with 2 second precision.
+const MTIME_TOLERANCE: Duration = Duration::from_secs(2);
+
+// True when `target` already exists with the same size and (within tolerance) the same
+// modified time as `source`.
+fn is_unchanged_copy(source: &Path, target: &Path) -> bool {
+    let (Ok(src_meta), Ok(dst_meta)) = (fs::metadata(source), fs::metadata(target)) else {
+        return false;
+    };
+    if !dst_meta.is_file() || src_meta.len() != dst_meta.len() {
+        return false;
+    }
+    match (src_meta.modified(), dst_meta.modified()) {
+        (Ok(a), Ok(b)) => {
+            let diff = a.duration_since(b).or_else(|_| b.duration_since(a));
+            diff.is_ok_and(|d| d <= MTIME_TOLERANCE)
+        }
+        _ => false,
+    }
+}
+
+// Give the destination the source's modified time so a later sync can recognise it
+// as unchanged. Best effort: a failure only means the file is copied again next time.
+fn preserve_modified_time(source: &Path, target: &Path) {
+    let Ok(modified) = fs::metadata(source).and_then(|m| m.modified()) else {
+        return;
+    };
+    if let Ok(file) = OpenOptions::new().write(true).open(target) {
+        let _ = file.set_modified(modified);
+    }
+}
+
+/// Payload of the `network_copy_progress` event
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkCopyProgress {
+    pub files_done: usize,
+    pub files_total: usize,
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// File that was just copied or skipped
+    pub current_file: String,
+}
+
+// Emits `network_copy_progress` events as `copy_dir_recursive` works through the tree.
+struct CopyProgressEmitter {
+    app: tauri::AppHandle,
+    state: NetworkCopyProgress,
+}
+
+impl CopyProgressEmitter {
+    // Pre-count the files under `src` so each event can carry the totals.
+    fn new(app: tauri::AppHandle, src: &Path) -> Self {
+        let (files_total, bytes_total) = count_files(src, &CancelToken::default());
+        Self {
+            app,
+            state: NetworkCopyProgress {
+                files_done: 0,
+                files_total,
+                bytes_done: 0,
+                bytes_total,
+                current_file: String::new(),
+            },
+        }
+    }
+
+    fn file_done(&mut self, path: &Path, bytes: u64) {
+        self.state.files_done += 1;
+        self.state.bytes_done += bytes;
+        self.state.current_file = to_user_visible_path(path);
+        let _ = self.app.emit("network_copy_progress", &self.state);
+    }
+}
+
+// Number of files and total bytes under `dir`; unreadable entries are ignored.
+// Once `cancel` fires the walk stops and the partial counts are returned.
+fn count_files(dir: &Path, cancel: &CancelToken) -> (usize, u64) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (0, 0);
+    };
+    entries.flatten().fold((0, 0), |(files, bytes), entry| {
+        if cancel.is_cancelled() {
+            return (files, bytes);
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            let (sub_files, sub_bytes) = count_files(&path, cancel);
+            (files + sub_files, bytes + sub_bytes)
+        } else {
+            let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            (files + 1, bytes + len)
+        }
+    })
+}
+
+// Mirror `src` into `dst`, skipping files whose size and modified time already match at
+// the destination and counting copied vs skipped files in `summary`.
 fn copy_dir_recursive<F>(
     src: &Path,
     dst: &Path,
     deadline: Option<SystemTime>,
+    cancel: &CancelToken,
+    summary: &mut CopySummary,
+    mut progress: Option<&mut CopyProgressEmitter>,
     log: &mut F,
 ) -> io::Result<()>
 where
     F: FnMut(String),
 {
+    if cancel.is_cancelled() {
+        return Err(io::Error::new(io::ErrorKind::Interrupted, "Copy cancelled"));
+    }
     if let Some(deadline) = deadline {
         if SystemTime::now() > deadline {
             return Err(io::Error::new(
@@ -638,7 +2707,21 @@ where
         let target = dst.join(&file_name);
         if path.is_dir() {
             log(format!("Descending into {}", to_user_visible_path(&path)));
-            copy_dir_recursive(&path, &target, deadline, log)?;
+            copy_dir_recursive(
+                &path,
+                &target,
+                deadline,
+                cancel,
+                summary,
+                progress.as_deref_mut(),
+                log,
+            )?;
+        } else if is_unchanged_copy(&path, &target) {
+            log(format!(
+                "Skipping unchanged file {}",
+                to_user_visible_path(&path)
+            ));
+            summary.files_skipped += 1;
         } else {
             log(format!(
                 "Copying file {} -> {}",
@@ -656,6 +2739,17 @@ where
                     ),
                 )
             })?;
+            preserve_modified_time(&path, &target);
+            summary.files_copied += 1;
+        }
+        if let Some(progress) = progress.as_deref_mut() {
+            if !path.is_dir() {
+                let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                progress.file_done(&path, len);
+            }
+        }
+        if cancel.is_cancelled() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Copy cancelled"));
         }
         if let Some(deadline) = deadline {
             if SystemTime::now() > deadline {
@@ -674,13 +2768,22 @@ where
 
 /// Copies a saved local report folder to a network UNC path.
 ///
-/// Returns true on success, or an error string.
+/// Files that already exist at the destination with the same size and modified time
+/// are skipped, so re-running a save after a partial failure only copies what is missing.
+///
+/// Emits `network_copy_progress` events (payload: `NetworkCopyProgress`) after each file.
+/// On success the share is remembered as the last-used network path. With
+/// `network_config.validate_before_copy` set, a report that fails `verify_report` is not
+/// copied and the error lists the bad files.
+///
+/// Returns the number of files copied and skipped, or an error string.
 #[tauri::command]
-pub fn save_report_to_network(
-    state: tauri::State<AppState>,
+pub async fn save_report_to_network(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
     report_path: String,
     network_config: NetworkConfig,
-) -> Result<bool, String> {
+) -> Result<CopySummary, String> {
     let logger = NetworkCopyLogger::new_from_state(&state);
     let save_mode = network_config
         .save_mode
@@ -691,6 +2794,172 @@ pub fn save_report_to_network(
         "Starting network copy | report_path='{}' | unc_path='{}' | mode='{}'",
         report_path, network_config.unc_path, save_mode
     ));
+    let op = state.operations.register("network_copy");
+
+    // The copy can take minutes on a slow share; keep it off the main thread so progress
+    // events reach the UI and `cancel_all_operations` can stop it.
+    let (summary, normalized) =
+        tauri::async_runtime::spawn_blocking(move || -> Result<(CopySummary, String), String> {
+            let normalized = normalize_unc_path(&network_config.unc_path);
+            if normalized.is_empty() {
+                let msg = "UNC path is empty";
+                logger.log(msg);
+                return Err(msg.into());
+            }
+
+            let src_raw = PathBuf::from(&report_path);
+            if !src_raw.exists() || !src_raw.is_dir() {
+                let msg = format!(
+                    "Local report path not found or not a directory: {}",
+                    to_user_visible_path(&src_raw)
+                );
+                logger.log(&msg);
+                return Err(msg);
+            }
+
+            let folder_name = src_raw.file_name().ok_or_else(|| {
+                let msg = format!(
+                    "Failed to derive folder name from {}",
+                    to_user_visible_path(&src_raw)
+                );
+                logger.log(&msg);
+                msg
+            })?;
+
+            let src = prepare_path_for_io(&src_raw);
+            if network_config.validate_before_copy {
+                let verification = verify_report_dir(&src);
+                if !verification.ok {
+                    let problems: Vec<String> = verification
+                        .issues
+                        .iter()
+                        .filter(|i| i.severity == "error")
+                        .map(|i| format!("{}: {}", i.file, i.message))
+                        .collect();
+                    let msg = format!(
+                        "Report failed verification and was not copied: {}",
+                        problems.join("; ")
+                    );
+                    logger.log(&msg);
+                    return Err(msg);
+                }
+            }
+            let share_path = PathBuf::from(&normalized);
+            let dst_root = prepare_path_for_io(&share_path);
+
+            logger.log(format!(
+                "Resolved destination root '{}' (io path: '{}')",
+                normalized,
+                dst_root.display()
+            ));
+
+            match fs::read_dir(&dst_root) {
+                Ok(_) => logger.log(format!(
+                    "Verified network share is reachable: {}",
+                    normalized
+                )),
+                Err(e) => {
+                    let warn = format!(
+                        "Warning: unable to list network share {}: {}",
+                        normalized, e
+                    );
+                    logger.log(&warn);
+                    if e.kind() == io::ErrorKind::NotFound {
+                        return Err(format!("Network share not found: {}", normalized));
+                    }
+                }
+            }
+
+            let dst = dst_root.join(&folder_name);
+            logger.log(format!(
+                "Copy target resolved to {}",
+                to_user_visible_path(&dst)
+            ));
+
+            // Allow additional time for network operations to reduce false timeouts on slower links
+            let timeout =
+                network_timeout(network_config.timeout_seconds, NETWORK_COPY_TIMEOUT_SECS);
+            let deadline = SystemTime::now() + timeout;
+
+            let mut log_fn = |line: String| logger.log(line);
+            let mut summary = CopySummary::default();
+            let mut progress = CopyProgressEmitter::new(app, &src);
+            copy_dir_recursive(
+                &src,
+                &dst,
+                Some(deadline),
+                &op.token(),
+                &mut summary,
+                Some(&mut progress),
+                &mut log_fn,
+            )
+            .map_err(|e| {
+                logger.log(format!(
+                    "Copy failed for {} -> {}: {}",
+                    to_user_visible_path(&src_raw),
+                    to_user_visible_path(&dst),
+                    e
+                ));
+                format!("Copy failed: {e}")
+            })?;
+
+            logger.log(format!(
+                "Network copy completed successfully for {} -> {} ({} copied, {} skipped)",
+                to_user_visible_path(&src_raw),
+                to_user_visible_path(&dst),
+                summary.files_copied,
+                summary.files_skipped
+            ));
+            Ok((summary, normalized))
+        })
+        .await
+        .map_err(|e| format!("Network copy failed: {}", e))??;
+
+    remember_network_path(&state, &normalized);
+    Ok(summary)
+}
+
+/// Payload of the `file_copy_progress` event
+#[derive(Debug, Clone, Serialize)]
+pub struct FileCopyProgress {
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// Destination file being written
+    pub dest_path: String,
+}
+
+/// Chunk size used by `copy_file_to_network`
+const FILE_COPY_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Copies a single file to a network UNC path, in chunks, with progress.
+///
+/// The file is written as `<dest_name>.partial` and renamed once complete, so the share
+/// never shows a half-copied file under its final name. Emits `file_copy_progress`
+/// events (payload: `FileCopyProgress`) after each chunk. The copy stops at the next
+/// chunk when cancelled via `cancel_all_operations`; the deadline from
+/// `network_config.timeout_seconds` applies only when it is set, since large files can
+/// legitimately take longer than the folder-copy default.
+///
+/// # Arguments
+/// * `src` - Local file to copy
+/// * `network_config` - Destination share
+/// * `dest_name` - File name on the share (defaults to the source file name)
+///
+/// # Returns
+/// The number of bytes copied
+#[tauri::command]
+pub async fn copy_file_to_network(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    src: String,
+    network_config: NetworkConfig,
+    dest_name: Option<String>,
+) -> Result<u64, String> {
+    let logger = NetworkCopyLogger::new_from_state(&state);
+    logger.log(format!(
+        "Starting file copy | src='{}' | unc_path='{}'",
+        src, network_config.unc_path
+    ));
 
     let normalized = normalize_unc_path(&network_config.unc_path);
     if normalized.is_empty() {
@@ -699,159 +2968,664 @@ pub fn save_report_to_network(
         return Err(msg.into());
     }
 
-    let src_raw = PathBuf::from(&report_path);
-    if !src_raw.exists() || !src_raw.is_dir() {
-        let msg = format!(
-            "Local report path not found or not a directory: {}",
-            to_user_visible_path(&src_raw)
-        );
+    let src_raw = PathBuf::from(&src);
+    if !src_raw.is_file() {
+        let msg = format!("Local file not found: {}", to_user_visible_path(&src_raw));
         logger.log(&msg);
         return Err(msg);
     }
+    let dest_name = match dest_name {
+        Some(name) => name,
+        None => src_raw
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+    if !is_plain_file_name(&dest_name) {
+        return Err(format!("Invalid destination file name: {}", dest_name));
+    }
 
-    let folder_name = src_raw.file_name().ok_or_else(|| {
-        let msg = format!(
-            "Failed to derive folder name from {}",
-            to_user_visible_path(&src_raw)
-        );
+    let src_io = prepare_path_for_io(&src_raw);
+    let dst_root = prepare_path_for_io(&PathBuf::from(&normalized));
+    if !dst_root.is_dir() {
+        let msg = format!("Network share not found: {}", normalized);
         logger.log(&msg);
-        msg
-    })?;
+        return Err(msg);
+    }
+    let dst = dst_root.join(&dest_name);
+    let deadline = network_config
+        .timeout_seconds
+        .map(|secs| SystemTime::now() + network_timeout(Some(secs), NETWORK_COPY_TIMEOUT_SECS));
+    let op = state.operations.register("file_copy");
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = copy_file_chunked(&src_io, &dst, deadline, &op.token(), |progress| {
+            let _ = app.emit("file_copy_progress", progress);
+        });
+        match &result {
+            Ok(bytes) => logger.log(format!(
+                "File copy completed for {} -> {} ({} bytes)",
+                to_user_visible_path(&src_raw),
+                to_user_visible_path(&dst),
+                bytes
+            )),
+            Err(e) => logger.log(format!(
+                "File copy failed for {} -> {}: {}",
+                to_user_visible_path(&src_raw),
+                to_user_visible_path(&dst),
+                e
+            )),
+        }
+        result.map_err(|e| format!("Copy failed: {e}"))
+    })
+    .await
+    .map_err(|e| format!("File copy failed: {}", e))?
+}
+
+// Copy `src` to `dst` through a `.partial` file, reporting progress after every chunk.
+// The partial file is removed if the copy fails or is cancelled.
+fn copy_file_chunked<P>(
+    src: &Path,
+    dst: &Path,
+    deadline: Option<SystemTime>,
+    cancel: &CancelToken,
+    mut on_progress: P,
+) -> io::Result<u64>
+where
+    P: FnMut(&FileCopyProgress),
+{
+    let mut partial_name = dst.file_name().unwrap_or_default().to_os_string();
+    partial_name.push(".partial");
+    let partial = dst.with_file_name(partial_name);
+
+    let result = (|| -> io::Result<u64> {
+        let mut input = fs::File::open(src)?;
+        let mut output = fs::File::create(&partial)?;
+        let mut progress = FileCopyProgress {
+            bytes_done: 0,
+            bytes_total: input.metadata()?.len(),
+            dest_path: to_user_visible_path(dst),
+        };
+        let mut buf = vec![0u8; FILE_COPY_CHUNK_BYTES];
+        loop {
+            if cancel.is_cancelled() {
+                return Err(io::Error::new(io::ErrorKind::Interrupted, "Copy cancelled"));
+            }
+            if deadline.is_some_and(|d| SystemTime::now() > d) {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "Copy timed out"));
+            }
+            let n = io::Read::read(&mut input, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+            output.write_all(&buf[..n])?;
+            progress.bytes_done += n as u64;
+            on_progress(&progress);
+        }
+        output.sync_all()?;
+        drop(output);
+        fs::rename(&partial, dst)?;
+        Ok(progress.bytes_done)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    result
+}
+
+fn list_reports_in_dir(
+    dir: &Path,
+    include_sizes: bool,
+    cancel: &CancelToken,
+) -> io::Result<Vec<ReportListItem>> {
+    let mut reports = Vec::new();
+    if !dir.exists() {
+        return Ok(reports);
+    }
+    for entry in fs::read_dir(dir)? {
+        if cancel.is_cancelled() {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Network listing cancelled",
+            ));
+        }
+        let entry = match entry {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let folder_name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => continue,
+        };
+        let has_report_json = path.join("report.json").exists();
+        let has_execution_log = has_execution_log(&path);
+        let has_run_plan = path.join("run_plan.json").exists();
+        let metadata = read_metadata(&path);
+        let size_bytes = include_sizes.then(|| count_files(&path, cancel).1);
+        reports.push(ReportListItem {
+            folder_name,
+            folder_path: to_user_visible_path(&path),
+            metadata,
+            has_report_json,
+            has_execution_log,
+            has_run_plan,
+            size_bytes,
+        });
+    }
+    // Sort newest first similar to local implementation
+    reports.sort_by(|a, b| {
+        let a_time = a.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
+        let b_time = b.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
+        b_time.cmp(&a_time)
+    });
+    Ok(reports)
+}
 
-    let src = prepare_path_for_io(&src_raw);
+/// Lists reports from a network UNC path.
+///
+/// `timeout_seconds` bounds the listing (defaults to 10 seconds). With `include_sizes`,
+/// every folder is walked to fill in `size_bytes`; this is much slower over the network,
+/// so allow a longer timeout.
+#[tauri::command]
+pub async fn list_network_reports(
+    state: tauri::State<'_, AppState>,
+    unc_path: String,
+    timeout_seconds: Option<u64>,
+    include_sizes: Option<bool>,
+) -> Result<Vec<ReportListItem>, String> {
+    let include_sizes = include_sizes.unwrap_or(false);
+    let normalized = normalize_unc_path(&unc_path);
     let share_path = PathBuf::from(&normalized);
-    let dst_root = prepare_path_for_io(&share_path);
+    let path = prepare_path_for_io(&share_path);
+
+    // Run in a worker thread with timeout to avoid UI freeze on hanging shares
+    let op = state.operations.register("network_listing");
+    let cancel = op.token();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let res = list_reports_in_dir(&path, include_sizes, &op.token()).map_err(|e| e.to_string());
+        let _ = tx.send(res);
+    });
+
+    let timeout = network_timeout(timeout_seconds, NETWORK_LIST_TIMEOUT_SECS);
+    match wait_for_network_listing(rx, timeout, &cancel).await? {
+        Ok(res) => res,
+        Err(_) => Err("Network listing timed out".into()),
+    }
+}
+
+// Wait for a listing worker without blocking the async runtime. On timeout the worker's
+// token is cancelled so it stops walking the share instead of running on unobserved.
+async fn wait_for_network_listing<T: Send + 'static>(
+    rx: std::sync::mpsc::Receiver<T>,
+    timeout: Duration,
+    cancel: &CancelToken,
+) -> Result<Result<T, std::sync::mpsc::RecvTimeoutError>, String> {
+    let received = tauri::async_runtime::spawn_blocking(move || rx.recv_timeout(timeout))
+        .await
+        .map_err(|e| format!("Network listing failed: {}", e))?;
+    if received.is_err() {
+        cancel.cancel();
+    }
+    Ok(received)
+}
+
+/// Lists the local reports that are not on a network share yet.
+///
+/// Reports are matched by folder name (case-insensitively, as on Windows shares). With
+/// `compare_hashes`, a report that exists on the share under the same name but whose
+/// content hash differs (or whose report.json there is missing or unreadable) also
+/// counts as missing, so a partial or stale copy gets sent again. Hashes on the share
+/// are computed read-only; nothing is written there. `timeout_seconds` bounds the
+/// share scan (defaults to 10 seconds).
+///
+/// # Returns
+/// The local reports to copy, newest first
+#[tauri::command]
+pub async fn find_reports_missing_on_network(
+    state: tauri::State<'_, AppState>,
+    unc_path: String,
+    compare_hashes: Option<bool>,
+    timeout_seconds: Option<u64>,
+) -> Result<Vec<ReportListItem>, String> {
+    let normalized = normalize_unc_path(&unc_path);
+    if normalized.is_empty() {
+        return Err("UNC path is empty".into());
+    }
+    let compare_hashes = compare_hashes.unwrap_or(false);
+    let reports_dir = state.data_dir().join("reports");
+    let local_dir = reports_dir.clone();
+    let local = tauri::async_runtime::spawn_blocking(move || {
+        read_reports_page(&local_dir, 0, usize::MAX, ReportSort::Timestamp)
+    })
+    .await
+    .map_err(|e| format!("Failed to list local reports: {}", e))??
+    .items;
+
+    let share = prepare_path_for_io(&PathBuf::from(&normalized));
+    let names: HashSet<String> = local.iter().map(|r| r.folder_name.to_lowercase()).collect();
+    let op = state.operations.register("network_listing");
+    let cancel = op.token();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let res = remote_report_hashes(&share, &names, compare_hashes, &op.token());
+        let _ = tx.send(res);
+    });
+    let timeout = network_timeout(timeout_seconds, NETWORK_LIST_TIMEOUT_SECS);
+    let remote = match wait_for_network_listing(rx, timeout, &cancel).await? {
+        Ok(res) => res.map_err(|e| format!("Failed to list network reports: {}", e))?,
+        Err(_) => return Err("Network listing timed out".into()),
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        reports_missing_remotely(local, &reports_dir, &remote, compare_hashes)
+    })
+    .await
+    .map_err(|e| format!("Failed to compare reports: {}", e))
+}
+
+// The local reports that `remote` (from `remote_report_hashes`) lacks or, with
+// `compare_hashes`, holds with different content.
+fn reports_missing_remotely(
+    local: Vec<ReportListItem>,
+    reports_dir: &Path,
+    remote: &HashMap<String, Option<String>>,
+    compare_hashes: bool,
+) -> Vec<ReportListItem> {
+    local
+        .into_iter()
+        .filter(|item| match remote.get(&item.folder_name.to_lowercase()) {
+            None => true,
+            Some(remote_hash) if compare_hashes => {
+                // Without a local hash there is nothing to compare, so the name match stands.
+                match report_content_hash(&reports_dir.join(&item.folder_name), false) {
+                    Ok(local_hash) => remote_hash.as_deref() != Some(local_hash.as_str()),
+                    Err(_) => false,
+                }
+            }
+            Some(_) => false,
+        })
+        .collect()
+}
 
+/// Payload of the `network_sync_progress` event, emitted after each report
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkSyncProgress {
+    pub reports_done: usize,
+    pub reports_total: usize,
+    /// Report that was just copied (or failed)
+    pub current_report: String,
+}
+
+/// A report that `sync_reports_to_network` could not copy
+#[derive(Debug, Serialize)]
+pub struct SyncFailure {
+    pub folder_name: String,
+    pub error: String,
+}
+
+/// Outcome of `sync_reports_to_network`
+#[derive(Debug, Default, Serialize)]
+pub struct NetworkSyncSummary {
+    /// Reports copied to the share
+    pub copied: usize,
+    /// Local reports already on the share
+    pub skipped: usize,
+    pub failed: Vec<SyncFailure>,
+    /// True when the sync was stopped before every report was handled
+    pub cancelled: bool,
+}
+
+/// Copies every local report that is not on the share yet, in one call.
+///
+/// Missing reports are found as in `find_reports_missing_on_network` (by folder name,
+/// plus content hash when `compare_hashes` is set) and copied one by one as
+/// `save_report_to_network` would, including its `validate_before_copy` check and
+/// per-report `timeout_seconds` deadline. A failed report doesn't stop the others.
+/// Each report is copied into a temporary folder on the share and renamed once
+/// complete, so an interrupted copy is never taken for a synced report. The share scan
+/// is bounded by the default listing timeout (10 seconds).
+/// Emits `network_sync_progress` events (payload: `NetworkSyncProgress`) after each
+/// report; `cancel_all_operations` stops the sync after the current file.
+///
+/// # Arguments
+/// * `unc_path` - Destination share (takes precedence over `network_config.unc_path`)
+/// * `network_config` - Copy options (`timeout_seconds`, `validate_before_copy`)
+/// * `compare_hashes` - Also re-copy reports whose content on the share differs
+#[tauri::command]
+pub async fn sync_reports_to_network(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    unc_path: String,
+    network_config: NetworkConfig,
+    compare_hashes: Option<bool>,
+) -> Result<NetworkSyncSummary, String> {
+    let logger = NetworkCopyLogger::new_from_state(&state);
+    let normalized = normalize_unc_path(&unc_path);
+    if normalized.is_empty() {
+        return Err("UNC path is empty".into());
+    }
+    let reports_dir = state.data_dir().join("reports");
+    let local = read_reports_page(&reports_dir, 0, usize::MAX, ReportSort::Timestamp)?.items;
+    let compare_hashes = compare_hashes.unwrap_or(false);
+    let dst_root = prepare_path_for_io(&PathBuf::from(&normalized));
+    let op = state.operations.register("network_sync");
     logger.log(format!(
-        "Resolved destination root '{}' (io path: '{}')",
+        "Starting network sync | unc_path='{}' | local reports={}",
         normalized,
-        dst_root.display()
+        local.len()
     ));
 
-    match fs::read_dir(&dst_root) {
-        Ok(_) => logger.log(format!(
-            "Verified network share is reachable: {}",
-            normalized
-        )),
-        Err(e) => {
-            let warn = format!(
-                "Warning: unable to list network share {}: {}",
-                normalized, e
-            );
-            logger.log(&warn);
-            if e.kind() == io::ErrorKind::NotFound {
-                return Err(format!("Network share not found: {}", normalized));
+    let summary = tauri::async_runtime::spawn_blocking(move || {
+        let cancel = op.token();
+        let names: HashSet<String> = local.iter().map(|r| r.folder_name.to_lowercase()).collect();
+        // Scan the share on its own thread so a hanging share can't stall the sync forever.
+        let (tx, rx) = std::sync::mpsc::channel();
+        let share = dst_root.clone();
+        let listing_cancel = cancel.clone();
+        std::thread::spawn(move || {
+            let res = remote_report_hashes(&share, &names, compare_hashes, &listing_cancel);
+            let _ = tx.send(res);
+        });
+        let remote = match rx.recv_timeout(network_timeout(None, NETWORK_LIST_TIMEOUT_SECS)) {
+            Ok(res) => res.map_err(|e| format!("Failed to list network reports: {}", e))?,
+            Err(_) => return Err("Network listing timed out".to_string()),
+        };
+        let local_count = local.len();
+        let missing = reports_missing_remotely(local, &reports_dir, &remote, compare_hashes);
+
+        let mut summary = NetworkSyncSummary {
+            skipped: local_count - missing.len(),
+            ..Default::default()
+        };
+        let timeout = network_timeout(network_config.timeout_seconds, NETWORK_COPY_TIMEOUT_SECS);
+        let mut log_fn = |line: String| logger.log(line);
+        for (index, item) in missing.iter().enumerate() {
+            if cancel.is_cancelled() {
+                summary.cancelled = true;
+                break;
             }
+            let src = prepare_path_for_io(&reports_dir.join(&item.folder_name));
+            let result = if network_config.validate_before_copy && !verify_report_dir(&src).ok {
+                Err("Report failed verification".to_string())
+            } else {
+                copy_report_to_share(
+                    &src,
+                    &dst_root,
+                    &item.folder_name,
+                    SystemTime::now() + timeout,
+                    &cancel,
+                    &mut log_fn,
+                )
+            };
+            match result {
+                Ok(()) => summary.copied += 1,
+                Err(_) if cancel.is_cancelled() => {
+                    summary.cancelled = true;
+                    break;
+                }
+                Err(error) => summary.failed.push(SyncFailure {
+                    folder_name: item.folder_name.clone(),
+                    error,
+                }),
+            }
+            let _ = app.emit(
+                "network_sync_progress",
+                NetworkSyncProgress {
+                    reports_done: index + 1,
+                    reports_total: missing.len(),
+                    current_report: item.folder_name.clone(),
+                },
+            );
         }
-    }
-
-    let dst = dst_root.join(&folder_name);
-    logger.log(format!(
-        "Copy target resolved to {}",
-        to_user_visible_path(&dst)
-    ));
-
-    // Allow additional time for network operations to reduce false timeouts on slower links
-    let timeout = Duration::from_secs(120);
-    let deadline = SystemTime::now() + timeout;
-
-    let mut log_fn = |line: String| logger.log(line);
-    copy_dir_recursive(&src, &dst, Some(deadline), &mut log_fn).map_err(|e| {
         logger.log(format!(
-            "Copy failed for {} -> {}: {}",
-            to_user_visible_path(&src_raw),
-            to_user_visible_path(&dst),
-            e
+            "Network sync finished | copied={} skipped={} failed={} cancelled={}",
+            summary.copied,
+            summary.skipped,
+            summary.failed.len(),
+            summary.cancelled
         ));
-        format!("Copy failed: {e}")
-    })?;
+        Ok::<_, String>(summary)
+    })
+    .await
+    .map_err(|e| format!("Network sync failed: {}", e))??;
 
-    logger.log(format!(
-        "Network copy completed successfully for {} -> {}",
-        to_user_visible_path(&src_raw),
-        to_user_visible_path(&dst)
-    ));
-    Ok(true)
+    remember_network_path(&state, &normalized);
+    Ok(summary)
 }
 
-fn list_reports_in_dir(dir: &Path) -> io::Result<Vec<ReportListItem>> {
-    let mut reports = Vec::new();
-    if !dir.exists() {
-        return Ok(reports);
+// Copy a report into a temporary folder on the share, then rename it to `folder_name`.
+// A failed or cancelled copy is removed again; an existing copy on the share is only
+// replaced once the new one is complete.
+fn copy_report_to_share<F>(
+    src: &Path,
+    dst_root: &Path,
+    folder_name: &str,
+    deadline: SystemTime,
+    cancel: &CancelToken,
+    log: &mut F,
+) -> Result<(), String>
+where
+    F: FnMut(String),
+{
+    let dst = dst_root.join(folder_name);
+    let partial = dst_root.join(format!(".{}.partial", folder_name));
+    if partial.exists() {
+        let _ = fs::remove_dir_all(&partial);
+    }
+    if let Err(e) = copy_dir_recursive(
+        src,
+        &partial,
+        Some(deadline),
+        cancel,
+        &mut CopySummary::default(),
+        None,
+        log,
+    ) {
+        let _ = fs::remove_dir_all(&partial);
+        return Err(e.to_string());
+    }
+    if dst.exists() {
+        if let Err(e) = fs::remove_dir_all(&dst) {
+            let _ = fs::remove_dir_all(&partial);
+            return Err(format!("Failed to replace the copy on the share: {}", e));
+        }
     }
+    fs::rename(&partial, &dst).map_err(|e| {
+        let _ = fs::remove_dir_all(&partial);
+        format!("Failed to move the copy into place: {}", e)
+    })
+}
+
+// Folder names on a share (lowercased). When `with_hashes` is set, folders that are in
+// `names` also get their content hash (None if report.json can't be read).
+fn remote_report_hashes(
+    dir: &Path,
+    names: &HashSet<String>,
+    with_hashes: bool,
+    cancel: &CancelToken,
+) -> io::Result<HashMap<String, Option<String>>> {
+    let mut remote = HashMap::new();
     for entry in fs::read_dir(dir)? {
-        let entry = match entry {
-            Ok(e) => e,
-            Err(_) => continue,
+        if cancel.is_cancelled() {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Network listing cancelled",
+            ));
+        }
+        let Ok(entry) = entry else {
+            continue;
         };
         let path = entry.path();
         if !path.is_dir() {
             continue;
         }
-        let folder_name = match path.file_name() {
-            Some(name) => name.to_string_lossy().to_string(),
-            None => continue,
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        let hash = if with_hashes && names.contains(&name) {
+            hash_report_contents(&path).ok()
+        } else {
+            None
         };
-        let has_report_json = path.join("report.json").exists();
-        let has_execution_log = path.join("execution.log").exists();
-        let has_run_plan = path.join("run_plan.json").exists();
-        let metadata = read_metadata(&path);
-        reports.push(ReportListItem {
-            folder_name,
-            folder_path: to_user_visible_path(&path),
-            metadata,
-            has_report_json,
-            has_execution_log,
-            has_run_plan,
-        });
+        remote.insert(name, hash);
     }
-    // Sort newest first similar to local implementation
-    reports.sort_by(|a, b| {
-        let a_time = a.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
-        let b_time = b.metadata.as_ref().map(|m| m.timestamp).unwrap_or(0);
-        b_time.cmp(&a_time)
-    });
-    Ok(reports)
+    Ok(remote)
 }
 
-/// Lists reports from a network UNC path.
+/// Tests connectivity to a network UNC directory by attempting to read its entries.
+///
+/// `timeout_seconds` bounds the test (defaults to 6 seconds). A reachable share is
+/// remembered as the last-used network path.
 #[tauri::command]
-pub fn list_network_reports(
-    _state: tauri::State<AppState>,
+pub fn test_network_path(
+    state: tauri::State<AppState>,
     unc_path: String,
-) -> Result<Vec<ReportListItem>, String> {
+    timeout_seconds: Option<u64>,
+) -> Result<bool, String> {
     let normalized = normalize_unc_path(&unc_path);
     let share_path = PathBuf::from(&normalized);
     let path = prepare_path_for_io(&share_path);
-
-    // Run in a worker thread with timeout to avoid UI freeze on hanging shares
     let (tx, rx) = std::sync::mpsc::channel();
     std::thread::spawn(move || {
-        let res = list_reports_in_dir(&path).map_err(|e| e.to_string());
+        let res = fs::read_dir(&path).map(|_| true).map_err(|e| e.to_string());
         let _ = tx.send(res);
     });
+    let result = match rx.recv_timeout(network_timeout(timeout_seconds, NETWORK_TEST_TIMEOUT_SECS))
+    {
+        Ok(v) => v,
+        Err(_) => Err("Network test timed out".into()),
+    };
+    if result.is_ok() {
+        remember_network_path(&state, &normalized);
+    }
+    result
+}
 
-    match rx.recv_timeout(Duration::from_secs(10)) {
-        Ok(res) => res,
-        Err(_) => Err("Network listing timed out".into()),
+// Record a share that just worked as the last-used one; failures are only logged.
+fn remember_network_path(state: &tauri::State<AppState>, normalized: &str) {
+    let path = trim_trailing_separators(normalized);
+    if let Err(e) = crate::settings::remember_network_path(&state.data_dir(), path) {
+        eprintln!("Warning: Failed to remember network path: {}", e);
     }
 }
 
-/// Tests connectivity to a network UNC directory by attempting to read its entries.
+/// Resolves a UNC path to the form the server reports for it.
+///
+/// Paths that differ only in casing or separators (`\\SERVER\Share` vs
+/// `//server/share/`) can otherwise end up stored as different destinations. The path
+/// is opened and resolved with `fs::canonicalize`, which returns the casing the share
+/// reports for each component. When the share can't be reached within
+/// `timeout_seconds` (defaults to 6 seconds), the `normalize_unc_path` form is returned
+/// instead, without a trailing separator.
+#[tauri::command]
+pub async fn canonicalize_unc_path(
+    unc_path: String,
+    timeout_seconds: Option<u64>,
+) -> Result<String, String> {
+    let normalized = normalize_unc_path(&unc_path);
+    if normalized.is_empty() {
+        return Err("UNC path is empty".into());
+    }
+    let fallback = trim_trailing_separators(&normalized).to_string();
+    let path = prepare_path_for_io(&PathBuf::from(&fallback));
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(fs::canonicalize(&path));
+    });
+    let timeout = network_timeout(timeout_seconds, NETWORK_TEST_TIMEOUT_SECS);
+    let resolved = tauri::async_runtime::spawn_blocking(move || rx.recv_timeout(timeout))
+        .await
+        .map_err(|e| format!("UNC canonicalization failed: {}", e))?;
+    Ok(match resolved {
+        Ok(Ok(canonical)) => {
+            trim_trailing_separators(&to_user_visible_path(&canonical)).to_string()
+        }
+        _ => fallback,
+    })
+}
+
+// Drop trailing path separators, keeping at least the leading `\\` / `//` of a UNC path.
+fn trim_trailing_separators(path: &str) -> &str {
+    let trimmed = path.trim_end_matches(['\\', '/']);
+    if trimmed.is_empty() {
+        path
+    } else {
+        trimmed
+    }
+}
+
+/// Deletes a report folder from a network UNC path.
+///
+/// The folder must be a direct child of the share and contain report.json, so an
+/// arbitrary folder cannot be removed by mistake. Runs on a worker thread bounded by
+/// `timeout_seconds` (defaults to 60 seconds); the worker is registered as the
+/// `network_delete` operation.
+///
+/// # Returns
+/// True if deletion succeeded, error message otherwise
 #[tauri::command]
-pub fn test_network_path(_state: tauri::State<AppState>, unc_path: String) -> Result<bool, String> {
+pub async fn delete_network_report(
+    state: tauri::State<'_, AppState>,
+    unc_path: String,
+    folder_name: String,
+    timeout_seconds: Option<u64>,
+) -> Result<bool, String> {
+    let logger = NetworkCopyLogger::new_from_state(&state);
     let normalized = normalize_unc_path(&unc_path);
+    if normalized.is_empty() {
+        return Err("UNC path is empty".into());
+    }
+    validate_report_folder_name(&folder_name)?;
+
     let share_path = PathBuf::from(&normalized);
-    let path = prepare_path_for_io(&share_path);
+    let folder = prepare_path_for_io(&share_path).join(&folder_name);
+    let visible = to_user_visible_path(&folder);
+    logger.log(format!("Deleting network report {}", visible));
+
+    let op = state.operations.register("network_delete");
     let (tx, rx) = std::sync::mpsc::channel();
+    let target = folder.clone();
     std::thread::spawn(move || {
-        let res = fs::read_dir(&path).map(|_| true).map_err(|e| e.to_string());
+        let res = if op.token().is_cancelled() {
+            Err("Network delete cancelled".to_string())
+        } else if !target.is_dir() {
+            Err(format!(
+                "Report folder not found: {}",
+                to_user_visible_path(&target)
+            ))
+        } else if !target.join("report.json").is_file() {
+            Err(format!(
+                "Not a report folder (no report.json): {}",
+                to_user_visible_path(&target)
+            ))
+        } else {
+            fs::remove_dir_all(&target)
+                .map(|_| true)
+                .map_err(|e| format!("Failed to delete report folder: {}", e))
+        };
         let _ = tx.send(res);
     });
-    match rx.recv_timeout(Duration::from_secs(6)) {
-        Ok(v) => v,
-        Err(_) => Err("Network test timed out".into()),
+
+    let timeout = network_timeout(timeout_seconds, NETWORK_DELETE_TIMEOUT_SECS);
+    let received = tauri::async_runtime::spawn_blocking(move || rx.recv_timeout(timeout))
+        .await
+        .map_err(|e| format!("Network delete failed: {}", e))?;
+    let res = match received {
+        Ok(res) => res,
+        Err(_) => Err(format!(
+            "Deleting {} timed out after {}s; it may still complete in the background",
+            visible,
+            timeout.as_secs()
+        )),
+    };
+    match &res {
+        Ok(_) => logger.log(format!("Deleted network report {}", visible)),
+        Err(e) => logger.log(format!("Delete failed for {}: {}", visible, e)),
     }
+    res
 }
 
 /// Opens an absolute path (file or directory) in the OS file explorer.
@@ -910,9 +3684,7 @@ fn generate_folder_name(
     timestamp: u64,
 ) -> String {
     // Use chrono to format human-readable date/time
-    let datetime = chrono::DateTime::from_timestamp(timestamp as i64, 0)
-        .unwrap_or_else(|| chrono::Utc::now().into());
-    let date_str = datetime.format("%Y-%m-%d_%H-%M-%S").to_string();
+    let date_str = format_folder_timestamp(timestamp);
 
     // Sanitize and use provided names
     let hostname_part = sanitize_name(hostname.unwrap_or("Unknown_PC"));
@@ -966,6 +3738,31 @@ fn sanitize_name(name: &str) -> String {
 mod tests {
     use super::*;
 
+    /// Scratch directory under the system temp dir, removed on drop even when a test panics
+    struct TempReportsDir(PathBuf);
+
+    impl TempReportsDir {
+        fn new(prefix: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "autoservice_{}_{}",
+                prefix,
+                uuid::Uuid::new_v4()
+            ));
+            fs::create_dir_all(&dir).unwrap();
+            TempReportsDir(dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempReportsDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
     #[test]
     fn test_sanitize_name() {
         assert_eq!(sanitize_name("John Doe"), "John_Doe");
@@ -974,6 +3771,19 @@ mod tests {
         assert_eq!(sanitize_name("  spaces  "), "spaces");
     }
 
+    #[test]
+    fn test_validate_report_folder_name() {
+        assert!(validate_report_folder_name("John_Doe_PC_2024-01-01_10-00-00").is_ok());
+        assert!(validate_report_folder_name("report..old").is_ok());
+        for bad in ["", ".", "..", "../secrets", "a/b", "a\\b", "C:evil"] {
+            assert!(
+                validate_report_folder_name(bad).is_err(),
+                "{:?} accepted",
+                bad
+            );
+        }
+    }
+
     #[test]
     fn test_generate_folder_name() {
         let timestamp = 1760000000; // Some fixed timestamp
@@ -998,6 +3808,407 @@ mod tests {
         assert!(name_no_tech.contains("__"));
     }
 
+    #[test]
+    fn test_verify_report_dir() {
+        let tmp = TempReportsDir::new("verify");
+        let dir = tmp.path();
+        let timestamp = 1760000000;
+        let folder = dir.join(generate_folder_name(Some("MyPC"), None, None, timestamp));
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("report.json"), "{\"results\": [").unwrap();
+        fs::write(
+            folder.join("metadata.json"),
+            serde_json::json!({
+                "timestamp": timestamp,
+                "hostname": "MyPC",
+                "customer_name": null,
+                "technician_name": null,
+                "saved_at": "2025-10-09T00:00:00+00:00",
+            })
+            .to_string(),
+        )
+        .unwrap();
+        fs::write(folder.join("execution.log"), "").unwrap();
+
+        let result = verify_report_dir(&folder);
+
+        assert!(!result.ok);
+        let files: Vec<&str> = result.issues.iter().map(|i| i.file.as_str()).collect();
+        assert!(files.contains(&"report.json"));
+        assert!(files.contains(&"execution.log"));
+        assert!(files.contains(&"run_plan.json"));
+        assert!(!files.contains(&"metadata.json"));
+    }
+
+    #[test]
+    fn test_copy_file_chunked() {
+        let tmp = TempReportsDir::new("filecopy");
+        let dir = tmp.path();
+        let src = dir.join("big.bin");
+        let dst = dir.join("copy.bin");
+        let data = vec![7u8; FILE_COPY_CHUNK_BYTES * 2 + 10];
+        fs::write(&src, &data).unwrap();
+
+        let mut events = Vec::new();
+        let copied = copy_file_chunked(&src, &dst, None, &CancelToken::default(), |p| {
+            events.push(p.bytes_done)
+        })
+        .unwrap();
+
+        let cancel = CancelToken::default();
+        cancel.cancel();
+        let cancelled = copy_file_chunked(&src, &dir.join("cancelled.bin"), None, &cancel, |_| {});
+        let copied_data = fs::read(&dst).unwrap();
+        let leftovers = fs::read_dir(dir).unwrap().count();
+
+        assert_eq!(copied, data.len() as u64);
+        assert_eq!(copied_data, data);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.last().copied(), Some(data.len() as u64));
+        assert!(cancelled.is_err());
+        assert_eq!(leftovers, 2);
+    }
+
+    #[test]
+    fn test_count_files_stops_when_cancelled() {
+        let tmp = TempReportsDir::new("count");
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("a.txt"), "abc").unwrap();
+        fs::write(dir.join("sub").join("b.txt"), "de").unwrap();
+
+        let counted = count_files(dir, &CancelToken::default());
+        let cancel = CancelToken::default();
+        cancel.cancel();
+        let cancelled = count_files(dir, &cancel);
+
+        assert_eq!(counted, (2, 5));
+        assert_eq!(cancelled, (0, 0));
+    }
+
+    #[test]
+    fn test_compress_execution_log_roundtrip() {
+        let tmp = TempReportsDir::new("gzlog");
+        let dir = tmp.path();
+        let log = "line\n".repeat(1000);
+        fs::write(dir.join("execution.log"), &log).unwrap();
+
+        let skipped = compress_execution_log(dir, 1024 * 1024).unwrap();
+        let compressed = compress_execution_log(dir, 0).unwrap();
+        let has_plain = dir.join("execution.log").exists();
+        let has_log = has_execution_log(dir);
+        let read_back = read_execution_log(dir);
+
+        assert!(!skipped);
+        assert!(compressed);
+        assert!(!has_plain);
+        assert!(has_log);
+        assert_eq!(read_back.as_deref(), Some(log.as_str()));
+    }
+
+    #[test]
+    fn test_append_report_note() {
+        let tmp = TempReportsDir::new("notes");
+        let dir = tmp.path();
+        assert!(read_report_notes(dir).is_empty());
+        append_report_note(dir, "Called customer").unwrap();
+        let notes = append_report_note(dir, "Replace fan next visit").unwrap();
+        let loaded = read_report_notes(dir);
+
+        assert_eq!(notes.len(), 2);
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].text, "Called customer");
+        assert_eq!(loaded[1].text, "Replace fan next visit");
+        assert!(chrono::DateTime::parse_from_rfc3339(&loaded[1].timestamp).is_ok());
+    }
+
+    #[test]
+    fn test_repair_metadata_without_metadata_file() {
+        let tmp = TempReportsDir::new("repair");
+        let dir = tmp.path();
+        let timestamp = 1760000000;
+        let name = generate_folder_name(
+            Some("MyPC"),
+            Some("John Doe"),
+            Some("Tech Smith"),
+            timestamp,
+        );
+        let folder = dir.join(&name);
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(
+            folder.join("report.json"),
+            r#"{"results": [{"status": "failure"}]}"#,
+        )
+        .unwrap();
+
+        let meta = repair_metadata_in(&folder, &["Tech Smith".to_string()]).unwrap();
+        let written = read_metadata(&folder);
+
+        assert_eq!(meta.timestamp, timestamp);
+        assert_eq!(meta.hostname.as_deref(), Some("MyPC"));
+        assert_eq!(meta.customer_name.as_deref(), Some("John Doe"));
+        assert_eq!(meta.technician_name.as_deref(), Some("Tech Smith"));
+        assert_eq!(meta.overall_status.as_deref(), Some("failure"));
+        assert_eq!(written.map(|m| m.timestamp), Some(timestamp));
+    }
+
+    #[test]
+    fn test_repair_metadata_with_truncated_json() {
+        let tmp = TempReportsDir::new("repair");
+        let dir = tmp.path();
+        let timestamp = 1760000000;
+        let folder = dir.join(generate_folder_name(None, None, None, timestamp));
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("metadata.json"), r#"{"timestamp": 17600"#).unwrap();
+
+        let meta = repair_metadata_in(&folder, &[]).unwrap();
+        let written = read_metadata(&folder);
+
+        assert_eq!(meta.timestamp, timestamp);
+        assert_eq!(meta.hostname, None);
+        assert_eq!(meta.customer_name, None);
+        assert_eq!(meta.technician_name, None);
+        assert_eq!(meta.overall_status, None);
+        assert!(written.is_some());
+    }
+
+    #[test]
+    fn test_reformat_json_keeps_order_and_floats() {
+        let text = r#"{"zeta": 1, "alpha": {"b": 0.30000000000000004, "a": 2.2250738585072014e-308},
+            "mid": [1.7976931348623157e308, 9007199254740993, -0.0]}"#;
+
+        let minified = reformat_json(text, false).unwrap();
+        let pretty = reformat_json(text, true).unwrap();
+
+        assert_eq!(
+            minified,
+            r#"{"zeta":1,"alpha":{"b":0.30000000000000004,"a":2.2250738585072014e-308},"mid":[1.7976931348623157e308,9007199254740993,-0.0]}"#
+        );
+        assert_eq!(reformat_json(&pretty, false).unwrap(), minified);
+        assert!(pretty.find("\"zeta\"").unwrap() < pretty.find("\"alpha\"").unwrap());
+        assert!(reformat_json("{\"a\": ", true).is_err());
+    }
+
+    #[test]
+    fn test_csv_row_quoting() {
+        let row = csv_row([
+            "PC-1".to_string(),
+            "Smith, John".to_string(),
+            "say \"hi\"".to_string(),
+            String::new(),
+        ]);
+        assert_eq!(row, "PC-1,\"Smith, John\",\"say \"\"hi\"\"\",\r\n");
+    }
+
+    #[test]
+    fn test_read_tail_bytes() {
+        let tmp = TempReportsDir::new("tail");
+        let dir = tmp.path();
+        let log = dir.join("execution.log");
+        fs::write(&log, "first line\nsecond line\nthird\n").unwrap();
+
+        let tail = |max_bytes| with_log_reader(&log, |r| read_tail_bytes(r, max_bytes)).unwrap();
+        let partial = tail(15);
+        let exact = tail(18);
+        let whole = tail(1000);
+
+        assert_eq!(partial.content, "third\n");
+        assert_eq!(partial.start_offset, 23);
+        assert_eq!(exact.content, "second line\nthird\n");
+        assert_eq!(exact.start_offset, 11);
+        assert_eq!(whole.content, "first line\nsecond line\nthird\n");
+        assert_eq!(whole.start_offset, 0);
+        assert_eq!(whole.file_size, 29);
+    }
+
+    #[test]
+    fn test_diff_json_values() {
+        let before = serde_json::json!({
+            "summary": { "status": "failure", "score": 40 },
+            "results": [
+                { "task": "sfc", "status": "failure" },
+                { "task": "disk", "status": "success" }
+            ],
+            "notes": "first run"
+        });
+        let after = serde_json::json!({
+            "summary": { "status": "success", "score": 40, "a/b": true },
+            "results": [
+                { "task": "sfc", "status": "success" }
+            ],
+            "extra": {}
+        });
+
+        let mut diff = ReportDiff::default();
+        diff_json_values("", Some(&before), Some(&after), &mut diff);
+        diff.changed.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths = |changes: &[JsonChange]| -> Vec<String> {
+            let mut p: Vec<String> = changes.iter().map(|c| c.path.clone()).collect();
+            p.sort();
+            p
+        };
+
+        assert_eq!(paths(&diff.added), vec!["/extra", "/summary/a~1b"]);
+        assert_eq!(
+            paths(&diff.removed),
+            vec!["/notes", "/results/1/status", "/results/1/task"]
+        );
+        assert_eq!(
+            diff.changed,
+            vec![
+                JsonChange {
+                    path: "/results/0/status".into(),
+                    old_value: Some("failure".into()),
+                    new_value: Some("success".into()),
+                },
+                JsonChange {
+                    path: "/summary/status".into(),
+                    old_value: Some("failure".into()),
+                    new_value: Some("success".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_same_second_saves_get_separate_folders() {
+        let tmp = TempReportsDir::new("save");
+        let dir = tmp.path();
+        let request = |json: &str| SaveReportRequest {
+            report_json: json.to_string(),
+            plan_file_path: None,
+            log_file_path: None,
+            hostname: Some("MyPC".into()),
+            customer_name: Some("John Doe".into()),
+            technician_name: None,
+        };
+        let timestamp = 1760000000;
+
+        let (first, first_conflict) =
+            write_report_folder(dir, &request("{\"n\": 1}"), timestamp).unwrap();
+        let (second, second_conflict) =
+            write_report_folder(dir, &request("{\"n\": 2}"), timestamp).unwrap();
+        let first_json = fs::read_to_string(first.join("report.json")).unwrap();
+        let second_json = fs::read_to_string(second.join("report.json")).unwrap();
+        let second_name = second.file_name().unwrap().to_string_lossy().to_string();
+
+        assert_ne!(first, second);
+        assert!(!first_conflict);
+        assert!(second_conflict);
+        assert!(second_name.ends_with("_2"));
+        assert_eq!(first_json, "{\"n\": 1}");
+        assert_eq!(second_json, "{\"n\": 2}");
+        assert_eq!(
+            folder_name_timestamp(&second_name),
+            Some(format_folder_timestamp(timestamp).as_str())
+        );
+    }
+
+    #[test]
+    fn test_create_unique_dir_and_sanitize() {
+        let tmp = TempReportsDir::new("unique");
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("PC_report")).unwrap();
+        fs::create_dir_all(dir.join("PC_report_2")).unwrap();
+
+        let taken = create_unique_dir(dir, "PC_report").unwrap();
+        let free = create_unique_dir(dir, "Other").unwrap();
+
+        assert_eq!(taken, dir.join("PC_report_3"));
+        assert!(taken.is_dir());
+        assert_eq!(free, dir.join("Other"));
+        assert_eq!(
+            sanitize_folder_name("MyPC_John__2025-10-09_12-00-00"),
+            "MyPC_John__2025-10-09_12-00-00"
+        );
+        assert_eq!(sanitize_folder_name("../evil name"), "evil_name");
+        assert_eq!(sanitize_folder_name("..."), "imported_report");
+    }
+
+    #[test]
+    fn test_read_last_lines() {
+        let tmp = TempReportsDir::new("tail");
+        let path = tmp.path().join("execution.log");
+        let content: String = (1..=5000).map(|i| format!("line {}\n", i)).collect();
+        fs::write(&path, content).unwrap();
+
+        let last = |count| with_log_reader(&path, |r| read_last_lines(r, count)).unwrap();
+        let tail = last(3);
+        let all = last(10_000);
+        let none = last(0);
+
+        assert_eq!(tail, vec!["line 4998", "line 4999", "line 5000"]);
+        assert_eq!(all.len(), 5000);
+        assert_eq!(all[0], "line 1");
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_read_compressed_log_tail() {
+        let tmp = TempReportsDir::new("gztail");
+        let dir = tmp.path();
+        fs::write(
+            dir.join("execution.log"),
+            "first line\nsecond line\nthird\n",
+        )
+        .unwrap();
+        assert!(compress_execution_log(dir, 0).unwrap());
+
+        let gz = dir.join(COMPRESSED_LOG_NAME);
+        let lines = with_log_reader(&gz, |r| read_last_lines(r, 2)).unwrap();
+        let chunk = with_log_reader(&gz, |r| read_tail_bytes(r, 15)).unwrap();
+
+        assert_eq!(lines, vec!["second line", "third"]);
+        assert_eq!(chunk.content, "third\n");
+        assert_eq!(chunk.file_size, 29);
+    }
+
+    #[test]
+    fn test_search_lines_context() {
+        let log = "start\nok\nERROR one\nok\nok\nerror two\nend\n";
+        let matcher = |l: &str| l.to_lowercase().contains("error");
+        let result = search_lines(io::Cursor::new(log), &matcher, 1, 10).unwrap();
+
+        assert!(!result.truncated);
+        assert_eq!(result.matches.len(), 2);
+        assert_eq!(result.matches[0].line_number, 3);
+        assert_eq!(result.matches[0].before, vec!["ok"]);
+        assert_eq!(result.matches[0].after, vec!["ok"]);
+        assert_eq!(result.matches[1].line, "error two");
+        assert_eq!(result.matches[1].after, vec!["end"]);
+
+        let bounded = search_lines(io::Cursor::new(log), &matcher, 0, 1).unwrap();
+        assert!(bounded.truncated);
+        assert_eq!(bounded.matches.len(), 1);
+    }
+
+    #[test]
+    fn test_canonical_json_ignores_key_order() {
+        let a: serde_json::Value =
+            serde_json::from_str(r#"{"b": 1, "a": {"y": [1, 2], "x": "s"}}"#).unwrap();
+        let b: serde_json::Value =
+            serde_json::from_str(r#"{"a": {"x": "s", "y": [1, 2]}, "b": 1}"#).unwrap();
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(canonical_json(&a), r#"{"a":{"x":"s","y":[1,2]},"b":1}"#);
+    }
+
+    #[test]
+    fn test_compute_overall_status() {
+        let passed = serde_json::json!({
+            "overall_status": "success",
+            "results": [{"status": "success"}, {"status": "skipped"}],
+        });
+        let failed = serde_json::json!({
+            "overall_status": "completed_with_errors",
+            "results": [{"status": "success"}, {"status": "failure"}],
+        });
+        let legacy = serde_json::json!({"overall_status": "completed_with_errors"});
+        assert_eq!(compute_overall_status(&passed).as_deref(), Some("success"));
+        assert_eq!(compute_overall_status(&failed).as_deref(), Some("failure"));
+        assert_eq!(compute_overall_status(&legacy).as_deref(), Some("failure"));
+        assert_eq!(compute_overall_status(&serde_json::json!({})), None);
+    }
+
     #[test]
     fn test_generate_folder_name_defaults() {
         let timestamp = 1760000000;
@@ -1005,4 +4216,24 @@ mod tests {
         assert!(name.contains("Unknown_PC"));
         assert!(name.contains("Report"));
     }
+
+    #[test]
+    fn test_progress_weights() {
+        let history = HashMap::from([
+            ("sfc_scan".to_string(), vec![10.0, 30.0, 20.0]),
+            ("chkdsk_scan".to_string(), vec![60.0]),
+        ]);
+        let plan = ["sfc_scan", "chkdsk_scan", "ping_test"].map(String::from);
+        let weights = progress_weights(&plan, &history);
+        assert_eq!(weights[0].median_seconds, Some(20.0));
+        assert_eq!(weights[2].median_seconds, None);
+        // The unknown task gets the average of the known medians (40s of 120s)
+        let expected = [1.0 / 6.0, 0.5, 1.0 / 3.0];
+        for (w, e) in weights.iter().zip(expected) {
+            assert!((w.weight - e).abs() < 1e-9);
+        }
+
+        let unknown = progress_weights(&plan, &HashMap::new());
+        assert!(unknown.iter().all(|w| (w.weight - 1.0 / 3.0).abs() < 1e-9));
+    }
 }
@@ -19,8 +19,66 @@ use std::{
 use tauri::Manager;
 use uuid::Uuid;
 
+use serde::Serialize;
+
 use crate::{models::ScriptEntry, paths, state::AppState};
 
+/// Number of leading lines of a script searched for a `#Requires -RunAsAdministrator`
+/// directive.
+const REQUIRES_SCAN_LINES: usize = 100;
+
+/// Folder (relative to the data directory) holding downloaded copies of link scripts.
+const SCRIPT_CACHE_DIR: &str = "resources/script_cache";
+/// Timeout in seconds and size limit for downloading a script into the cache.
+const CACHE_FETCH_TIMEOUT_SECS: u32 = 60;
+const CACHE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Maximum number of bytes returned by `preview_script`.
+const PREVIEW_MAX_BYTES: usize = 256 * 1024;
+/// Timeout in seconds for fetching a link script's content for preview.
+const PREVIEW_FETCH_TIMEOUT_SECS: u32 = 15;
+
+/// Content of a script as returned by `preview_script`.
+#[derive(Debug, Serialize)]
+pub struct ScriptPreview {
+    /// Script text (lossily decoded as UTF-8), capped at `PREVIEW_MAX_BYTES`
+    pub content: String,
+    /// True when the script is larger than the preview cap
+    pub truncated: bool,
+}
+
+/// A syntax problem found by `check_script_syntax`.
+#[derive(Debug, Serialize)]
+pub struct ScriptSyntaxError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number
+    pub column: usize,
+    pub message: String,
+}
+
+/// Outcome of `check_script_syntax`.
+#[derive(Debug, Serialize)]
+pub struct ScriptSyntaxCheck {
+    /// True when no syntax errors were found
+    pub valid: bool,
+    /// True for heuristic checks (cmd), which can miss errors
+    pub best_effort: bool,
+    pub errors: Vec<ScriptSyntaxError>,
+}
+
+/// Whether a script will prompt for elevation, and why.
+#[derive(Debug, Serialize)]
+pub struct ElevationRequirement {
+    /// True when running the script triggers a UAC prompt
+    pub requires_elevation: bool,
+    /// Machine-readable reason: "admin_runner" or "requires_directive"; None when
+    /// elevation is not required
+    pub reason: Option<String>,
+    /// Human-readable explanation for the UI
+    pub detail: String,
+}
+
 /// Constructs the path to the scripts configuration file (scripts.json) within the settings directory.
 ///
 /// # Arguments
@@ -28,11 +86,21 @@ use crate::{models::ScriptEntry, paths, state::AppState};
 ///
 /// # Returns
 /// A `PathBuf` pointing to the scripts.json file.
-fn scripts_json_path(data_root: &Path) -> PathBuf {
+pub(crate) fn scripts_json_path(data_root: &Path) -> PathBuf {
     let (_reports, _programs, settings, _resources) = paths::subdirs(data_root);
     settings.join("scripts.json")
 }
 
+/// Resolves a file script's path, treating relative paths as relative to the data directory.
+pub(crate) fn resolve_script_path(data_root: &Path, path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() {
+        path
+    } else {
+        data_root.join(path)
+    }
+}
+
 /// Reads and parses the scripts configuration file into a vector of ScriptEntry objects.
 ///
 /// If the file doesn't exist or parsing fails, returns an empty vector.
@@ -42,7 +110,7 @@ fn scripts_json_path(data_root: &Path) -> PathBuf {
 ///
 /// # Returns
 /// A vector of `ScriptEntry` objects.
-fn read_scripts_file(path: &Path) -> Vec<ScriptEntry> {
+pub(crate) fn read_scripts_file(path: &Path) -> Vec<ScriptEntry> {
     if let Ok(data) = fs::read_to_string(path) {
         if let Ok(list) = serde_json::from_str::<Vec<ScriptEntry>>(&data) {
             return list;
@@ -53,7 +121,7 @@ fn read_scripts_file(path: &Path) -> Vec<ScriptEntry> {
 
 /// Writes a vector of ScriptEntry objects to the scripts configuration file in pretty JSON format.
 ///
-/// Creates the parent directory if it doesn't exist.
+/// The file is replaced atomically; the parent directory is created if it doesn't exist.
 ///
 /// # Arguments
 /// * `path` - The path to the scripts.json file.
@@ -61,13 +129,9 @@ fn read_scripts_file(path: &Path) -> Vec<ScriptEntry> {
 ///
 /// # Returns
 /// A `Result` indicating success or containing an error string.
-fn write_scripts_file(path: &Path, list: &Vec<ScriptEntry>) -> Result<(), String> {
-    let parent = path
-        .parent()
-        .ok_or_else(|| "Invalid settings path".to_string())?;
-    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+pub(crate) fn write_scripts_file(path: &Path, list: &Vec<ScriptEntry>) -> Result<(), String> {
     let data = serde_json::to_string_pretty(list).map_err(|e| e.to_string())?;
-    fs::write(path, data).map_err(|e| e.to_string())
+    crate::fs_utils::write_atomic(path, data)
 }
 
 /// Retrieves the list of all stored scripts from the configuration file.
@@ -83,7 +147,8 @@ fn write_scripts_file(path: &Path, list: &Vec<ScriptEntry>) -> Result<(), String
 /// A `Result` containing either a vector of `ScriptEntry` objects or an error string.
 #[tauri::command]
 pub fn list_scripts(state: tauri::State<AppState>) -> Result<Vec<ScriptEntry>, String> {
-    let data_root = state.data_dir.as_path();
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
     let settings_path = scripts_json_path(data_root);
     let mut list = read_scripts_file(&settings_path);
     for script_entry in &mut list {
@@ -116,13 +181,14 @@ pub fn list_scripts(state: tauri::State<AppState>) -> Result<Vec<ScriptEntry>, S
 /// A `Result` indicating success or containing an error string.
 #[tauri::command]
 pub fn save_script(state: tauri::State<AppState>, script: ScriptEntry) -> Result<(), String> {
-    let settings_path = scripts_json_path(state.data_dir.as_path());
+    let settings_path = scripts_json_path(&state.data_dir());
     let mut entry = script;
     // For file source, if the path is absolute and under data root, store relative for portability
     if entry.source == "file" {
         let script_path = PathBuf::from(&entry.path);
         if script_path.is_absolute() {
-            let data_root = state.data_dir.as_path();
+            let data_dir = state.data_dir();
+            let data_root = data_dir.as_path();
             if let Ok(stripped) = script_path.strip_prefix(data_root) {
                 entry.path = stripped.to_string_lossy().to_string();
             }
@@ -149,12 +215,131 @@ pub fn save_script(state: tauri::State<AppState>, script: ScriptEntry) -> Result
 /// A `Result` indicating success or containing an error string.
 #[tauri::command]
 pub fn remove_script(state: tauri::State<AppState>, id: Uuid) -> Result<(), String> {
-    let settings_path = scripts_json_path(state.data_dir.as_path());
+    let settings_path = scripts_json_path(&state.data_dir());
     let mut list = read_scripts_file(&settings_path);
     list.retain(|script| script.id != id);
     write_scripts_file(&settings_path, &list)
 }
 
+/// Downloads a link script into the local script cache and switches it to run from there.
+///
+/// The content is saved to `resources/script_cache/{id}.{ext}` (`.ps1` or `.cmd` by runner)
+/// and the entry becomes a "file" script pointing at it. The original URL is kept so the
+/// cache can be updated with `refresh_cached_script`.
+///
+/// # Arguments
+/// * `state` - The application state containing the data directory path.
+/// * `id` - The UUID of the link script to cache.
+///
+/// # Returns
+/// The updated `ScriptEntry`, or an error string if the download failed.
+#[tauri::command]
+pub async fn cache_script(
+    state: tauri::State<'_, AppState>,
+    id: Uuid,
+) -> Result<ScriptEntry, String> {
+    let data_root = state.data_dir();
+    tauri::async_runtime::spawn_blocking(move || -> Result<ScriptEntry, String> {
+        let settings_path = scripts_json_path(&data_root);
+        let mut list = read_scripts_file(&settings_path);
+        let entry = list
+            .iter_mut()
+            .find(|s| s.id == id)
+            .ok_or_else(|| format!("Script not found: {}", id))?;
+        if entry.url.trim().is_empty() {
+            return Err("Script has no URL to cache".into());
+        }
+        let relative = cached_script_relative_path(entry);
+        download_script(&entry.url, &data_root.join(&relative))?;
+        entry.source = "file".into();
+        entry.path = relative;
+        let updated = entry.clone();
+        write_scripts_file(&settings_path, &list)?;
+        Ok(updated)
+    })
+    .await
+    .map_err(|e| format!("Cache worker failed: {}", e))?
+}
+
+/// Re-downloads a cached script from its original URL, replacing the cached file.
+///
+/// # Arguments
+/// * `state` - The application state containing the data directory path.
+/// * `id` - The UUID of a script previously cached with `cache_script`.
+///
+/// # Returns
+/// The `ScriptEntry`, or an error string if it is not cached or the download failed.
+/// On failure the previously cached copy is left untouched.
+#[tauri::command]
+pub async fn refresh_cached_script(
+    state: tauri::State<'_, AppState>,
+    id: Uuid,
+) -> Result<ScriptEntry, String> {
+    let data_root = state.data_dir();
+    tauri::async_runtime::spawn_blocking(move || -> Result<ScriptEntry, String> {
+        let entry = read_scripts_file(&scripts_json_path(&data_root))
+            .into_iter()
+            .find(|s| s.id == id)
+            .ok_or_else(|| format!("Script not found: {}", id))?;
+        let is_cached = entry.source == "file"
+            && !entry.url.trim().is_empty()
+            && Path::new(&entry.path).starts_with(SCRIPT_CACHE_DIR);
+        if !is_cached {
+            return Err("Script is not a cached link script".into());
+        }
+        download_script(&entry.url, &data_root.join(&entry.path))?;
+        Ok(entry)
+    })
+    .await
+    .map_err(|e| format!("Cache worker failed: {}", e))?
+}
+
+/// Cache file location of a script, relative to the data directory.
+fn cached_script_relative_path(script: &ScriptEntry) -> String {
+    let ext = if script.runner.to_lowercase().starts_with("cmd") {
+        "cmd"
+    } else {
+        "ps1"
+    };
+    format!("{}/{}.{}", SCRIPT_CACHE_DIR, script.id, ext)
+}
+
+/// Downloads `url` to `dest` with curl, replacing `dest` only after a complete download.
+fn download_script(url: &str, dest: &Path) -> Result<(), String> {
+    use std::process::Command;
+    let url = url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err("Script URL must start with http:// or https://".into());
+    }
+    let parent = dest
+        .parent()
+        .ok_or_else(|| "Invalid cache path".to_string())?;
+    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    let tmp = dest.with_extension("download");
+    let output = Command::new("curl")
+        .args(["-sSL", "--fail", "--max-time"])
+        .arg(CACHE_FETCH_TIMEOUT_SECS.to_string())
+        .arg("--max-filesize")
+        .arg(CACHE_MAX_BYTES.to_string())
+        .arg("-o")
+        .arg(&tmp)
+        .arg(url)
+        .output()
+        .map_err(|e| format!("Failed to start curl: {}", e))?;
+    if !output.status.success() {
+        let _ = fs::remove_file(&tmp);
+        return Err(format!(
+            "Failed to download {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    fs::rename(&tmp, dest).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        format!("Failed to store cached script: {}", e)
+    })
+}
+
 /// Executes a script using the appropriate runner (PowerShell or CMD) with optional administrative privileges.
 ///
 /// This function spawns a new console window to run the script, ensuring visibility.
@@ -195,13 +380,11 @@ pub async fn run_script(app: tauri::AppHandle, script: ScriptEntry) -> Result<()
         }
 
         // Resolve file path relative to data directory if not absolute
-        let data_root = app.state::<AppState>().data_dir.clone();
+        let data_root = app.state::<AppState>().data_dir();
         let resolve_path = |path_str: String| -> String {
-            let pb = PathBuf::from(&path_str);
-            if pb.is_absolute() {
-                return path_str;
-            }
-            data_root.join(pb).to_string_lossy().to_string()
+            resolve_script_path(&data_root, &path_str)
+                .to_string_lossy()
+                .to_string()
         };
 
         // Build the target executable and its arguments based on runner type
@@ -308,3 +491,413 @@ pub async fn run_script(app: tauri::AppHandle, script: ScriptEntry) -> Result<()
         Ok(())
     }
 }
+
+/// Determines whether running a script will require administrative privileges.
+///
+/// Scripts using an `-admin` runner always elevate. PowerShell scripts (file or inline)
+/// are also checked for a `#Requires -RunAsAdministrator` directive in their first lines,
+/// which makes PowerShell refuse to run them unelevated.
+///
+/// # Arguments
+/// * `state` - The application state containing the data directory path.
+/// * `script` - The `ScriptEntry` to check.
+/// * `inspect_content` - Whether to scan the script body for the directive (default true).
+///
+/// # Returns
+/// An `ElevationRequirement` with a machine-readable reason and a description.
+#[tauri::command]
+pub fn script_requires_elevation(
+    state: tauri::State<AppState>,
+    script: ScriptEntry,
+    inspect_content: Option<bool>,
+) -> Result<ElevationRequirement, String> {
+    let runner = script.runner.to_lowercase();
+    if runner.ends_with("-admin") {
+        return Ok(ElevationRequirement {
+            requires_elevation: true,
+            reason: Some("admin_runner".into()),
+            detail: format!(
+                "The '{}' runner always runs as administrator",
+                script.runner
+            ),
+        });
+    }
+
+    if inspect_content.unwrap_or(true) && runner.starts_with("powershell") {
+        let content = match script.source.as_str() {
+            "file" => {
+                let path = resolve_script_path(&state.data_dir(), &script.path);
+                fs::read(&path)
+                    .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+                    .ok()
+            }
+            "inline" => Some(script.inline.clone()),
+            // Remote content is not fetched just to check for the directive.
+            _ => None,
+        };
+        if content.as_deref().is_some_and(has_run_as_admin_directive) {
+            return Ok(ElevationRequirement {
+                requires_elevation: true,
+                reason: Some("requires_directive".into()),
+                detail: "The script declares #Requires -RunAsAdministrator".into(),
+            });
+        }
+    }
+
+    Ok(ElevationRequirement {
+        requires_elevation: false,
+        reason: None,
+        detail: "The script runs without elevation".into(),
+    })
+}
+
+/// Checks the leading lines of a PowerShell script for `#Requires -RunAsAdministrator`.
+fn has_run_as_admin_directive(content: &str) -> bool {
+    content.lines().take(REQUIRES_SCAN_LINES).any(|line| {
+        let line = line.trim().to_ascii_lowercase();
+        line.strip_prefix("#requires")
+            .is_some_and(|rest| rest.contains("-runasadministrator"))
+    })
+}
+
+/// Returns the content of a script without executing it.
+///
+/// - "file": reads the script file (relative paths resolve against the data directory)
+/// - "link": downloads the URL with curl, with a timeout, without running it
+/// - "inline": returns the stored command text
+///
+/// Content is capped at `PREVIEW_MAX_BYTES`; `truncated` tells whether more exists.
+///
+/// # Arguments
+/// * `state` - The application state containing the data directory path.
+/// * `script` - The `ScriptEntry` to preview.
+///
+/// # Returns
+/// A `ScriptPreview` or an error string if the content could not be read or fetched.
+#[tauri::command]
+pub async fn preview_script(
+    state: tauri::State<'_, AppState>,
+    script: ScriptEntry,
+) -> Result<ScriptPreview, String> {
+    let data_root = state.data_dir();
+    tauri::async_runtime::spawn_blocking(move || -> Result<ScriptPreview, String> {
+        let bytes = load_script_content(&data_root, &script)?;
+        let truncated = bytes.len() > PREVIEW_MAX_BYTES;
+        let shown = &bytes[..bytes.len().min(PREVIEW_MAX_BYTES)];
+        Ok(ScriptPreview {
+            content: String::from_utf8_lossy(shown).to_string(),
+            truncated,
+        })
+    })
+    .await
+    .map_err(|e| format!("Preview worker failed: {}", e))?
+}
+
+/// Loads up to `PREVIEW_MAX_BYTES + 1` bytes of a script's content from its source.
+fn load_script_content(data_root: &Path, script: &ScriptEntry) -> Result<Vec<u8>, String> {
+    match script.source.as_str() {
+        "file" => {
+            if script.path.trim().is_empty() {
+                return Err("Script path is empty".into());
+            }
+            let path = resolve_script_path(data_root, &script.path);
+            let file = fs::File::open(&path)
+                .map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+            read_capped(file)
+        }
+        "link" => fetch_capped(&script.url),
+        _ => Ok(script.inline.clone().into_bytes()),
+    }
+}
+
+/// Reads at most `PREVIEW_MAX_BYTES + 1` bytes, enough to detect truncation.
+fn read_capped(reader: impl std::io::Read) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+    let mut buf = Vec::new();
+    reader
+        .take(PREVIEW_MAX_BYTES as u64 + 1)
+        .read_to_end(&mut buf)
+        .map_err(|e| format!("Failed to read script: {}", e))?;
+    Ok(buf)
+}
+
+/// Downloads the start of `url` with curl without executing anything.
+fn fetch_capped(url: &str) -> Result<Vec<u8>, String> {
+    use std::process::{Command, Stdio};
+    let url = url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err("Script URL must start with http:// or https://".into());
+    }
+    let mut child = Command::new("curl")
+        .args(["-sSL", "--fail", "--max-time"])
+        .arg(PREVIEW_FETCH_TIMEOUT_SECS.to_string())
+        .arg(url)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start curl: {}", e))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "Failed to capture curl output".to_string())?;
+    let body = read_capped(stdout);
+    let truncated = body.as_ref().is_ok_and(|b| b.len() > PREVIEW_MAX_BYTES);
+    if truncated {
+        // We have enough for the preview; stop the rest of the download.
+        let _ = child.kill();
+        let _ = child.wait();
+        return body;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("curl failed: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to fetch {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    body
+}
+
+/// Checks a script for syntax errors without executing it.
+///
+/// PowerShell scripts are parsed with the PowerShell language parser, which reports
+/// every error with its position. CMD scripts get a best-effort check for unbalanced
+/// parentheses and `goto`/`call` targets that have no matching label.
+///
+/// # Arguments
+/// * `state` - The application state containing the data directory path.
+/// * `script` - The `ScriptEntry` to check (any source).
+///
+/// # Returns
+/// A `ScriptSyntaxCheck` listing the errors found, or an error string if the script
+/// content could not be loaded or parsed.
+#[tauri::command]
+pub async fn check_script_syntax(
+    state: tauri::State<'_, AppState>,
+    script: ScriptEntry,
+) -> Result<ScriptSyntaxCheck, String> {
+    let data_root = state.data_dir();
+    tauri::async_runtime::spawn_blocking(move || -> Result<ScriptSyntaxCheck, String> {
+        let bytes = load_script_content(&data_root, &script)?;
+        if bytes.len() > PREVIEW_MAX_BYTES {
+            return Err("Script is too large to check".into());
+        }
+        let content = String::from_utf8_lossy(&bytes).to_string();
+        let is_cmd = script.runner.to_lowercase().starts_with("cmd");
+        let (errors, best_effort) = if is_cmd {
+            (check_cmd_syntax(&content), true)
+        } else {
+            (check_powershell_syntax(&content)?, false)
+        };
+        Ok(ScriptSyntaxCheck {
+            valid: errors.is_empty(),
+            best_effort,
+            errors,
+        })
+    })
+    .await
+    .map_err(|e| format!("Syntax check worker failed: {}", e))?
+}
+
+/// Parses PowerShell code with `System.Management.Automation.Language.Parser`.
+#[cfg(windows)]
+fn check_powershell_syntax(content: &str) -> Result<Vec<ScriptSyntaxError>, String> {
+    use std::process::Command;
+
+    // Parse from a temp file so scripts of any length or quoting survive intact. The
+    // BOM makes Windows PowerShell read it as UTF-8.
+    let tmp = std::env::temp_dir().join(format!("autoservice_syntax_{}.ps1", Uuid::new_v4()));
+    fs::write(&tmp, format!("\u{feff}{}", content))
+        .map_err(|e| format!("Failed to write temp script: {}", e))?;
+    let ps = format!(
+        "$tokens = $null; $errors = $null; \
+         [void][System.Management.Automation.Language.Parser]::ParseFile('{}', [ref]$tokens, [ref]$errors); \
+         ConvertTo-Json -Compress -InputObject @($errors | ForEach-Object {{ \
+         [pscustomobject]@{{ line = $_.Extent.StartLineNumber; column = $_.Extent.StartColumnNumber; message = $_.Message }} }})",
+        tmp.to_string_lossy().replace('\'', "''")
+    );
+    let output = Command::new("powershell.exe")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-ExecutionPolicy",
+            "Bypass",
+            "-Command",
+            &ps,
+        ])
+        .output();
+    let _ = fs::remove_file(&tmp);
+    let output = output.map_err(|e| format!("Failed to start PowerShell: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "PowerShell parser failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let parsed: Vec<serde_json::Value> =
+        serde_json::from_str(String::from_utf8_lossy(&output.stdout).trim())
+            .map_err(|e| format!("Unexpected parser output: {}", e))?;
+    Ok(parsed
+        .iter()
+        .map(|e| ScriptSyntaxError {
+            line: e.get("line").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
+            column: e.get("column").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
+            message: e
+                .get("message")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Syntax error")
+                .to_string(),
+        })
+        .collect())
+}
+
+#[cfg(not(windows))]
+fn check_powershell_syntax(_content: &str) -> Result<Vec<ScriptSyntaxError>, String> {
+    Err("PowerShell syntax checking is only supported on Windows".into())
+}
+
+/// Heuristic CMD checks: unbalanced parentheses and jumps to undefined labels.
+fn check_cmd_syntax(content: &str) -> Vec<ScriptSyntaxError> {
+    let mut errors = Vec::new();
+    let mut labels = Vec::new();
+    let mut jumps = Vec::new();
+    // Open parentheses as (line, column)
+    let mut open: Vec<(usize, usize)> = Vec::new();
+
+    for (idx, raw) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim_start();
+        let lower = line.to_ascii_lowercase();
+        if line.starts_with("::") || lower == "rem" || lower.starts_with("rem ") {
+            continue;
+        }
+        if let Some(label) = line.strip_prefix(':') {
+            labels.push(
+                label
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or("")
+                    .to_ascii_lowercase(),
+            );
+            continue;
+        }
+        for keyword in ["goto", "call"] {
+            let mut words = lower.split_whitespace();
+            while let Some(word) = words.next() {
+                if word.trim_start_matches(['(', '@']) != keyword {
+                    continue;
+                }
+                let Some(target) = words.next() else { break };
+                // `call` only jumps for `:label`; otherwise it runs another program.
+                if keyword == "call" && !target.starts_with(':') {
+                    continue;
+                }
+                let target = target.trim_start_matches(':').trim_end_matches(')');
+                if !target.is_empty() && !target.contains('%') && target != "eof" {
+                    jumps.push((line_no, target.to_string()));
+                }
+            }
+        }
+
+        let mut in_quotes = false;
+        let mut escaped = false;
+        for (col, ch) in raw.chars().enumerate() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match ch {
+                '^' if !in_quotes => escaped = true,
+                '"' => in_quotes = !in_quotes,
+                '(' if !in_quotes => open.push((line_no, col + 1)),
+                ')' if !in_quotes => {
+                    let unmatched = open.pop().is_none();
+                    if unmatched && !prints_literal_paren(&lower) {
+                        errors.push(ScriptSyntaxError {
+                            line: line_no,
+                            column: col + 1,
+                            message: "Unmatched closing parenthesis".into(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (line, column) in open {
+        errors.push(ScriptSyntaxError {
+            line,
+            column,
+            message: "Parenthesis is never closed".into(),
+        });
+    }
+    for (line, target) in jumps {
+        if !labels.contains(&target) {
+            errors.push(ScriptSyntaxError {
+                line,
+                column: 1,
+                message: format!("Label :{} is not defined", target),
+            });
+        }
+    }
+    errors.sort_by_key(|e| (e.line, e.column));
+    errors
+}
+
+/// `echo)` and similar print a literal parenthesis; don't flag those lines.
+fn prints_literal_paren(lower_line: &str) -> bool {
+    lower_line.starts_with("echo") || lower_line.starts_with("@echo")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_cmd_syntax() {
+        let cases: [(&str, &[(usize, usize, &str)]); 11] = [
+            (
+                "@echo off\nif exist foo (\n  echo yes\n) else (\n  goto :done\n)\n:done\n",
+                &[],
+            ),
+            (
+                "if 1==1 (\necho hi\n",
+                &[(1, 9, "Parenthesis is never closed")],
+            ),
+            ("dir )", &[(1, 5, "Unmatched closing parenthesis")]),
+            ("echo)", &[]),
+            ("echo \"(\"", &[]),
+            ("set x=^(", &[]),
+            (
+                "goto missing\ncall :sub\n:sub\n",
+                &[(1, 1, "Label :missing is not defined")],
+            ),
+            ("call other.bat\ngoto :eof\ngoto %target%", &[]),
+            ("rem goto nowhere\n:: goto nowhere", &[]),
+            (":Done\ngoto DONE", &[]),
+            (
+                "goto a\n(\ngoto b\n",
+                &[
+                    (1, 1, "Label :a is not defined"),
+                    (2, 1, "Parenthesis is never closed"),
+                    (3, 1, "Label :b is not defined"),
+                ],
+            ),
+        ];
+        for (script, expected) in cases {
+            let errors: Vec<(usize, usize, String)> = check_cmd_syntax(script)
+                .into_iter()
+                .map(|e| (e.line, e.column, e.message))
+                .collect();
+            let expected: Vec<(usize, usize, String)> = expected
+                .iter()
+                .map(|(line, column, message)| (*line, *column, message.to_string()))
+                .collect();
+            assert_eq!(errors, expected, "{:?}", script);
+        }
+    }
+}
@@ -4,13 +4,35 @@
 //! - Compute the `data/settings/app_settings.json` path under the configured data root
 //! - Load user settings as JSON (empty object if the file is missing)
 //! - Save settings as pretty-printed JSON, creating parent directories when needed
+//! - Export/import drive-letter independent settings profiles
+//! - Watch the settings files for external changes and notify the frontend
 use std::{
     fs,
     path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime},
 };
 
+use serde::{Deserialize, Serialize};
+
+use tauri::Emitter;
+
+use crate::fs_utils::write_atomic;
 use crate::{paths, state::AppState};
 
+/// Plan templates (service presets) stored alongside the other settings files.
+const PLAN_TEMPLATES_FILE: &str = "service_presets.json";
+/// Current version of the exported settings profile format.
+const PROFILE_FORMAT_VERSION: u32 = 1;
+
+/// Kind under which the settings watcher is registered in the operation registry.
+const SETTINGS_WATCHER_OP: &str = "settings_watcher";
+/// How often the settings watcher polls the files for changes.
+const SETTINGS_POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Serializes writers of `app_settings.json` so read-modify-write updates don't race.
+static SETTINGS_WRITE_LOCK: Mutex<()> = Mutex::new(());
+
 // Build the full path to the app settings JSON within the `settings` directory.
 fn settings_file_path(data_root: &Path) -> PathBuf {
     let (_reports, _programs, settings, _resources) = paths::subdirs(data_root);
@@ -23,7 +45,14 @@ fn settings_file_path(data_root: &Path) -> PathBuf {
 /// Returns an empty JSON object when the file does not exist. Any parse error
 /// from an existing file is surfaced as a user-facing error string.
 pub fn load_app_settings(state: tauri::State<AppState>) -> Result<serde_json::Value, String> {
-    let path = settings_file_path(state.data_dir.as_path());
+    read_app_settings(&state.data_dir())
+}
+
+/// Read the settings file under `data_root` (empty object when missing).
+///
+/// Shared by commands that need to consult a setting on the backend.
+pub(crate) fn read_app_settings(data_root: &Path) -> Result<serde_json::Value, String> {
+    let path = settings_file_path(data_root);
     match fs::read_to_string(&path) {
         // File exists: attempt to parse the JSON content into a generic Value.
         Ok(text) => serde_json::from_str::<serde_json::Value>(&text)
@@ -36,19 +65,331 @@ pub fn load_app_settings(state: tauri::State<AppState>) -> Result<serde_json::Va
 #[tauri::command]
 /// Save the provided application settings to `data/settings/app_settings.json`.
 ///
+/// Known keys are validated (and lightly coerced, e.g. `"true"` -> `true`) before
+/// writing; unknown keys are passed through untouched for forward compatibility.
 /// Ensures the parent directory exists and writes pretty-printed JSON for readability.
 pub fn save_app_settings(
     state: tauri::State<AppState>,
-    data: serde_json::Value,
+    mut data: serde_json::Value,
 ) -> Result<(), String> {
-    let path = settings_file_path(state.data_dir.as_path());
-    if let Some(parent) = path.parent() {
-        // Ensure the `settings/` directory exists before writing the file.
-        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
-    }
+    validate_settings(&mut data)?;
+    let _guard = SETTINGS_WRITE_LOCK.lock().map_err(|e| e.to_string())?;
+    let path = settings_file_path(&state.data_dir());
     // Store human-readable JSON to simplify manual inspection and diffs.
+    // `write_atomic` creates the `settings/` directory when missing.
     let pretty = serde_json::to_string_pretty(&data).map_err(|e| e.to_string())?;
-    fs::write(&path, pretty).map_err(|e| e.to_string())
+    write_atomic(&path, pretty)
+}
+
+#[tauri::command]
+/// Read a single top-level settings key, returning `null` when it is not set.
+pub fn get_setting(
+    state: tauri::State<AppState>,
+    key: String,
+) -> Result<serde_json::Value, String> {
+    let settings = read_app_settings(&state.data_dir())?;
+    Ok(settings
+        .get(&key)
+        .cloned()
+        .unwrap_or(serde_json::Value::Null))
+}
+
+#[tauri::command]
+/// Set a single top-level settings key, leaving every other key untouched.
+///
+/// The file is read, updated and atomically rewritten under a lock so concurrent
+/// updates of different keys cannot overwrite each other. Passing `null` removes
+/// the key.
+///
+/// # Returns
+/// The stored value (after validation/coercion)
+pub fn set_setting(
+    state: tauri::State<AppState>,
+    key: String,
+    value: serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    if key.trim().is_empty() {
+        return Err("Setting key must not be empty".into());
+    }
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
+    let _guard = SETTINGS_WRITE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut settings = read_app_settings(data_root)?;
+    let map = settings
+        .as_object_mut()
+        .ok_or("Settings file does not contain a JSON object")?;
+    if value.is_null() {
+        map.remove(&key);
+    } else {
+        map.insert(key.clone(), value);
+    }
+    validate_settings(&mut settings)?;
+
+    let pretty = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    write_atomic(&settings_file_path(data_root), pretty)?;
+    Ok(settings
+        .get(&key)
+        .cloned()
+        .unwrap_or(serde_json::Value::Null))
+}
+
+/// How many shares `list_recent_network_paths` remembers.
+const MAX_RECENT_NETWORK_PATHS: usize = 10;
+
+#[tauri::command]
+/// The network share most recently used successfully, for pre-filling the path field.
+pub fn get_last_network_path(state: tauri::State<AppState>) -> Result<Option<String>, String> {
+    let settings = read_app_settings(&state.data_dir())?;
+    Ok(settings
+        .pointer("/network_sharing/last_path")
+        .and_then(|v| v.as_str())
+        .map(str::to_string))
+}
+
+#[tauri::command]
+/// Record `path` as the last-used network share and move it to the front of the recent list.
+///
+/// Also done automatically when `save_report_to_network` or `test_network_path` succeeds.
+///
+/// # Returns
+/// The recent shares, most recent first
+pub fn set_last_network_path(
+    state: tauri::State<AppState>,
+    path: String,
+) -> Result<Vec<String>, String> {
+    remember_network_path(&state.data_dir(), &path)
+}
+
+#[tauri::command]
+/// Recently used network shares, most recent first (at most `MAX_RECENT_NETWORK_PATHS`).
+pub fn list_recent_network_paths(state: tauri::State<AppState>) -> Result<Vec<String>, String> {
+    let settings = read_app_settings(&state.data_dir())?;
+    Ok(recent_network_paths(&settings))
+}
+
+/// Store `path` as `network_sharing.last_path` and at the front of
+/// `network_sharing.recent_paths`, dropping older duplicates (compared case-insensitively,
+/// as UNC paths are).
+pub(crate) fn remember_network_path(data_root: &Path, path: &str) -> Result<Vec<String>, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("Network path must not be empty".into());
+    }
+    let _guard = SETTINGS_WRITE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut settings = read_app_settings(data_root)?;
+    let mut recent = recent_network_paths(&settings);
+    recent.retain(|p| !p.eq_ignore_ascii_case(path));
+    recent.insert(0, path.to_string());
+    recent.truncate(MAX_RECENT_NETWORK_PATHS);
+
+    let map = settings
+        .as_object_mut()
+        .ok_or("Settings file does not contain a JSON object")?;
+    let sharing = map
+        .entry("network_sharing")
+        .or_insert_with(|| serde_json::json!({}));
+    let sharing = sharing
+        .as_object_mut()
+        .ok_or("Setting network_sharing must be an object")?;
+    sharing.insert("last_path".into(), serde_json::json!(path));
+    sharing.insert("recent_paths".into(), serde_json::json!(recent));
+    validate_settings(&mut settings)?;
+
+    let pretty = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    write_atomic(&settings_file_path(data_root), pretty)?;
+    Ok(recent)
+}
+
+/// Store `path` as the `tool_path_overrides` entry for `key`, or remove it when None.
+///
+/// # Returns
+/// Whether an override for `key` existed before
+pub(crate) fn set_tool_path_override(
+    data_root: &Path,
+    key: &str,
+    path: Option<&str>,
+) -> Result<bool, String> {
+    let _guard = SETTINGS_WRITE_LOCK.lock().map_err(|e| e.to_string())?;
+    let mut settings = read_app_settings(data_root)?;
+    let map = settings
+        .as_object_mut()
+        .ok_or("Settings file does not contain a JSON object")?;
+    let overrides = map
+        .entry("tool_path_overrides")
+        .or_insert_with(|| serde_json::json!({}));
+    let overrides = overrides
+        .as_object_mut()
+        .ok_or("Setting tool_path_overrides must be an object")?;
+    let existed = match path {
+        Some(path) => overrides
+            .insert(key.to_string(), serde_json::json!(path))
+            .is_some(),
+        None => overrides.remove(key).is_some(),
+    };
+    validate_settings(&mut settings)?;
+
+    let pretty = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    write_atomic(&settings_file_path(data_root), pretty)?;
+    Ok(existed)
+}
+
+fn recent_network_paths(settings: &serde_json::Value) -> Vec<String> {
+    settings
+        .pointer("/network_sharing/recent_paths")
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|p| p.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Expected shape of a known settings value.
+enum SettingRule {
+    Object,
+    Bool,
+    Text,
+    TextList,
+    Number { min: f64, max: f64 },
+    OneOf(&'static [&'static str]),
+}
+
+/// Known settings keys (as JSON pointers) and their expected shapes.
+const SETTING_RULES: &[(&str, SettingRule)] = &[
+    ("/business", SettingRule::Object),
+    ("/business/technician_mode", SettingRule::Bool),
+    ("/business/name", SettingRule::Text),
+    ("/business/logo", SettingRule::Text),
+    ("/business/technician_names", SettingRule::TextList),
+    ("/reports", SettingRule::Object),
+    ("/reports/auto_save", SettingRule::Bool),
+    ("/reports/notifications_enabled", SettingRule::Bool),
+    ("/reports/sound_enabled", SettingRule::Bool),
+    (
+        "/reports/sound_volume",
+        SettingRule::Number {
+            min: 0.0,
+            max: 100.0,
+        },
+    ),
+    (
+        "/reports/sound_repeat",
+        SettingRule::Number {
+            min: 1.0,
+            max: 10.0,
+        },
+    ),
+    ("/network_sharing", SettingRule::Object),
+    ("/network_sharing/enabled", SettingRule::Bool),
+    ("/network_sharing/unc_path", SettingRule::Text),
+    ("/network_sharing/last_path", SettingRule::Text),
+    ("/network_sharing/recent_paths", SettingRule::TextList),
+    (
+        "/network_sharing/save_mode",
+        SettingRule::OneOf(&["local", "network", "both"]),
+    ),
+    ("/network", SettingRule::Object),
+    ("/network/iperf_server", SettingRule::Text),
+    ("/network/ping_host", SettingRule::Text),
+    ("/network/public_ip_lookup_url", SettingRule::Text),
+    ("/tool_categories", SettingRule::Object),
+    ("/tool_path_overrides", SettingRule::Object),
+    ("/sentry_enabled", SettingRule::Bool),
+    ("/prevent_sleep_during_run", SettingRule::Bool),
+    ("/auto_restart_runner", SettingRule::Bool),
+    ("/system_info", SettingRule::Object),
+    (
+        "/system_info/cache_ttl_seconds",
+        SettingRule::Number {
+            min: 0.0,
+            max: 3600.0,
+        },
+    ),
+];
+
+// Validate known keys in place, coercing obviously-meant values (string booleans,
+// numeric strings, enum casing). Unknown keys are left untouched.
+fn validate_settings(data: &mut serde_json::Value) -> Result<(), String> {
+    use serde_json::Value;
+    if !data.is_object() {
+        return Err("Settings must be a JSON object".into());
+    }
+    for (pointer, rule) in SETTING_RULES {
+        let Some(value) = data.pointer_mut(pointer) else {
+            continue;
+        };
+        if value.is_null() {
+            continue;
+        }
+        let key = pointer.trim_start_matches('/').replace('/', ".");
+        match rule {
+            SettingRule::Object => {
+                if !value.is_object() {
+                    return Err(format!("Setting '{}' must be an object", key));
+                }
+            }
+            SettingRule::Bool => {
+                let coerced = match &*value {
+                    Value::Bool(b) => *b,
+                    Value::String(s) if s.trim().eq_ignore_ascii_case("true") => true,
+                    Value::String(s) if s.trim().eq_ignore_ascii_case("false") => false,
+                    _ => return Err(format!("Setting '{}' must be true or false", key)),
+                };
+                *value = Value::Bool(coerced);
+            }
+            SettingRule::Text => {
+                if let Value::Number(n) = &*value {
+                    *value = Value::String(n.to_string());
+                } else if !value.is_string() {
+                    return Err(format!("Setting '{}' must be a string", key));
+                }
+            }
+            SettingRule::TextList => {
+                let valid = value
+                    .as_array()
+                    .is_some_and(|items| items.iter().all(Value::is_string));
+                if !valid {
+                    return Err(format!("Setting '{}' must be a list of strings", key));
+                }
+            }
+            SettingRule::Number { min, max } => {
+                let number = match &*value {
+                    Value::Number(n) => n.as_f64(),
+                    Value::String(s) => s.trim().parse::<f64>().ok(),
+                    _ => None,
+                };
+                match number {
+                    Some(n) if n >= *min && n <= *max => {
+                        if let Value::String(_) = value {
+                            *value = serde_json::json!(n);
+                        }
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Setting '{}' must be a number between {} and {}",
+                            key, min, max
+                        ))
+                    }
+                }
+            }
+            SettingRule::OneOf(allowed) => {
+                let normalized = value.as_str().map(|s| s.trim().to_ascii_lowercase());
+                match normalized {
+                    Some(s) if allowed.contains(&s.as_str()) => *value = Value::String(s),
+                    _ => {
+                        return Err(format!(
+                            "Setting '{}' must be one of: {}",
+                            key,
+                            allowed.join(", ")
+                        ))
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
 }
 
 #[tauri::command]
@@ -72,18 +413,19 @@ pub fn make_portable_path(
     state: tauri::State<AppState>,
     absolute_path: String,
 ) -> Result<String, String> {
-    let abs = PathBuf::from(&absolute_path);
-    let data_root = state.data_dir.as_path();
-
-    // Try to make the path relative to the data directory
-    if let Ok(rel) = abs.strip_prefix(data_root) {
-        // Convert to forward slashes for consistency and prepend "data/"
-        let rel_str = rel.to_string_lossy().replace('\\', "/");
-        Ok(format!("data/{}", rel_str))
-    } else {
-        // Path is outside data directory - return as-is
-        Ok(absolute_path)
+    // Paths outside the data directory are returned as-is
+    Ok(to_portable(&state.data_dir(), &absolute_path).unwrap_or(absolute_path))
+}
+
+// Convert an absolute path inside `data_root` to its "data/..." form.
+fn to_portable(data_root: &Path, absolute_path: &str) -> Option<String> {
+    let abs = PathBuf::from(absolute_path);
+    if !abs.is_absolute() {
+        return None;
     }
+    // Convert to forward slashes for consistency and prepend "data/"
+    let rel = abs.strip_prefix(data_root).ok()?;
+    Some(format!("data/{}", rel.to_string_lossy().replace('\\', "/")))
 }
 
 #[tauri::command]
@@ -106,15 +448,222 @@ pub fn resolve_portable_path(
     state: tauri::State<AppState>,
     portable_path: String,
 ) -> Result<String, String> {
-    // Check if this is a portable path starting with "data/"
-    if portable_path.starts_with("data/") || portable_path.starts_with("data\\") {
-        let rel_path = portable_path
-            .trim_start_matches("data/")
-            .trim_start_matches("data\\");
-        let abs_path = state.data_dir.join(rel_path);
-        Ok(abs_path.to_string_lossy().to_string())
+    // Not a portable path - return as-is (could be URL or absolute path)
+    Ok(from_portable(&state.data_dir(), &portable_path).unwrap_or(portable_path))
+}
+
+// Resolve a "data/..." path against `data_root`; None for any other string.
+fn from_portable(data_root: &Path, portable_path: &str) -> Option<String> {
+    let rel_path = portable_path
+        .strip_prefix("data/")
+        .or_else(|| portable_path.strip_prefix("data\\"))?;
+    Some(data_root.join(rel_path).to_string_lossy().to_string())
+}
+
+/// A settings bundle that can be moved between USB drives.
+///
+/// Every absolute path inside the data directory is stored in "data/..." form.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    pub format_version: u32,
+    /// RFC 3339 timestamp of when the profile was exported
+    pub exported_at: String,
+    pub app_settings: serde_json::Value,
+    pub programs: serde_json::Value,
+    pub scripts: serde_json::Value,
+    /// Plan templates, when the source machine had any
+    #[serde(default)]
+    pub plan_templates: Option<serde_json::Value>,
+}
+
+#[tauri::command]
+/// Export app settings, programs, scripts and plan templates to a portable profile file.
+///
+/// Absolute paths inside the data directory are rewritten to "data/..." form so the
+/// profile can be imported on a drive with a different letter.
+pub fn export_settings_profile(
+    state: tauri::State<AppState>,
+    output_path: String,
+) -> Result<(), String> {
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
+    let settings_dir = settings_file_path(data_root)
+        .parent()
+        .map(Path::to_path_buf)
+        .ok_or("Invalid settings path")?;
+
+    let portable = |mut value: serde_json::Value| {
+        map_json_strings(&mut value, &|s| to_portable(data_root, s));
+        value
+    };
+    let read_list = |path: PathBuf| -> Result<serde_json::Value, String> {
+        match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| format!("Failed to parse {}: {}", path.display(), e)),
+            Err(_) => Ok(serde_json::json!([])),
+        }
+    };
+
+    let plan_templates_path = settings_dir.join(PLAN_TEMPLATES_FILE);
+    let plan_templates = if plan_templates_path.is_file() {
+        Some(portable(read_list(plan_templates_path)?))
     } else {
-        // Not a portable path - return as-is (could be URL or absolute path)
-        Ok(portable_path)
+        None
+    };
+    let profile = SettingsProfile {
+        format_version: PROFILE_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        app_settings: portable(read_app_settings(data_root)?),
+        programs: portable(read_list(crate::programs::programs_json_path(data_root))?),
+        scripts: portable(read_list(crate::scripts::scripts_json_path(data_root))?),
+        plan_templates,
+    };
+    let pretty = serde_json::to_string_pretty(&profile).map_err(|e| e.to_string())?;
+    write_atomic(Path::new(&output_path), pretty)
+}
+
+#[tauri::command]
+/// Import a profile written by `export_settings_profile`, replacing the current settings.
+///
+/// "data/..." paths in programs, scripts and plan templates are resolved against this
+/// machine's data directory. App settings keep the portable form because the frontend
+/// resolves those with `resolve_portable_path`. Plan templates are only replaced when
+/// the profile contains them.
+pub fn import_settings_profile(state: tauri::State<AppState>, path: String) -> Result<(), String> {
+    let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read profile: {}", e))?;
+    let profile: SettingsProfile =
+        serde_json::from_str(&text).map_err(|e| format!("Invalid settings profile: {}", e))?;
+    if profile.format_version > PROFILE_FORMAT_VERSION {
+        return Err(format!(
+            "Settings profile version {} is newer than supported ({})",
+            profile.format_version, PROFILE_FORMAT_VERSION
+        ));
     }
+    if !profile.programs.is_array() || !profile.scripts.is_array() {
+        return Err("Invalid settings profile: programs and scripts must be lists".into());
+    }
+    let mut app_settings = profile.app_settings;
+    validate_settings(&mut app_settings)?;
+
+    let data_dir = state.data_dir();
+
+    let data_root = data_dir.as_path();
+    let write = |target: PathBuf, mut value: serde_json::Value, resolve: bool| {
+        if resolve {
+            map_json_strings(&mut value, &|s| from_portable(data_root, s));
+        }
+        let pretty = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+        write_atomic(&target, pretty)
+    };
+    let settings_path = settings_file_path(data_root);
+    if let Some(templates) = profile.plan_templates {
+        let dir = settings_path.parent().ok_or("Invalid settings path")?;
+        write(dir.join(PLAN_TEMPLATES_FILE), templates, true)?;
+    }
+    write(
+        crate::programs::programs_json_path(data_root),
+        profile.programs,
+        true,
+    )?;
+    write(
+        crate::scripts::scripts_json_path(data_root),
+        profile.scripts,
+        true,
+    )?;
+    write(settings_path, app_settings, false)
+}
+
+// Replace every string in `value` for which `f` returns a new value.
+fn map_json_strings(value: &mut serde_json::Value, f: &dyn Fn(&str) -> Option<String>) {
+    match value {
+        serde_json::Value::String(s) => {
+            if let Some(mapped) = f(s) {
+                *s = mapped;
+            }
+        }
+        serde_json::Value::Array(items) => {
+            items.iter_mut().for_each(|v| map_json_strings(v, f));
+        }
+        serde_json::Value::Object(map) => {
+            map.values_mut().for_each(|v| map_json_strings(v, f));
+        }
+        _ => {}
+    }
+}
+
+#[tauri::command]
+/// Start watching programs.json, scripts.json and app_settings.json for changes on disk.
+///
+/// Emits a `settings_changed` event (`{ "files": [...] }`) when any of them change.
+/// A change is only reported once the file has been stable for one poll interval, so
+/// in-progress writes (including our own atomic-write temp files) don't fire events.
+///
+/// # Returns
+/// `false` if a watcher was already running
+pub fn start_watching_settings(
+    app: tauri::AppHandle,
+    state: tauri::State<AppState>,
+) -> Result<bool, String> {
+    if state.operations.is_running(SETTINGS_WATCHER_OP) {
+        return Ok(false);
+    }
+    spawn_settings_watcher(app, &state);
+    Ok(true)
+}
+
+/// Point a running settings watcher at the current data directory (after it moved).
+pub(crate) fn restart_settings_watcher(app: &tauri::AppHandle, state: &AppState) {
+    if state.operations.cancel_kind(SETTINGS_WATCHER_OP) > 0 {
+        spawn_settings_watcher(app.clone(), state);
+    }
+}
+
+// Poll the settings files of the current data directory until cancelled.
+fn spawn_settings_watcher(app: tauri::AppHandle, state: &AppState) {
+    let data_dir = state.data_dir();
+    let data_root = data_dir.as_path();
+    let files = [
+        settings_file_path(data_root),
+        crate::programs::programs_json_path(data_root),
+        crate::scripts::scripts_json_path(data_root),
+    ];
+    let op = state.operations.register(SETTINGS_WATCHER_OP);
+    std::thread::spawn(move || {
+        let cancel = op.token();
+        let mut reported: Vec<_> = files.iter().map(|f| file_stamp(f)).collect();
+        let mut previous = reported.clone();
+        while !cancel.is_cancelled() {
+            std::thread::sleep(SETTINGS_POLL_INTERVAL);
+            let current: Vec<_> = files.iter().map(|f| file_stamp(f)).collect();
+            let changed: Vec<String> = files
+                .iter()
+                .enumerate()
+                // Changed since the last event and unchanged since the last poll
+                .filter(|(i, _)| current[*i] != reported[*i] && current[*i] == previous[*i])
+                .filter_map(|(_, f)| f.file_name().map(|n| n.to_string_lossy().to_string()))
+                .collect();
+            if !changed.is_empty() {
+                for (i, stamp) in current.iter().enumerate() {
+                    if *stamp == previous[i] {
+                        reported[i] = *stamp;
+                    }
+                }
+                let _ = app.emit("settings_changed", serde_json::json!({ "files": changed }));
+            }
+            previous = current;
+        }
+    });
+}
+
+#[tauri::command]
+/// Stop the settings watcher started by `start_watching_settings`.
+pub fn stop_watching_settings(state: tauri::State<AppState>) -> Result<(), String> {
+    state.operations.cancel_kind(SETTINGS_WATCHER_OP);
+    Ok(())
+}
+
+// Modification time and size of a file, or None when it does not exist.
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
 }
@@ -5,6 +5,37 @@
 //!
 //! On non-Windows platforms, this command returns an error since shortcuts
 //! are not supported.
+//!
+//! Shortcut macros (named sequences of shortcut ids) are persisted to
+//! `settings/shortcut_macros.json` and launched in order by `run_shortcut_macro`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::models::ShortcutMacro;
+use crate::{paths, state::AppState};
+
+/// Pause between launches so each tool's window opens in a predictable order.
+const MACRO_LAUNCH_DELAY: Duration = Duration::from_millis(750);
+
+/// A shortcut of a macro that failed to launch.
+#[derive(Debug, Serialize)]
+pub struct ShortcutLaunchFailure {
+    pub id: String,
+    pub error: String,
+}
+
+/// Outcome of `run_shortcut_macro`.
+#[derive(Debug, Serialize)]
+pub struct ShortcutMacroResult {
+    /// Ids that were launched, in order
+    pub launched: Vec<String>,
+    /// Ids that failed to launch, with the reason
+    pub failed: Vec<ShortcutLaunchFailure>,
+}
 
 #[tauri::command]
 /// Launches a Windows shortcut by ID.
@@ -158,3 +189,109 @@ pub fn launch_shortcut(id: &str) -> Result<(), String> {
         }
     }
 }
+
+#[tauri::command]
+/// List the saved shortcut macros.
+pub fn list_shortcut_macros(state: tauri::State<AppState>) -> Result<Vec<ShortcutMacro>, String> {
+    Ok(read_macros_file(&macros_json_path(&state.data_dir())))
+}
+
+#[tauri::command]
+/// Create or replace the shortcut macro called `name` (matched case-insensitively).
+///
+/// # Arguments
+/// * `name` - Display name of the macro
+/// * `ids` - Shortcut ids to launch, in order
+pub fn save_shortcut_macro(
+    state: tauri::State<AppState>,
+    name: String,
+    ids: Vec<String>,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Macro name is required".into());
+    }
+    let ids: Vec<String> = ids
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    if ids.is_empty() {
+        return Err("A macro needs at least one shortcut".into());
+    }
+
+    let path = macros_json_path(&state.data_dir());
+    let mut list = read_macros_file(&path);
+    let entry = ShortcutMacro { name, ids };
+    match list
+        .iter_mut()
+        .find(|m| m.name.eq_ignore_ascii_case(&entry.name))
+    {
+        Some(existing) => *existing = entry,
+        None => list.push(entry),
+    }
+    write_macros_file(&path, &list)
+}
+
+#[tauri::command]
+/// Delete the shortcut macro called `name` (matched case-insensitively).
+pub fn remove_shortcut_macro(state: tauri::State<AppState>, name: String) -> Result<(), String> {
+    let path = macros_json_path(&state.data_dir());
+    let mut list = read_macros_file(&path);
+    list.retain(|m| !m.name.eq_ignore_ascii_case(name.trim()));
+    write_macros_file(&path, &list)
+}
+
+#[tauri::command]
+/// Launch every shortcut of the macro called `name` in order, pausing briefly between
+/// launches. A failing shortcut does not stop the rest of the macro.
+///
+/// # Returns
+/// The ids that were launched and those that failed, with the error for each
+pub async fn run_shortcut_macro(
+    state: tauri::State<'_, AppState>,
+    name: String,
+) -> Result<ShortcutMacroResult, String> {
+    let shortcut_macro = read_macros_file(&macros_json_path(&state.data_dir()))
+        .into_iter()
+        .find(|m| m.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| format!("Shortcut macro not found: {}", name))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut result = ShortcutMacroResult {
+            launched: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (i, id) in shortcut_macro.ids.into_iter().enumerate() {
+            if i > 0 {
+                std::thread::sleep(MACRO_LAUNCH_DELAY);
+            }
+            match launch_shortcut(&id) {
+                Ok(()) => result.launched.push(id),
+                Err(error) => result.failed.push(ShortcutLaunchFailure { id, error }),
+            }
+        }
+        result
+    })
+    .await
+    .map_err(|e| format!("Shortcut macro worker failed: {}", e))
+}
+
+// Build the path to the persisted shortcut macros within the settings directory.
+fn macros_json_path(data_root: &Path) -> PathBuf {
+    let (_reports, _programs, settings, _resources) = paths::subdirs(data_root);
+    settings.join("shortcut_macros.json")
+}
+
+// Read saved macros; a missing or unreadable file yields an empty list.
+fn read_macros_file(path: &Path) -> Vec<ShortcutMacro> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+fn write_macros_file(path: &Path, list: &[ShortcutMacro]) -> Result<(), String> {
+    let data = serde_json::to_string_pretty(list).map_err(|e| e.to_string())?;
+    crate::fs_utils::write_atomic(path, data)
+}
@@ -1,6 +1,69 @@
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+
+use crate::models::SystemInfo;
+use crate::operations::OperationRegistry;
 
 #[derive(Clone)]
 pub struct AppState {
-    pub data_dir: Arc<std::path::PathBuf>,
+    /// Root of the portable data directory; replaced at runtime by `migrate_data_dir`
+    data_dir: Arc<RwLock<Arc<PathBuf>>>,
+    /// Background operations currently running, with their cancellation tokens
+    pub operations: Arc<OperationRegistry>,
+    /// The service run currently in progress, if any
+    pub active_run: Arc<Mutex<Option<ActiveRun>>>,
+    /// Last full `get_system_info` result, reused until it is older than the cache TTL
+    pub system_info_cache: Arc<Mutex<Option<CachedSystemInfo>>>,
+}
+
+impl AppState {
+    pub fn new(data_dir: PathBuf) -> Self {
+        AppState {
+            data_dir: Arc::new(RwLock::new(Arc::new(data_dir))),
+            operations: Arc::new(OperationRegistry::default()),
+            active_run: Arc::new(Mutex::new(None)),
+            system_info_cache: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The current data directory.
+    ///
+    /// Returns a snapshot; commands should read it once and use that value throughout.
+    pub fn data_dir(&self) -> Arc<PathBuf> {
+        match self.data_dir.read() {
+            Ok(dir) => Arc::clone(&dir),
+            Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+        }
+    }
+
+    /// Point the application at a different data directory.
+    pub fn set_data_dir(&self, data_dir: PathBuf) {
+        let mut guard = match self.data_dir.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *guard = Arc::new(data_dir);
+    }
+}
+
+/// Files belonging to the service run started by `start_service_run`.
+#[derive(Clone, Debug)]
+pub struct ActiveRun {
+    pub plan_file: PathBuf,
+    pub log_file: PathBuf,
+    /// Touched periodically by the runner while it is alive (see `get_runner_heartbeat`)
+    pub heartbeat_file: PathBuf,
+    /// Process id of the current runner instance, set once it has been spawned
+    pub pid: Option<u32>,
+    /// Set by `recover_active_run` when stopping the run, so the runner isn't restarted
+    pub stop_requested: Arc<AtomicBool>,
+}
+
+/// A cached system snapshot (temperatures in Celsius) and when it was collected.
+#[derive(Clone, Debug)]
+pub struct CachedSystemInfo {
+    pub collected_at: Instant,
+    pub info: SystemInfo,
 }
@@ -4,38 +4,501 @@
 //! networks, sensors, users) and augments on Windows with additional details
 //! collected via PowerShell/WMI. GPU information is sourced from `wgpu` when
 //! available. Results are aggregated into the `SystemInfo` model for the UI.
-use sysinfo::{Components, Cpu, Disks, Networks, System, Users};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use sysinfo::{Components, Cpu, CpuRefreshKind, Disks, Networks, System, Users};
+use tauri::Emitter;
 
 use crate::models::{
-    BatteryInfo, CpuCoreInfo, CpuInfo, DiskInfo, ExtraInfo, GpuInfo, LoadAvgInfo, MemoryInfo,
-    MotherboardInfo, NetworkInfo, ProductInfo, SensorInfo, SystemInfo,
+    BatteryInfo, BitLockerVolume, CpuCoreInfo, CpuInfo, DiskDelta, DiskInfo, DiskIoRate, ExtraInfo,
+    GpuInfo, GpuReport, LoadAvgInfo, MachineFingerprint, MemoryInfo, MotherboardInfo, NetworkInfo,
+    NvidiaGpuStats, ProductInfo, PublicNetworkInfo, ReliabilityEvent, ReliabilityHistory,
+    SensorInfo, StabilityPoint, SystemInfo, SystemInfoDiff, SystemSnapshot, TemperatureDelta,
 };
+use crate::state::{AppState, CachedSystemInfo};
+
+/// Operation kind of the background monitor started by `start_system_monitor`.
+const SYSTEM_MONITOR_OP: &str = "system_monitor";
+const MONITOR_MIN_INTERVAL_MS: u64 = 250;
+const MONITOR_MAX_INTERVAL_MS: u64 = 60_000;
+/// Bounds of the interval accepted by `sample_disk_io`.
+const DISK_IO_MIN_INTERVAL_MS: u64 = 100;
+const DISK_IO_MAX_INTERVAL_MS: u64 = 60_000;
+
+// `System` instance shared by all callers so CPU usage can be computed from the
+// previous refresh instead of sleeping on every call.
+struct CachedSystem {
+    sys: System,
+    refreshed_at: Instant,
+}
+
+static CACHED_SYSTEM: Mutex<Option<CachedSystem>> = Mutex::new(None);
+
+// Parts of `SystemInfo` that never change during a session; collected once because
+// GPU enumeration and the board/product queries are comparatively slow.
+#[derive(Clone)]
+struct StaticInfo {
+    os: Option<String>,
+    hostname: Option<String>,
+    kernel_version: Option<String>,
+    os_version: Option<String>,
+    system_name: Option<String>,
+    boot_time_seconds: u64,
+    cpu_brand: String,
+    cpu_vendor_id: Option<String>,
+    num_physical_cores: Option<usize>,
+    num_logical_cpus: usize,
+    gpus: Vec<GpuInfo>,
+    motherboard: Option<MotherboardInfo>,
+    product: Option<ProductInfo>,
+}
+
+static STATIC_INFO: Mutex<Option<StaticInfo>> = Mutex::new(None);
+
+/// Maximum number of reliability records returned by `get_reliability_history`.
+#[cfg(target_os = "windows")]
+const MAX_RELIABILITY_EVENTS: usize = 200;
 
 #[tauri::command]
 /// Collect a comprehensive snapshot of the current system.
 ///
 /// Cross‑platform via `sysinfo` with optional Windows‑specific enrichment (BIOS, TPM,
-/// hotfixes, etc.) collected concurrently. CPU usage sampling includes a short delay to
-/// provide meaningful utilization values.
-pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String> {
-    let mut sys = System::new_all();
-    sys.refresh_all();
-
-    // CPU usage requires two samples. Take an initial refresh, wait the minimum interval,
-    // then refresh usage to compute deltas.
-    sys.refresh_cpu_all();
-    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    sys.refresh_cpu_usage();
+/// hotfixes, etc.) collected concurrently. Static details (OS, CPU model, core counts,
+/// GPUs, motherboard, product) are gathered once per session and reused; only volatile
+/// fields (usage, temperatures, memory, disks, network counters) are refreshed per call.
+/// The first call includes a short delay so CPU usage values are meaningful.
+/// Disk SMART health is filled in on Windows when smartctl is among the saved tools.
+///
+/// The full result is cached in `AppState` and returned as-is while it is younger than
+/// the `system_info.cache_ttl_seconds` setting (default 30 seconds), so dashboards that
+/// poll don't rerun the PowerShell/WMI queries every time.
+///
+/// # Arguments
+/// * `refresh_static` - Recompute the cached static details as well (implies `force`)
+/// * `unit` - Temperature unit for sensor, CPU and battery readings: "C" (default) or "F";
+///   the chosen unit is echoed in `SystemInfo.temperature_unit`
+/// * `force` - Ignore the cached snapshot and collect a fresh one
+pub async fn get_system_info(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    refresh_static: Option<bool>,
+    unit: Option<String>,
+    force: Option<bool>,
+) -> Result<SystemInfo, String> {
+    let fahrenheit = wants_fahrenheit(unit.as_deref())?;
+    let refresh_static = refresh_static.unwrap_or(false);
+    let ttl = system_info_cache_ttl(&state.data_dir());
+
+    let cached = state
+        .system_info_cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .filter(|c| !refresh_static && !force.unwrap_or(false) && c.collected_at.elapsed() < ttl)
+        .map(|c| c.info.clone());
+    let mut info = match cached {
+        Some(info) => info,
+        None => {
+            let info = collect_system_info(&app, &state, refresh_static).await?;
+            *state
+                .system_info_cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = Some(CachedSystemInfo {
+                collected_at: Instant::now(),
+                info: info.clone(),
+            });
+            info
+        }
+    };
+
+    if fahrenheit {
+        convert_to_fahrenheit(&mut info);
+    }
+    Ok(info)
+}
+
+#[tauri::command]
+/// Collect only the quick parts of the system snapshot.
+///
+/// Everything `get_system_info` gathers through `sysinfo`, `wgpu` and the battery API,
+/// without any PowerShell/WMI or smartctl calls, so `extra` is None and disks carry no
+/// SMART data. Pair with `get_system_extra` to show these numbers immediately and fill
+/// in the slow details later. Not cached.
+///
+/// # Arguments
+/// * `refresh_static` - Recompute the cached static details as well
+/// * `unit` - Temperature unit: "C" (default) or "F"
+pub async fn get_system_info_fast(
+    refresh_static: Option<bool>,
+    unit: Option<String>,
+) -> Result<SystemInfo, String> {
+    let fahrenheit = wants_fahrenheit(unit.as_deref())?;
+    let mut info = collect_base_info(refresh_static.unwrap_or(false));
+    if fahrenheit {
+        convert_to_fahrenheit(&mut info);
+    }
+    Ok(info)
+}
+
+#[tauri::command]
+/// Collect just the slow Windows-specific details (`SystemInfo.extra`): BIOS, TPM,
+/// hotfixes and the rest of the PowerShell/WMI queries. Returns None on other platforms.
+pub async fn get_system_extra(app: tauri::AppHandle) -> Result<Option<ExtraInfo>, String> {
+    #[cfg(target_os = "windows")]
+    {
+        Ok(collect_windows_extra_async(&app).await)
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = app;
+        Ok(None)
+    }
+}
+
+// Parse the `unit` argument of the system info commands: true for Fahrenheit.
+fn wants_fahrenheit(unit: Option<&str>) -> Result<bool, String> {
+    match unit.map(str::to_ascii_uppercase).as_deref() {
+        None | Some("C") | Some("CELSIUS") => Ok(false),
+        Some("F") | Some("FAHRENHEIT") => Ok(true),
+        Some(_) => Err(format!(
+            "Unknown temperature unit: {}",
+            unit.unwrap_or_default()
+        )),
+    }
+}
+
+/// Default lifetime of the cached `get_system_info` result.
+const SYSTEM_INFO_CACHE_TTL_SECS: u64 = 30;
+
+// How long a cached snapshot stays fresh, from the `system_info.cache_ttl_seconds` setting.
+fn system_info_cache_ttl(data_root: &std::path::Path) -> Duration {
+    let secs = crate::settings::read_app_settings(data_root)
+        .ok()
+        .and_then(|v| v.pointer("/system_info/cache_ttl_seconds")?.as_u64())
+        .unwrap_or(SYSTEM_INFO_CACHE_TTL_SECS);
+    Duration::from_secs(secs)
+}
+
+// Collect a full snapshot: the `sysinfo` basics plus SMART health and the Windows extras.
+#[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+async fn collect_system_info(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    refresh_static: bool,
+) -> Result<SystemInfo, String> {
+    let mut info = collect_base_info(refresh_static);
+
+    // Kick off (possibly slow) Windows-specific collection.
+    #[cfg(target_os = "windows")]
+    let extra_fut = collect_windows_extra_async(app);
+    #[cfg(not(target_os = "windows"))]
+    let extra_fut = async { None };
+
+    #[cfg(target_os = "windows")]
+    if let Some(smartctl) = crate::programs::find_known_tool(&state.data_dir(), "smartctl") {
+        let disks = std::mem::take(&mut info.disks);
+        info.disks = tauri::async_runtime::spawn_blocking(move || {
+            let mut disks = disks;
+            for disk in &mut disks {
+                read_smart_health(&smartctl, disk);
+            }
+            disks
+        })
+        .await
+        .map_err(|e| format!("SMART query failed: {}", e))?;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let (extra, network_config) = tokio::join!(extra_fut, query_network_config(app));
+        apply_network_config(&mut info.networks, &network_config);
+        if let Some(extra) = &extra {
+            apply_gpu_vram(&mut info.gpus, &extra.video_ctrl_ex);
+        }
+        info.extra = extra;
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        info.extra = extra_fut.await;
+    }
+
+    if info.gpus.iter().any(|g| g.vendor == Some(NVIDIA_VENDOR_ID)) {
+        let stats = tauri::async_runtime::spawn_blocking(query_nvidia_smi)
+            .await
+            .map_err(|e| format!("nvidia-smi query failed: {}", e))?;
+        apply_nvidia_stats(&mut info.gpus, &stats);
+    }
+    Ok(info)
+}
+
+// Fill `vram_bytes` from the matching `Win32_VideoController` rows.
+#[cfg(target_os = "windows")]
+fn apply_gpu_vram(gpus: &mut [GpuInfo], video_ctrl_ex: &[serde_json::Value]) {
+    let controllers = parse_video_controllers(video_ctrl_ex);
+    for gpu in gpus.iter_mut() {
+        gpu.vram_bytes = controllers
+            .iter()
+            .filter(|c| gpu_names_match(&c.name, &gpu.name))
+            .find_map(|c| c.vram_bytes)
+            .filter(|&bytes| bytes > 0);
+    }
+}
+
+// Fill live utilization for NVIDIA GPUs. `AdapterRAM` is a 32-bit value, so the
+// nvidia-smi total replaces it when larger.
+fn apply_nvidia_stats(gpus: &mut [GpuInfo], stats: &[(String, NvidiaGpuStats)]) {
+    for gpu in gpus
+        .iter_mut()
+        .filter(|g| g.vendor == Some(NVIDIA_VENDOR_ID))
+    {
+        let Some((_, stats)) = stats
+            .iter()
+            .find(|(name, _)| gpu_names_match(name, &gpu.name))
+        else {
+            continue;
+        };
+        gpu.utilization_percent = stats.utilization_percent;
+        if let Some(total) = stats.memory_total_mb.map(|mb| mb * 1024 * 1024) {
+            if gpu.vram_bytes.is_none_or(|bytes| total > bytes) {
+                gpu.vram_bytes = Some(total);
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+// Per-interface DNS servers (Get-DnsClientServerAddress) and default gateway (the
+// lowest-metric default route from Get-NetRoute), as `{alias, gateway, dns}` objects.
+async fn query_network_config(app: &tauri::AppHandle) -> Vec<serde_json::Value> {
+    use tauri_plugin_shell::ShellExt;
+    let script = "$routes = Get-NetRoute -DestinationPrefix '0.0.0.0/0','::/0' -ErrorAction SilentlyContinue | Sort-Object RouteMetric; \
+        Get-DnsClientServerAddress | Group-Object InterfaceAlias | ForEach-Object { $alias = $_.Name; \
+        [pscustomobject]@{ alias = $alias; \
+        gateway = ($routes | Where-Object InterfaceAlias -eq $alias | Select-Object -First 1).NextHop; \
+        dns = @($_.Group | ForEach-Object { $_.ServerAddresses }) } } | ConvertTo-Json -Compress";
+    parse_json_array(run_pwsh(&app.shell(), script).await)
+}
+
+#[cfg(target_os = "windows")]
+// Attach gateway/DNS entries to the `sysinfo` interfaces, matched by interface alias.
+fn apply_network_config(networks: &mut [NetworkInfo], config: &[serde_json::Value]) {
+    for network in networks {
+        let Some(entry) = config.iter().find(|c| {
+            c.get("alias")
+                .and_then(|a| a.as_str())
+                .is_some_and(|a| a.eq_ignore_ascii_case(&network.interface))
+        }) else {
+            continue;
+        };
+        network.default_gateway = entry
+            .get("gateway")
+            .and_then(|g| g.as_str())
+            .filter(|g| !g.is_empty())
+            .map(str::to_string);
+        network.dns_servers = match entry.get("dns") {
+            Some(serde_json::Value::Array(list)) => list
+                .iter()
+                .filter_map(|d| d.as_str())
+                .map(str::to_string)
+                .collect(),
+            Some(serde_json::Value::String(single)) => vec![single.clone()],
+            _ => Vec::new(),
+        };
+    }
+}
+
+// Fill in `smart_health`/`smart_attributes` for a volume by running smartctl against its
+// drive letter, which smartctl resolves to the physical disk holding that volume.
+#[cfg(target_os = "windows")]
+fn read_smart_health(smartctl: &std::path::Path, disk: &mut DiskInfo) {
+    let drive = disk.mount_point.trim_end_matches('\\');
+    if drive.len() != 2 || !drive.ends_with(':') {
+        return;
+    }
+    let Ok(output) = std::process::Command::new(smartctl)
+        .args(["-H", "-A", "-j", drive])
+        .output()
+    else {
+        return;
+    };
+    // smartctl uses non-zero exit bits for disk warnings, so parse the JSON regardless.
+    let Ok(json) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return;
+    };
+    disk.smart_health = json
+        .pointer("/smart_status/passed")
+        .and_then(|v| v.as_bool())
+        .map(|passed| if passed { "PASSED" } else { "FAILED" }.to_string());
+    disk.smart_attributes = json
+        .pointer("/ata_smart_attributes/table")
+        .or_else(|| json.get("nvme_smart_health_information_log"))
+        .cloned();
+}
+
+#[tauri::command]
+/// Start emitting `system_info_update` events (payload: `SystemInfo`) every `interval_ms`.
+///
+/// Samples on a background thread using the shared `System` instance, so no per-sample
+/// CPU warm-up delay is needed. `include_extra` adds the (slow) Windows-specific details
+/// to every sample. Calling this while a monitor is running restarts it with the new
+/// settings.
+///
+/// # Arguments
+/// * `interval_ms` - Time between samples (250 ms – 60 s)
+/// * `include_extra` - Include `SystemInfo.extra` in each update
+pub fn start_system_monitor(
+    app: tauri::AppHandle,
+    state: tauri::State<AppState>,
+    interval_ms: u64,
+    include_extra: bool,
+) -> Result<(), String> {
+    if !(MONITOR_MIN_INTERVAL_MS..=MONITOR_MAX_INTERVAL_MS).contains(&interval_ms) {
+        return Err(format!(
+            "Interval must be between {} and {} ms",
+            MONITOR_MIN_INTERVAL_MS, MONITOR_MAX_INTERVAL_MS
+        ));
+    }
+    state.operations.cancel_kind(SYSTEM_MONITOR_OP);
+    let op = state.operations.register(SYSTEM_MONITOR_OP);
+    std::thread::spawn(move || {
+        let cancel = op.token();
+        let interval = Duration::from_millis(interval_ms);
+        while !cancel.is_cancelled() {
+            let started = Instant::now();
+            let mut info = collect_base_info(false);
+            if include_extra {
+                info.extra = tauri::async_runtime::block_on(collect_windows_extra_async(&app));
+            }
+            if cancel.is_cancelled() {
+                break;
+            }
+            let _ = app.emit("system_info_update", &info);
+
+            // Sleep in short steps so stopping the monitor takes effect promptly.
+            while !cancel.is_cancelled() && started.elapsed() < interval {
+                let remaining = interval.saturating_sub(started.elapsed());
+                std::thread::sleep(remaining.min(Duration::from_millis(100)));
+            }
+        }
+    });
+    Ok(())
+}
+
+/// File under `data/settings` holding the samples recorded by `record_system_snapshot`.
+const SYSTEM_HISTORY_FILE: &str = "system_history.json";
+/// How many samples the history keeps; older ones are dropped first.
+const MAX_SYSTEM_HISTORY_ENTRIES: usize = 500;
+
+/// Serializes read-modify-write updates of `system_history.json`.
+static SYSTEM_HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+fn system_history_path(data_root: &std::path::Path) -> std::path::PathBuf {
+    let (_reports, _programs, settings, _resources) = crate::paths::subdirs(data_root);
+    settings.join(SYSTEM_HISTORY_FILE)
+}
+
+#[tauri::command]
+/// Sample CPU, memory and temperature and append it to `data/settings/system_history.json`.
+///
+/// Only the newest `MAX_SYSTEM_HISTORY_ENTRIES` samples are kept, so calling this
+/// periodically during a session builds a bounded trend for sparklines.
+///
+/// # Returns
+/// The recorded sample
+pub async fn record_system_snapshot(
+    state: tauri::State<'_, AppState>,
+) -> Result<SystemSnapshot, String> {
+    let path = system_history_path(&state.data_dir());
+    tauri::async_runtime::spawn_blocking(move || {
+        let snapshot = take_system_snapshot();
+        let _guard = SYSTEM_HISTORY_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let mut history = read_system_history(&path)?;
+        history.push(snapshot.clone());
+        let excess = history.len().saturating_sub(MAX_SYSTEM_HISTORY_ENTRIES);
+        history.drain(..excess);
+        let text = serde_json::to_string(&history).map_err(|e| e.to_string())?;
+        crate::fs_utils::write_atomic(&path, text)?;
+        Ok(snapshot)
+    })
+    .await
+    .map_err(|e| format!("Recording system snapshot failed: {}", e))?
+}
+
+#[tauri::command]
+/// The samples recorded by `record_system_snapshot`, oldest first.
+pub fn load_system_history(state: tauri::State<AppState>) -> Result<Vec<SystemSnapshot>, String> {
+    read_system_history(&system_history_path(&state.data_dir()))
+}
+
+// Read the history file; a missing file is an empty history.
+fn read_system_history(path: &std::path::Path) -> Result<Vec<SystemSnapshot>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .map_err(|e| format!("Failed to parse system history: {}", e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Failed to read system history: {}", e)),
+    }
+}
+
+fn take_system_snapshot() -> SystemSnapshot {
+    let statics = static_info(false);
+    let (cpu, memory) = sample_cpu_memory(&statics);
+    let cpu_usage_percent = if cpu.cores.is_empty() {
+        0.0
+    } else {
+        cpu.cores.iter().map(|c| c.usage_percent).sum::<f32>() / cpu.cores.len() as f32
+    };
+    let max_temperature_c = Components::new_with_refreshed_list()
+        .iter()
+        .filter_map(|c| c.temperature())
+        .filter(|t| t.is_finite() && *t > 0.0)
+        .reduce(f32::max);
+    SystemSnapshot {
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        cpu_usage_percent,
+        memory_used: memory.used,
+        memory_total: memory.total,
+        max_temperature_c,
+    }
+}
+
+#[tauri::command]
+/// Stop the monitor started by `start_system_monitor`.
+pub fn stop_system_monitor(state: tauri::State<AppState>) -> Result<(), String> {
+    state.operations.cancel_kind(SYSTEM_MONITOR_OP);
+    Ok(())
+}
+
+// Refresh CPU and memory stats on the shared `System` instance. CPU usage needs two
+// samples at least `MINIMUM_CPU_UPDATE_INTERVAL` apart; only the first call (or calls
+// in quick succession) has to wait for that.
+fn sample_cpu_memory(statics: &StaticInfo) -> (CpuInfo, MemoryInfo) {
+    let mut guard = CACHED_SYSTEM.lock().unwrap_or_else(|e| e.into_inner());
+    let cached = guard.get_or_insert_with(|| {
+        let mut sys = System::new();
+        sys.refresh_cpu_all();
+        CachedSystem {
+            sys,
+            refreshed_at: Instant::now(),
+        }
+    });
+    let elapsed = cached.refreshed_at.elapsed();
+    if elapsed < sysinfo::MINIMUM_CPU_UPDATE_INTERVAL {
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL - elapsed);
+    }
+    cached.sys.refresh_cpu_all();
+    cached.sys.refresh_memory();
+    cached.refreshed_at = Instant::now();
+    let sys = &cached.sys;
+
     let cpus: &[Cpu] = sys.cpus();
-    let brand = cpus
-        .first()
-        .map(|c| c.brand().to_string())
-        .unwrap_or_default();
-    let vendor_id = cpus.first().map(|c| c.vendor_id().to_string());
     let frequency_mhz = cpus.first().map(|c| c.frequency() as u64).unwrap_or(0);
-    let num_logical = cpus.len();
-    // Physical cores may be unknown on some platforms; `sysinfo` returns Option.
-    let num_physical = System::physical_core_count();
     let cores: Vec<CpuCoreInfo> = cpus
         .iter()
         .map(|c| CpuCoreInfo {
@@ -44,13 +507,14 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
             usage_percent: c.cpu_usage(),
         })
         .collect();
-    let cpu = CpuInfo {
-        brand,
-        vendor_id,
+    let mut cpu = CpuInfo {
+        brand: statics.cpu_brand.clone(),
+        vendor_id: statics.cpu_vendor_id.clone(),
         frequency_mhz,
-        num_physical_cores: num_physical,
-        num_logical_cpus: num_logical,
+        num_physical_cores: statics.num_physical_cores,
+        num_logical_cpus: statics.num_logical_cpus,
         cores,
+        cpu_temperature_c: None,
     };
 
     // Memory statistics are reported in bytes by `sysinfo`.
@@ -68,6 +532,68 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
         swap_total,
         swap_used,
     };
+    (cpu, memory)
+}
+
+#[tauri::command]
+/// Measure read/write throughput of every disk over `interval_ms`.
+///
+/// Takes two snapshots of the cumulative disk counters, `interval_ms` apart, on a worker
+/// thread and returns the per-second rates. Disks that appear or disappear during the
+/// interval are left out.
+///
+/// # Arguments
+/// * `interval_ms` - Sample length (100 ms – 60 s)
+pub async fn sample_disk_io(interval_ms: u64) -> Result<Vec<DiskIoRate>, String> {
+    if !(DISK_IO_MIN_INTERVAL_MS..=DISK_IO_MAX_INTERVAL_MS).contains(&interval_ms) {
+        return Err(format!(
+            "Interval must be between {} and {} ms",
+            DISK_IO_MIN_INTERVAL_MS, DISK_IO_MAX_INTERVAL_MS
+        ));
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let counters = || -> Vec<(String, String, u64, u64)> {
+            Disks::new_with_refreshed_list()
+                .iter()
+                .map(|d| {
+                    let usage = d.usage();
+                    (
+                        d.name().to_string_lossy().to_string(),
+                        d.mount_point().to_string_lossy().to_string(),
+                        usage.total_read_bytes,
+                        usage.total_written_bytes,
+                    )
+                })
+                .collect()
+        };
+        let before = counters();
+        let started = Instant::now();
+        std::thread::sleep(Duration::from_millis(interval_ms));
+        let after = counters();
+        let seconds = started.elapsed().as_secs_f64().max(f64::EPSILON);
+
+        after
+            .into_iter()
+            .filter_map(|(name, mount_point, read, written)| {
+                let (_, _, read_before, written_before) =
+                    before.iter().find(|(_, m, _, _)| *m == mount_point)?;
+                Some(DiskIoRate {
+                    name,
+                    read_bytes_per_sec: read.saturating_sub(*read_before) as f64 / seconds,
+                    written_bytes_per_sec: written.saturating_sub(*written_before) as f64 / seconds,
+                    mount_point,
+                })
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Disk I/O sampling failed: {}", e))
+}
+
+// Everything in `SystemInfo` except the Windows-only `extra` details.
+fn collect_base_info(refresh_static: bool) -> SystemInfo {
+    let statics = static_info(refresh_static);
+    let (cpu, memory) = sample_cpu_memory(&statics);
 
     // ----- Disks -----
     let disks_list = Disks::new_with_refreshed_list();
@@ -84,6 +610,8 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
             kind: format!("{:?}", d.kind()),
             read_bytes: d.usage().read_bytes,
             written_bytes: d.usage().written_bytes,
+            smart_health: None,
+            smart_attributes: None,
         })
         .collect();
 
@@ -102,6 +630,8 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
             total_transmitted: data.total_transmitted(),
             errors_rx: data.errors_on_received(),
             errors_tx: data.errors_on_transmitted(),
+            default_gateway: None,
+            dns_servers: Vec::new(),
         })
         .collect();
 
@@ -115,6 +645,104 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
             temperature_c: c.temperature().unwrap_or(0.0),
         })
         .collect();
+    cpu.cpu_temperature_c = cpu_temperature(&sensors);
+
+    // ----- Users -----
+    let users_list = Users::new_with_refreshed_list();
+    let users: Vec<String> = users_list.iter().map(|u| u.name().to_string()).collect();
+
+    // ----- Batteries -----
+    // Battery support varies by platform/drivers; return an empty list on failure.
+    let batteries = match get_batteries_info() {
+        Ok(list) => list,
+        Err(_) => Vec::new(),
+    };
+
+    let la = System::load_average();
+
+    // ----- Final aggregation -----
+    SystemInfo {
+        os: statics.os,
+        hostname: statics.hostname,
+        kernel_version: statics.kernel_version,
+        os_version: statics.os_version,
+        system_name: statics.system_name,
+        uptime_seconds: System::uptime(),
+        boot_time_seconds: statics.boot_time_seconds,
+        users,
+        cpu,
+        memory,
+        disks,
+        networks,
+        gpus: statics.gpus,
+        sensors,
+        batteries,
+        motherboard: statics.motherboard,
+        product: statics.product,
+        load_avg: LoadAvgInfo {
+            one: la.one,
+            five: la.five,
+            fifteen: la.fifteen,
+        },
+        extra: None,
+        temperature_unit: "C".to_string(),
+    }
+}
+
+/// Sensor label fragments that identify the CPU temperature, most specific first.
+///
+/// Matched case-insensitively against `Components` labels; the first fragment that any
+/// sensor matches wins. Covers Intel package sensors ("CPU Package", "coretemp Package
+/// id 0"), AMD k10temp ("Tctl", "Tdie", "k10temp Tccd1"), and generic fallbacks such as
+/// ACPI "CPU" zones. Machines whose CPU sensor isn't recognised report None.
+const CPU_SENSOR_LABELS: &[&str] = &[
+    "cpu package",
+    "package id",
+    "tctl",
+    "tdie",
+    "k10temp",
+    "coretemp",
+    "cpu",
+];
+
+// Pick the CPU temperature out of the sensor list (see `CPU_SENSOR_LABELS`). Sensors
+// without a reading (reported as 0.0) are ignored.
+fn cpu_temperature(sensors: &[SensorInfo]) -> Option<f32> {
+    CPU_SENSOR_LABELS.iter().find_map(|pattern| {
+        sensors
+            .iter()
+            .find(|s| s.temperature_c > 0.0 && s.label.to_lowercase().contains(pattern))
+            .map(|s| s.temperature_c)
+    })
+}
+
+// Convert the Celsius temperatures in a freshly collected snapshot to Fahrenheit.
+fn convert_to_fahrenheit(info: &mut SystemInfo) {
+    let to_f = |c: f32| c * 9.0 / 5.0 + 32.0;
+    for sensor in &mut info.sensors {
+        sensor.temperature_c = to_f(sensor.temperature_c);
+    }
+    info.cpu.cpu_temperature_c = info.cpu.cpu_temperature_c.map(to_f);
+    for battery in &mut info.batteries {
+        battery.temperature_c = battery.temperature_c.map(to_f);
+    }
+    info.temperature_unit = "F".to_string();
+}
+
+// Cached static details, collecting them first when missing or when `refresh` is set.
+fn static_info(refresh: bool) -> StaticInfo {
+    let mut guard = STATIC_INFO.lock().unwrap_or_else(|e| e.into_inner());
+    if refresh {
+        *guard = None;
+    }
+    guard.get_or_insert_with(collect_static_info).clone()
+}
+
+// Gather the parts of `SystemInfo` that do not change while the app is running.
+fn collect_static_info() -> StaticInfo {
+    let mut sys = System::new();
+    sys.refresh_cpu_list(CpuRefreshKind::nothing());
+    let cpus: &[Cpu] = sys.cpus();
 
     // ----- GPUs (via wgpu) -----
     let gpus: Vec<GpuInfo> = {
@@ -135,6 +763,8 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
                     driver: Some(info.driver),
                     driver_info: Some(info.driver_info),
                     backend: Some(format!("{:?}", info.backend)),
+                    vram_bytes: None,
+                    utilization_percent: None,
                 });
             }
         }
@@ -236,17 +866,6 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
         out
     };
 
-    // ----- Users -----
-    let users_list = Users::new_with_refreshed_list();
-    let users: Vec<String> = users_list.iter().map(|u| u.name().to_string()).collect();
-
-    // ----- Batteries -----
-    // Battery support varies by platform/drivers; return an empty list on failure.
-    let batteries = match get_batteries_info() {
-        Ok(list) => list,
-        Err(_) => Vec::new(),
-    };
-
     // ----- Motherboard and Product identifiers -----
     let motherboard = sysinfo::Motherboard::new().map(|m| MotherboardInfo {
         vendor: m.vendor_name(),
@@ -265,43 +884,782 @@ pub async fn get_system_info(app: tauri::AppHandle) -> Result<SystemInfo, String
         uuid: sysinfo::Product::uuid(),
     });
 
-    let la = System::load_average();
-    // Kick off (possibly slow) Windows-specific collection concurrently while we finish base stats.
-    #[cfg(target_os = "windows")]
-    let extra_fut = collect_windows_extra_async(&app);
-    #[cfg(not(target_os = "windows"))]
-    let extra_fut = async { None };
-
-    let extra: Option<ExtraInfo> = extra_fut.await;
-
-    // ----- Final aggregation -----
-    let info = SystemInfo {
-        os: sysinfo::System::long_os_version(),
+    StaticInfo {
+        os: System::long_os_version(),
         hostname: System::host_name(),
         kernel_version: System::kernel_version(),
         os_version: System::os_version(),
         system_name: System::name(),
-        uptime_seconds: System::uptime(),
         boot_time_seconds: System::boot_time(),
-        users,
-        cpu,
-        memory,
-        disks,
-        networks,
+        cpu_brand: cpus
+            .first()
+            .map(|c| c.brand().to_string())
+            .unwrap_or_default(),
+        cpu_vendor_id: cpus.first().map(|c| c.vendor_id().to_string()),
+        // Physical cores may be unknown on some platforms; `sysinfo` returns Option.
+        num_physical_cores: System::physical_core_count(),
+        num_logical_cpus: cpus.len(),
         gpus,
-        sensors,
-        batteries,
         motherboard,
         product,
-        load_avg: LoadAvgInfo {
-            one: la.one,
-            five: la.five,
-            fifteen: la.fifteen,
-        },
-        extra,
+    }
+}
+
+#[tauri::command]
+/// Build a stable identifier for this machine from its hardware identifiers.
+///
+/// Combines the motherboard serial, product UUID, system disk serial and CPU ID into a
+/// SHA-256 hash so the machine is recognised across visits, even after an OS reinstall.
+/// Missing or placeholder values ("To Be Filled By O.E.M.", all-zero UUIDs, ...) are left
+/// out of the hash; `components_used` lists the ones that went in. The disk serial and
+/// CPU ID are only available on Windows.
+pub async fn get_machine_fingerprint(app: tauri::AppHandle) -> Result<MachineFingerprint, String> {
+    let statics = static_info(false);
+    let motherboard_serial = statics
+        .motherboard
+        .and_then(|m| m.serial_number)
+        .and_then(clean_identifier);
+    let product_uuid = statics
+        .product
+        .and_then(|p| p.uuid)
+        .and_then(clean_identifier);
+    let (disk_serial, cpu_id) = collect_hardware_ids(&app).await;
+
+    let components = [
+        ("motherboard_serial", &motherboard_serial),
+        ("product_uuid", &product_uuid),
+        ("disk_serial", &disk_serial),
+        ("cpu_id", &cpu_id),
+    ];
+    let components_used: Vec<String> = components
+        .iter()
+        .filter(|(_, value)| value.is_some())
+        .map(|(name, _)| name.to_string())
+        .collect();
+    if components_used.is_empty() {
+        return Err("No hardware identifiers available on this machine".into());
+    }
+
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    for (name, value) in components {
+        if let Some(value) = value {
+            // Label each component so values cannot shift between slots.
+            hasher.update(name.as_bytes());
+            hasher.update([0u8]);
+            hasher.update(value.to_uppercase().as_bytes());
+            hasher.update([0u8]);
+        }
+    }
+
+    Ok(MachineFingerprint {
+        fingerprint: format!("{:x}", hasher.finalize()),
+        motherboard_serial,
+        product_uuid,
+        disk_serial,
+        cpu_id,
+        components_used,
+    })
+}
+
+// Trim an identifier and drop empty values and common firmware placeholders.
+fn clean_identifier(value: String) -> Option<String> {
+    const PLACEHOLDERS: &[&str] = &[
+        "to be filled by o.e.m.",
+        "default string",
+        "system serial number",
+        "not applicable",
+        "not specified",
+        "none",
+        "n/a",
+        "03000200-0400-0500-0006-000700080009",
+    ];
+    let value = value.trim();
+    let lower = value.to_lowercase();
+    let filler_only = value
+        .chars()
+        .all(|c| matches!(c, '0' | 'F' | 'f' | '-' | ' '));
+    if value.is_empty() || filler_only || PLACEHOLDERS.contains(&lower.as_str()) {
+        return None;
+    }
+    Some(value.to_string())
+}
+
+#[cfg(target_os = "windows")]
+// System disk serial and processor ID via WMI.
+async fn collect_hardware_ids(app: &tauri::AppHandle) -> (Option<String>, Option<String>) {
+    use tauri_plugin_shell::ShellExt;
+    let shell = app.shell();
+
+    let disk_script =
+        "$d = Get-Partition -DriveLetter $env:SystemDrive[0] -ErrorAction SilentlyContinue | \
+                       Get-Disk -ErrorAction SilentlyContinue; \
+                       if ($d) { $d.SerialNumber } else { \
+                       (Get-CimInstance Win32_DiskDrive | Where-Object Index -eq 0).SerialNumber }";
+    let cpu_script = "(Get-CimInstance Win32_Processor | Select-Object -First 1).ProcessorId";
+    let (disk, cpu) = tokio::join!(run_pwsh(shell, disk_script), run_pwsh(shell, cpu_script));
+    (
+        disk.and_then(clean_identifier),
+        cpu.and_then(clean_identifier),
+    )
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn collect_hardware_ids(_app: &tauri::AppHandle) -> (Option<String>, Option<String>) {
+    (None, None)
+}
+
+#[tauri::command]
+/// Compare two system info snapshots and return what changed from `a` to `b`.
+///
+/// Each snapshot may be a `SystemInfo` object or a JSON string of one (as exported
+/// from the System Info page). Disks are matched by mount point, networks by
+/// interface name and sensors by label.
+pub fn diff_system_info(
+    snapshot_a_json: serde_json::Value,
+    snapshot_b_json: serde_json::Value,
+) -> Result<SystemInfoDiff, String> {
+    let a = parse_snapshot(snapshot_a_json, "first")?;
+    let b = parse_snapshot(snapshot_b_json, "second")?;
+    let delta = |before: u64, after: u64| after as i64 - before as i64;
+
+    let disks = b
+        .disks
+        .iter()
+        .filter_map(|disk_b| {
+            let disk_a = a
+                .disks
+                .iter()
+                .find(|d| d.mount_point == disk_b.mount_point)?;
+            Some(DiskDelta {
+                mount_point: disk_b.mount_point.clone(),
+                available_delta: delta(disk_a.available_space, disk_b.available_space),
+            })
+        })
+        .collect();
+    let temperatures = b
+        .sensors
+        .iter()
+        .filter_map(|sensor_b| {
+            let sensor_a = a.sensors.iter().find(|s| s.label == sensor_b.label)?;
+            Some(TemperatureDelta {
+                label: sensor_b.label.clone(),
+                before_c: sensor_a.temperature_c,
+                after_c: sensor_b.temperature_c,
+                delta_c: sensor_b.temperature_c - sensor_a.temperature_c,
+            })
+        })
+        .collect();
+    let disk_names = |info: &SystemInfo| -> Vec<String> {
+        info.disks.iter().map(|d| d.mount_point.clone()).collect()
+    };
+    let network_names = |info: &SystemInfo| -> Vec<String> {
+        info.networks.iter().map(|n| n.interface.clone()).collect()
+    };
+    let only_in = |left: &[String], right: &[String]| -> Vec<String> {
+        left.iter()
+            .filter(|x| !right.contains(x))
+            .cloned()
+            .collect()
     };
 
-    Ok(info)
+    Ok(SystemInfoDiff {
+        uptime_delta_seconds: delta(a.uptime_seconds, b.uptime_seconds),
+        // Boot time is derived from uptime and can jitter by a second or two
+        rebooted: a.boot_time_seconds.abs_diff(b.boot_time_seconds) > 60,
+        memory_used_delta: delta(a.memory.used, b.memory.used),
+        memory_available_delta: delta(a.memory.available, b.memory.available),
+        swap_used_delta: delta(a.memory.swap_used, b.memory.swap_used),
+        disks,
+        added_disks: only_in(&disk_names(&b), &disk_names(&a)),
+        removed_disks: only_in(&disk_names(&a), &disk_names(&b)),
+        added_networks: only_in(&network_names(&b), &network_names(&a)),
+        removed_networks: only_in(&network_names(&a), &network_names(&b)),
+        temperatures,
+    })
+}
+
+// Accept a snapshot either as a JSON object or as a string containing JSON.
+fn parse_snapshot(value: serde_json::Value, which: &str) -> Result<SystemInfo, String> {
+    let value = match value {
+        serde_json::Value::String(text) => serde_json::from_str(&text)
+            .map_err(|e| format!("The {} snapshot is not valid JSON: {}", which, e))?,
+        other => other,
+    };
+    serde_json::from_value(value)
+        .map_err(|e| format!("The {} snapshot is not a system info object: {}", which, e))
+}
+
+#[tauri::command]
+/// Build a focused report for every GPU in the machine.
+///
+/// Merges the wgpu adapters (as in `SystemInfo.gpus`) with Windows
+/// `Win32_VideoController` data (VRAM, driver version, video mode) and, when
+/// `include_nvidia` is set (the default) and nvidia-smi is available, live utilization,
+/// memory and temperature. Sources are matched by GPU name; a GPU known to only one
+/// source is still listed.
+pub async fn get_gpu_report(
+    app: tauri::AppHandle,
+    include_nvidia: Option<bool>,
+) -> Result<Vec<GpuReport>, String> {
+    let adapters = tauri::async_runtime::spawn_blocking(|| static_info(false).gpus)
+        .await
+        .map_err(|e| format!("GPU enumeration failed: {}", e))?;
+    let controllers = collect_video_controllers(&app).await;
+    let nvidia = if include_nvidia.unwrap_or(true) {
+        tauri::async_runtime::spawn_blocking(query_nvidia_smi)
+            .await
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    let mut reports: Vec<GpuReport> = adapters
+        .into_iter()
+        .map(|adapter| GpuReport {
+            name: adapter.name.clone(),
+            adapter: Some(adapter),
+            vram_bytes: None,
+            driver_version: None,
+            video_mode: None,
+            nvidia: None,
+        })
+        .collect();
+
+    let mut has_wmi = vec![false; reports.len()];
+    for ctrl in controllers {
+        let slot = (0..reports.len())
+            .find(|&i| !has_wmi[i] && gpu_names_match(&reports[i].name, &ctrl.name));
+        let index = slot.unwrap_or_else(|| {
+            reports.push(GpuReport {
+                name: ctrl.name.clone(),
+                adapter: None,
+                vram_bytes: None,
+                driver_version: None,
+                video_mode: None,
+                nvidia: None,
+            });
+            has_wmi.push(false);
+            reports.len() - 1
+        });
+        has_wmi[index] = true;
+        let report = &mut reports[index];
+        report.vram_bytes = ctrl.vram_bytes;
+        report.driver_version = ctrl.driver_version;
+        report.video_mode = ctrl.video_mode;
+    }
+
+    for (name, stats) in nvidia {
+        match reports
+            .iter_mut()
+            .find(|r| r.nvidia.is_none() && gpu_names_match(&r.name, &name))
+        {
+            Some(report) => report.nvidia = Some(stats),
+            None => reports.push(GpuReport {
+                name,
+                adapter: None,
+                vram_bytes: None,
+                driver_version: stats.driver_version.clone(),
+                video_mode: None,
+                nvidia: Some(stats),
+            }),
+        }
+    }
+    Ok(reports)
+}
+
+// A video controller as reported by `Win32_VideoController`.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+struct VideoController {
+    name: String,
+    vram_bytes: Option<u64>,
+    driver_version: Option<String>,
+    video_mode: Option<String>,
+}
+
+/// `Win32_VideoController` query behind `ExtraInfo.video_ctrl_ex` and `get_gpu_report`.
+#[cfg(target_os = "windows")]
+const VIDEO_CONTROLLER_SCRIPT: &str = "Get-CimInstance Win32_VideoController | Select-Object Name, AdapterRAM, DriverVersion, VideoModeDescription | ConvertTo-Json -Compress";
+
+#[cfg(target_os = "windows")]
+async fn collect_video_controllers(app: &tauri::AppHandle) -> Vec<VideoController> {
+    use tauri_plugin_shell::ShellExt;
+    let raw = run_pwsh(app.shell(), VIDEO_CONTROLLER_SCRIPT).await;
+    parse_video_controllers(&parse_json_array(raw))
+}
+
+// Typed view of the rows printed by `VIDEO_CONTROLLER_SCRIPT`; rows without a name are skipped.
+#[cfg(target_os = "windows")]
+fn parse_video_controllers(rows: &[serde_json::Value]) -> Vec<VideoController> {
+    rows.iter()
+        .filter_map(|v| {
+            let text = |key: &str| {
+                v.get(key)
+                    .and_then(|x| x.as_str())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+            };
+            Some(VideoController {
+                name: text("Name")?,
+                vram_bytes: v.get("AdapterRAM").and_then(|x| x.as_u64()),
+                driver_version: text("DriverVersion"),
+                video_mode: text("VideoModeDescription"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn collect_video_controllers(_app: &tauri::AppHandle) -> Vec<VideoController> {
+    Vec::new()
+}
+
+/// PCI vendor ID of NVIDIA GPUs.
+const NVIDIA_VENDOR_ID: u32 = 0x10DE;
+
+// Per-GPU stats from nvidia-smi; empty when the tool is missing or fails.
+fn query_nvidia_smi() -> Vec<(String, NvidiaGpuStats)> {
+    let output = std::process::Command::new("nvidia-smi")
+        .args([
+            "--query-gpu=name,utilization.gpu,memory.used,memory.total,temperature.gpu,driver_version",
+            "--format=csv,noheader,nounits",
+        ])
+        .output();
+    let stdout = match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout).to_string(),
+        _ => return Vec::new(),
+    };
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            if fields.len() < 6 || fields[0].is_empty() {
+                return None;
+            }
+            // Unsupported values are reported as "[N/A]" and parse to None.
+            let stats = NvidiaGpuStats {
+                utilization_percent: fields[1].parse().ok(),
+                memory_used_mb: fields[2].parse().ok(),
+                memory_total_mb: fields[3].parse().ok(),
+                temperature_c: fields[4].parse().ok(),
+                driver_version: Some(fields[5].to_string()).filter(|s| !s.is_empty()),
+            };
+            Some((fields[0].to_string(), stats))
+        })
+        .collect()
+}
+
+// Names from different sources match when equal or one contains the other
+// (ignoring case), e.g. "NVIDIA GeForce RTX 3060" vs "GeForce RTX 3060".
+fn gpu_names_match(a: &str, b: &str) -> bool {
+    let (a, b) = (a.trim().to_lowercase(), b.trim().to_lowercase());
+    !a.is_empty() && !b.is_empty() && (a.contains(&b) || b.contains(&a))
+}
+
+#[tauri::command]
+/// Plain-text summary of the machine for pasting into an email or chat.
+///
+/// One line each for the host, model, OS, CPU, RAM, every fixed disk, every GPU and
+/// the uptime. Uses the `get_system_info` snapshot while it is cached, otherwise a
+/// quick `get_system_info_fast`-style snapshot.
+pub async fn get_system_summary_text(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    let ttl = system_info_cache_ttl(&state.data_dir());
+    let cached = state
+        .system_info_cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .filter(|c| c.collected_at.elapsed() < ttl)
+        .map(|c| c.info.clone());
+    let info = match cached {
+        Some(info) => info,
+        None => tauri::async_runtime::spawn_blocking(|| collect_base_info(false))
+            .await
+            .map_err(|e| format!("System info collection failed: {}", e))?,
+    };
+    Ok(format_system_summary(&info))
+}
+
+fn format_system_summary(info: &SystemInfo) -> String {
+    const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+    let gb = |bytes: u64| format!("{:.1} GB", bytes as f64 / GIB);
+    let mut lines = Vec::new();
+
+    if let Some(hostname) = &info.hostname {
+        lines.push(format!("Host: {}", hostname));
+    }
+    let model = info.product.as_ref().map(|p| {
+        [p.vendor.as_deref(), p.name.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+    if let Some(model) = model.filter(|m| !m.is_empty()) {
+        lines.push(format!("Model: {}", model));
+    }
+    let os = [info.os.as_deref(), info.os_version.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !os.is_empty() {
+        lines.push(format!("OS: {}", os));
+    }
+
+    let cores = match info.cpu.num_physical_cores {
+        Some(physical) => format!("{}C/{}T", physical, info.cpu.num_logical_cpus),
+        None => format!("{} threads", info.cpu.num_logical_cpus),
+    };
+    lines.push(format!("CPU: {} ({})", info.cpu.brand.trim(), cores));
+    lines.push(format!(
+        "RAM: {} ({} in use)",
+        gb(info.memory.total),
+        gb(info.memory.used)
+    ));
+
+    for disk in info.disks.iter().filter(|d| !d.is_removable) {
+        let mut line = format!(
+            "Disk {}: {} {}, {} free",
+            disk.mount_point,
+            gb(disk.total_space),
+            disk.file_system,
+            gb(disk.available_space)
+        );
+        if let Some(health) = &disk.smart_health {
+            line.push_str(&format!(", SMART {}", health));
+        }
+        lines.push(line);
+    }
+    for gpu in &info.gpus {
+        match gpu.vram_bytes {
+            Some(vram) => lines.push(format!("GPU: {} ({} VRAM)", gpu.name, gb(vram))),
+            None => lines.push(format!("GPU: {}", gpu.name)),
+        }
+    }
+    lines.push(format!(
+        "Uptime: {}",
+        format_duration_human(info.uptime_seconds)
+    ));
+    lines.join("\n")
+}
+
+#[tauri::command]
+/// Format a duration such as `SystemInfo.uptime_seconds` for display, e.g. "3d 4h 12m".
+///
+/// Leading zero units are dropped ("4h 12m"); durations under a minute are shown in
+/// seconds ("42s").
+pub fn format_duration_human(seconds: u64) -> String {
+    let (days, hours, minutes) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3600,
+        seconds % 3600 / 60,
+    );
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[tauri::command]
+/// Format `SystemInfo.boot_time_seconds` (Unix time) as "YYYY-MM-DD HH:MM:SS".
+///
+/// # Arguments
+/// * `boot_time_seconds` - Boot time in seconds since the Unix epoch
+/// * `tz_offset_minutes` - Offset east of UTC (e.g. 600 for AEST; note this is the
+///   negation of JavaScript's `getTimezoneOffset()`). Defaults to the machine's time zone.
+pub fn format_boot_time(
+    boot_time_seconds: u64,
+    tz_offset_minutes: Option<i32>,
+) -> Result<String, String> {
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    let utc = i64::try_from(boot_time_seconds)
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .ok_or_else(|| format!("Invalid boot time: {}", boot_time_seconds))?;
+    match tz_offset_minutes {
+        Some(minutes) => {
+            let offset = chrono::FixedOffset::east_opt(minutes.saturating_mul(60))
+                .filter(|_| minutes.abs() <= 14 * 60)
+                .ok_or_else(|| format!("Invalid time zone offset: {} minutes", minutes))?;
+            Ok(utc.with_timezone(&offset).format(FORMAT).to_string())
+        }
+        None => Ok(utc.with_timezone(&chrono::Local).format(FORMAT).to_string()),
+    }
+}
+
+#[tauri::command]
+/// Whether AutoService is running elevated (as administrator).
+///
+/// Checks the elevation flag of the process token, which is what UAC-protected actions
+/// such as Defender scans and some shortcuts depend on. Always false off Windows.
+pub fn is_elevated() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+        use windows_sys::Win32::Security::{
+            GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY,
+        };
+        use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+        let mut token: HANDLE = std::ptr::null_mut();
+        // SAFETY: the token handle is only used while open and closed before returning;
+        // `elevation` is a properly sized out-buffer for the TokenElevation class.
+        unsafe {
+            if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+                return false;
+            }
+            let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+            let mut returned = 0u32;
+            let ok = GetTokenInformation(
+                token,
+                TokenElevation,
+                &mut elevation as *mut TOKEN_ELEVATION as *mut _,
+                std::mem::size_of::<TOKEN_ELEVATION>() as u32,
+                &mut returned,
+            );
+            CloseHandle(token);
+            ok != 0 && elevation.TokenIsElevated != 0
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        false
+    }
+}
+
+/// Default service queried by `get_public_network_info` (overridable with the
+/// `network.public_ip_lookup_url` setting).
+const DEFAULT_PUBLIC_IP_LOOKUP_URL: &str = "https://ipinfo.io/json";
+
+#[tauri::command]
+/// Look up the machine's public IP address, ISP and location.
+///
+/// Fetches a JSON lookup endpoint with `curl` (bundled with Windows 10 and later) and
+/// accepts the common field names of ipinfo.io, ip-api.com and ipapi.co. The endpoint
+/// comes from the `network.public_ip_lookup_url` setting, defaulting to ipinfo.io.
+/// Offline machines get a clear "no internet connection" error.
+pub async fn get_public_network_info(
+    state: tauri::State<'_, AppState>,
+) -> Result<PublicNetworkInfo, String> {
+    let url = crate::settings::read_app_settings(&state.data_dir())
+        .ok()
+        .and_then(|v| {
+            v.pointer("/network/public_ip_lookup_url")?
+                .as_str()
+                .map(|s| s.trim().to_string())
+        })
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_PUBLIC_IP_LOOKUP_URL.to_string());
+
+    let output = tauri::async_runtime::spawn_blocking(move || {
+        std::process::Command::new("curl")
+            .args(["-s", "-S", "-f", "-L", "--max-time", "10", &url])
+            .output()
+    })
+    .await
+    .map_err(|e| format!("Public IP lookup failed: {}", e))?
+    .map_err(|e| format!("Failed to run curl: {}", e))?;
+
+    if !output.status.success() {
+        // curl exit codes: 6 = couldn't resolve host, 7 = couldn't connect, 28 = timed out
+        return Err(match output.status.code() {
+            Some(6) | Some(7) | Some(28) => {
+                "No internet connection: the public IP lookup service could not be reached"
+                    .to_string()
+            }
+            _ => format!(
+                "Public IP lookup failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        });
+    }
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Unexpected response from the public IP lookup: {}", e))?;
+    let field = |keys: &[&str]| {
+        keys.iter().find_map(|k| {
+            json.get(*k)
+                .and_then(|v| v.as_str())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+        })
+    };
+    Ok(PublicNetworkInfo {
+        public_ip: field(&["ip", "query"])
+            .ok_or("The public IP lookup response has no IP address")?,
+        isp: field(&["isp", "org"]),
+        city: field(&["city"]),
+        country: field(&["country_name", "country"]),
+    })
+}
+
+#[tauri::command]
+/// Report the BitLocker state of every volume via WMI `Win32_EncryptableVolume`.
+///
+/// Returns protection status, encryption percentage and lock status per drive, so data
+/// on encrypted drives is not lost by accident when wiping or imaging. Querying the
+/// encryption namespace requires administrator rights. Windows-only.
+pub async fn get_bitlocker_status(app: tauri::AppHandle) -> Result<Vec<BitLockerVolume>, String> {
+    collect_bitlocker_status(&app).await
+}
+
+#[cfg(target_os = "windows")]
+async fn collect_bitlocker_status(app: &tauri::AppHandle) -> Result<Vec<BitLockerVolume>, String> {
+    use tauri_plugin_shell::ShellExt;
+    let shell = app.shell();
+
+    let script = "@(Get-CimInstance -Namespace 'root/cimv2/security/microsoftvolumeencryption' \
+                  -ClassName Win32_EncryptableVolume -ErrorAction Stop | ForEach-Object { \
+                  $conv = Invoke-CimMethod -InputObject $_ -MethodName GetConversionStatus; \
+                  $lock = Invoke-CimMethod -InputObject $_ -MethodName GetLockStatus; \
+                  [pscustomobject]@{ drive = $_.DriveLetter; id = $_.DeviceID; \
+                  protection = [int]$_.ProtectionStatus; \
+                  percentage = $conv.EncryptionPercentage; lock = $lock.LockStatus } }) | \
+                  ConvertTo-Json -Compress";
+    let raw = run_pwsh(shell, script).await.ok_or_else(|| {
+        "Failed to query BitLocker status (administrator rights are required)".to_string()
+    })?;
+
+    Ok(parse_json_array(Some(raw))
+        .into_iter()
+        .map(|v| {
+            let text = |key: &str| {
+                v.get(key)
+                    .and_then(|x| x.as_str())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+            };
+            let lock_status = match v.get("lock").and_then(|x| x.as_i64()) {
+                Some(0) => "unlocked",
+                Some(1) => "locked",
+                _ => "unknown",
+            };
+            BitLockerVolume {
+                drive: text("drive").or_else(|| text("id")).unwrap_or_default(),
+                protection_on: v.get("protection").and_then(|x| x.as_i64()) == Some(1),
+                encryption_percentage: v.get("percentage").and_then(|x| x.as_f64()),
+                lock_status: lock_status.to_string(),
+            }
+        })
+        .collect())
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn collect_bitlocker_status(_app: &tauri::AppHandle) -> Result<Vec<BitLockerVolume>, String> {
+    Err("BitLocker status is only available on Windows".into())
+}
+
+#[tauri::command]
+/// Collect Windows Reliability Monitor history for the last `days` days.
+///
+/// Queries `Win32_ReliabilityStabilityMetrics` for the stability index (1-10) over
+/// time and `Win32_ReliabilityRecords` for the events behind it (application crashes
+/// and hangs, hardware errors, unexpected shutdowns, installs). Events are newest
+/// first and capped at 200. Windows-only.
+pub async fn get_reliability_history(
+    app: tauri::AppHandle,
+    days: u32,
+) -> Result<ReliabilityHistory, String> {
+    let days = days.clamp(1, 365);
+    collect_reliability_history(&app, days).await
+}
+
+#[cfg(target_os = "windows")]
+async fn collect_reliability_history(
+    app: &tauri::AppHandle,
+    days: u32,
+) -> Result<ReliabilityHistory, String> {
+    use tauri_plugin_shell::ShellExt;
+    let shell = app.shell();
+
+    let since = format!("$since = (Get-Date).AddDays(-{});", days);
+    let metrics_script = format!(
+        "{since} @(Get-CimInstance Win32_ReliabilityStabilityMetrics | \
+         Where-Object {{ $_.TimeGenerated -ge $since }} | Sort-Object TimeGenerated | \
+         Select-Object @{{n='time';e={{$_.TimeGenerated.ToString('o')}}}}, \
+         @{{n='index';e={{[double]$_.SystemStabilityIndex}}}}) | ConvertTo-Json -Compress"
+    );
+    let records_script = format!(
+        "{since} @(Get-CimInstance Win32_ReliabilityRecords | \
+         Where-Object {{ $_.TimeGenerated -ge $since }} | Sort-Object TimeGenerated -Descending | \
+         Select-Object -First {max} @{{n='time';e={{$_.TimeGenerated.ToString('o')}}}}, \
+         @{{n='source';e={{$_.SourceName}}}}, @{{n='product';e={{$_.ProductName}}}}, \
+         @{{n='event_id';e={{[int64]$_.EventIdentifier}}}}, @{{n='message';e={{$_.Message}}}}) | \
+         ConvertTo-Json -Compress",
+        max = MAX_RELIABILITY_EVENTS
+    );
+    let (metrics_raw, records_raw) = tokio::join!(
+        run_pwsh(shell, &metrics_script),
+        run_pwsh(shell, &records_script),
+    );
+    if metrics_raw.is_none() && records_raw.is_none() {
+        return Err("Failed to query reliability history (is the RAC task enabled?)".into());
+    }
+
+    let stability_index = parse_json_array(metrics_raw)
+        .into_iter()
+        .filter_map(|v| {
+            Some(StabilityPoint {
+                time: v.get("time")?.as_str()?.to_string(),
+                index: v.get("index")?.as_f64()?,
+            })
+        })
+        .collect();
+    let events = parse_json_array(records_raw)
+        .into_iter()
+        .map(|v| {
+            let text = |key: &str| {
+                v.get(key)
+                    .and_then(|x| x.as_str())
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string()
+            };
+            let source = text("source");
+            let event_id = v.get("event_id").and_then(|x| x.as_i64()).unwrap_or(0);
+            ReliabilityEvent {
+                time: text("time"),
+                kind: classify_reliability_event(&source, event_id).to_string(),
+                product: text("product"),
+                message: text("message"),
+                source,
+                event_id,
+            }
+        })
+        .collect();
+    Ok(ReliabilityHistory {
+        stability_index,
+        events,
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn collect_reliability_history(
+    _app: &tauri::AppHandle,
+    _days: u32,
+) -> Result<ReliabilityHistory, String> {
+    Err("Reliability history is only available on Windows".into())
+}
+
+// Map a reliability record's source/event id to a coarse category for the UI.
+#[cfg(target_os = "windows")]
+fn classify_reliability_event(source: &str, event_id: i64) -> &'static str {
+    match (source, event_id) {
+        ("Application Error", _) | ("Windows Error Reporting", 1001) => "app_crash",
+        ("Application Hang", _) => "app_hang",
+        ("Microsoft-Windows-WHEA-Logger", _) => "hardware_failure",
+        ("EventLog", 6008) | ("Microsoft-Windows-Kernel-Power", 41) => "unexpected_shutdown",
+        ("MsiInstaller", _) => "install",
+        ("Microsoft-Windows-WindowsUpdateClient", _) => "windows_update",
+        _ => "other",
+    }
 }
 
 // Collect battery information, falling back to an empty list on any error to
@@ -357,30 +1715,45 @@ fn get_batteries_info() -> Result<Vec<BatteryInfo>, String> {
     Ok(out)
 }
 
+#[cfg(target_os = "windows")]
+// Async helper to run a PowerShell command and capture stdout as a trimmed String.
+async fn run_pwsh<R: tauri::Runtime>(
+    shell: &tauri_plugin_shell::Shell<R>,
+    script: &str,
+) -> Option<String> {
+    let fut = shell
+        .command("powershell.exe")
+        .args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .output();
+    match fut.await {
+        Ok(out) if out.status.success() => {
+            let v = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            Some(v)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "windows")]
+// JSON arrays normalization helper: if a single object is returned, wrap it as a one-element array.
+fn parse_json_array(s: Option<String>) -> Vec<serde_json::Value> {
+    if let Some(txt) = s {
+        match serde_json::from_str::<serde_json::Value>(&txt) {
+            Ok(serde_json::Value::Array(arr)) => arr,
+            Ok(other) => vec![other],
+            Err(_) => Vec::new(),
+        }
+    } else {
+        Vec::new()
+    }
+}
+
 #[cfg(target_os = "windows")]
 // Collect extra Windows details via PowerShell/WMI in parallel.
 async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo> {
     use tauri_plugin_shell::ShellExt;
     let shell = app.shell();
 
-    // Async helper to run a PowerShell command and capture stdout as a trimmed String.
-    async fn run_pwsh<R: tauri::Runtime>(
-        shell: &tauri_plugin_shell::Shell<R>,
-        script: &str,
-    ) -> Option<String> {
-        let fut = shell
-            .command("powershell.exe")
-            .args(["-NoProfile", "-NonInteractive", "-Command", script])
-            .output();
-        match fut.await {
-            Ok(out) if out.status.success() => {
-                let v = String::from_utf8_lossy(&out.stdout).trim().to_string();
-                Some(v)
-            }
-            _ => None,
-        }
-    }
-
     // Launch all commands concurrently to reduce total latency.
     let (
         secure_boot_raw,
@@ -397,21 +1770,25 @@ async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo
         disk_drives_raw,
         nic_enabled_raw,
         computer_system_raw,
+        pending_reboot_raw,
+        windows_license_raw,
     ) = tokio::join!(
         run_pwsh(&shell, "(Confirm-SecureBootUEFI) 2>$null | Out-String"),
         run_pwsh(&shell, "Get-Tpm | Select-Object -Property TpmPresent, TpmReady, ManagedAuthLevel, OwnerAuth, SpecVersion | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance -ClassName Win32_BIOS | Select-Object Manufacturer, SMBIOSBIOSVersion, ReleaseDate | ConvertTo-Json -Compress"),
-        run_pwsh(&shell, "Get-HotFix | Select-Object -ExpandProperty HotFixID | Out-String"),
+        run_pwsh(&shell, "Get-HotFix | Select-Object HotFixID, Description, @{n='InstalledOn';e={ if ($_.InstalledOn) { $_.InstalledOn.ToString('yyyy-MM-dd') } }} | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance Win32_VideoController | Select-Object -ExpandProperty Name | Out-String"),
         run_pwsh(&shell, "Get-PhysicalDisk | Select-Object FriendlyName, MediaType, Size | ForEach-Object { \"$($_.FriendlyName) ($($_.MediaType)) $(\"{0:N1}\" -f ($_.Size/1GB)) GB\" } | Out-String"),
         run_pwsh(&shell, "(Get-ChildItem 'HKLM:SOFTWARE\\Microsoft\\NET Framework Setup\\NDP' -Recurse | Get-ItemProperty -Name Version -ErrorAction SilentlyContinue | Sort-Object Version | Select-Object -Last 1).Version | Out-String"),
         run_pwsh(&shell, "Get-CimInstance Win32_PhysicalMemory | Select-Object BankLabel, DeviceLocator, Manufacturer, Capacity, Speed, SerialNumber, PartNumber, MemoryType, FormFactor, ConfiguredVoltage, DataWidth, TotalWidth | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance Win32_Processor | Select-Object Name, Manufacturer, NumberOfCores, NumberOfLogicalProcessors, MaxClockSpeed, LoadPercentage | ConvertTo-Json -Compress"),
-        run_pwsh(&shell, "Get-CimInstance Win32_VideoController | Select-Object Name, AdapterRAM, DriverVersion, VideoModeDescription | ConvertTo-Json -Compress"),
+        run_pwsh(&shell, VIDEO_CONTROLLER_SCRIPT),
         run_pwsh(&shell, "Get-CimInstance Win32_BaseBoard | Select-Object Manufacturer, Product, SerialNumber | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance Win32_DiskDrive | Select-Object Model, InterfaceType, MediaType, Size | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance Win32_NetworkAdapter | Where-Object {$_.NetEnabled -eq $true} | Select-Object Name, MACAddress, Speed | ConvertTo-Json -Compress"),
         run_pwsh(&shell, "Get-CimInstance Win32_ComputerSystem | ConvertTo-Json -Compress"),
+        run_pwsh(&shell, &pending_reboot_script()),
+        run_pwsh(&shell, crate::audit::WINDOWS_LICENSE_SCRIPT),
     );
 
     // Post-processing and normalization
@@ -449,26 +1826,34 @@ async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo
         .unwrap_or_default()
     };
 
-    let hotfixes = to_vec_lines(hotfixes_raw);
+    let mut hotfixes_detailed: Vec<crate::models::Hotfix> = parse_json_array(hotfixes_raw)
+        .iter()
+        .filter_map(|v| {
+            let text = |key: &str| {
+                v.get(key)
+                    .and_then(|x| x.as_str())
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+            };
+            Some(crate::models::Hotfix {
+                hotfix_id: text("HotFixID")?,
+                description: text("Description"),
+                installed_on: text("InstalledOn"),
+            })
+        })
+        .collect();
+    // ISO dates sort chronologically; updates without a date go last.
+    hotfixes_detailed.sort_by(|a, b| b.installed_on.cmp(&a.installed_on));
+    let hotfixes = hotfixes_detailed
+        .iter()
+        .map(|h| h.hotfix_id.clone())
+        .collect();
     let video_controllers = to_vec_lines(video_controllers_raw);
     let physical_disks = to_vec_lines(physical_disks_raw);
     let dotnet_version = dotnet_version_raw
         .and_then(|s| s.lines().last().map(|l| l.trim().to_string()))
         .filter(|s| !s.is_empty());
 
-    // JSON arrays normalization helper: if a single object is returned, wrap it as a one-element array.
-    let parse_json_array = |s: Option<String>| -> Vec<serde_json::Value> {
-        if let Some(txt) = s {
-            match serde_json::from_str::<serde_json::Value>(&txt) {
-                Ok(serde_json::Value::Array(arr)) => arr,
-                Ok(other) => vec![other],
-                Err(_) => Vec::new(),
-            }
-        } else {
-            Vec::new()
-        }
-    };
-
     let ram_modules = parse_json_array(ram_modules_raw);
     let cpu_wmi = parse_json_array(cpu_wmi_raw);
     let video_ctrl_ex = parse_json_array(video_ctrl_ex_raw);
@@ -476,6 +1861,23 @@ async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo
     let disk_drives = parse_json_array(disk_drives_raw);
     let nic_enabled = parse_json_array(nic_enabled_raw);
     let computer_system = parse_json_array(computer_system_raw);
+    let pending_reboot_reasons: Option<Vec<String>> =
+        pending_reboot_raw.and_then(|s| serde_json::from_str(&s).ok());
+    let pending_reboot = pending_reboot_reasons.as_ref().map(|r| !r.is_empty());
+    let windows_activation = windows_license_raw
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(s.trim()).ok())
+        .and_then(|v| {
+            let status = crate::audit::license_status_label(v.get("LicenseStatus")?.as_i64()?);
+            let key = v
+                .get("PartialProductKey")
+                .and_then(|k| k.as_str())
+                .map(str::trim)
+                .filter(|k| !k.is_empty());
+            Some(match key {
+                Some(key) => format!("{} (key ending {})", status, key),
+                None => status.to_string(),
+            })
+        });
 
     Some(ExtraInfo {
         secure_boot,
@@ -484,6 +1886,7 @@ async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo
         bios_version,
         bios_release_date,
         hotfixes,
+        hotfixes_detailed,
         video_controllers,
         physical_disks,
         dotnet_version,
@@ -494,11 +1897,247 @@ async fn collect_windows_extra_async(app: &tauri::AppHandle) -> Option<ExtraInfo
         disk_drives,
         nic_enabled,
         computer_system,
+        pending_reboot,
+        pending_reboot_reasons: pending_reboot_reasons.unwrap_or_default(),
+        windows_activation,
     })
 }
 
+#[cfg(target_os = "windows")]
+// PowerShell that prints a JSON array with the reason of every pending-reboot indicator
+// (`power::PENDING_REBOOT_INDICATORS`) that is present.
+fn pending_reboot_script() -> String {
+    let mut script = String::from("$r = @(); ");
+    for (key, value, reason) in crate::power::PENDING_REBOOT_INDICATORS {
+        let path = key.replacen('\\', ":\\", 1);
+        let test = match value {
+            Some(name) => format!(
+                "(Get-ItemProperty -Path '{}' -Name '{}' -ErrorAction SilentlyContinue)",
+                path, name
+            ),
+            None => format!("(Test-Path '{}')", path),
+        };
+        script.push_str(&format!("if {} {{ $r += '{}' }}; ", test, reason));
+    }
+    script.push_str("ConvertTo-Json -InputObject $r -Compress");
+    script
+}
+
 #[cfg(not(target_os = "windows"))]
 // Non-Windows platforms do not provide Windows-specific extra info.
 async fn collect_windows_extra_async(_app: &tauri::AppHandle) -> Option<ExtraInfo> {
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // A minimal `SystemInfo` snapshot; disks are (mount point, available bytes) and
+    // sensors are (label, temperature).
+    fn snapshot(
+        uptime: u64,
+        boot_time: u64,
+        memory_used: u64,
+        disks: &[(&str, u64)],
+        networks: &[&str],
+        sensors: &[(&str, f32)],
+    ) -> serde_json::Value {
+        json!({
+            "uptime_seconds": uptime,
+            "boot_time_seconds": boot_time,
+            "users": [],
+            "cpu": { "brand": "Test CPU", "frequency_mhz": 3000, "num_logical_cpus": 4, "cores": [] },
+            "memory": {
+                "total": 16_000,
+                "available": 16_000 - memory_used,
+                "used": memory_used,
+                "free": 16_000 - memory_used,
+                "swap_total": 0,
+                "swap_used": 0,
+            },
+            "disks": disks.iter().map(|(mount, available)| json!({
+                "name": mount,
+                "file_system": "NTFS",
+                "mount_point": mount,
+                "total_space": 1_000_000,
+                "available_space": available,
+                "is_removable": false,
+                "is_read_only": false,
+                "kind": "SSD",
+                "read_bytes": 0,
+                "written_bytes": 0,
+            })).collect::<Vec<_>>(),
+            "networks": networks.iter().map(|name| json!({
+                "interface": name,
+                "mtu": 1500,
+                "ips": [],
+                "received": 0,
+                "transmitted": 0,
+                "total_received": 0,
+                "total_transmitted": 0,
+                "errors_rx": 0,
+                "errors_tx": 0,
+            })).collect::<Vec<_>>(),
+            "gpus": [],
+            "sensors": sensors.iter().map(|(label, temp)| json!({
+                "label": label,
+                "temperature_c": temp,
+            })).collect::<Vec<_>>(),
+            "batteries": [],
+            "load_avg": { "one": 0.0, "five": 0.0, "fifteen": 0.0 },
+        })
+    }
+
+    #[test]
+    fn test_diff_system_info() {
+        let base = snapshot(
+            3_600,
+            1_000_000,
+            4_000,
+            &[("C:\\", 500_000)],
+            &["Ethernet"],
+            &[("CPU Package", 40.0)],
+        );
+        let cases = [
+            // Nothing changed
+            (
+                base.clone(),
+                json!({
+                    "uptime_delta_seconds": 0,
+                    "rebooted": false,
+                    "memory_used_delta": 0,
+                    "memory_available_delta": 0,
+                    "swap_used_delta": 0,
+                    "disks": [{ "mount_point": "C:\\", "available_delta": 0 }],
+                    "added_disks": [],
+                    "removed_disks": [],
+                    "added_networks": [],
+                    "removed_networks": [],
+                    "temperatures": [
+                        { "label": "CPU Package", "before_c": 40.0, "after_c": 40.0, "delta_c": 0.0 }
+                    ],
+                }),
+            ),
+            // An hour later (boot time jittered by a second): disk filled, USB stick and
+            // Wi-Fi appeared, Ethernet went away, CPU got warmer
+            (
+                snapshot(
+                    7_200,
+                    1_000_001,
+                    6_000,
+                    &[("C:\\", 400_000), ("E:\\", 10)],
+                    &["Wi-Fi"],
+                    &[("CPU Package", 45.5)],
+                ),
+                json!({
+                    "uptime_delta_seconds": 3_600,
+                    "rebooted": false,
+                    "memory_used_delta": 2_000,
+                    "memory_available_delta": -2_000,
+                    "swap_used_delta": 0,
+                    "disks": [{ "mount_point": "C:\\", "available_delta": -100_000 }],
+                    "added_disks": ["E:\\"],
+                    "removed_disks": [],
+                    "added_networks": ["Wi-Fi"],
+                    "removed_networks": ["Ethernet"],
+                    "temperatures": [
+                        { "label": "CPU Package", "before_c": 40.0, "after_c": 45.5, "delta_c": 5.5 }
+                    ],
+                }),
+            ),
+            // Rebooted in between; the disk and sensor vanished from the later snapshot
+            (
+                snapshot(120, 1_010_000, 4_000, &[], &["Ethernet"], &[]),
+                json!({
+                    "uptime_delta_seconds": -3_480,
+                    "rebooted": true,
+                    "memory_used_delta": 0,
+                    "memory_available_delta": 0,
+                    "swap_used_delta": 0,
+                    "disks": [],
+                    "added_disks": [],
+                    "removed_disks": ["C:\\"],
+                    "added_networks": [],
+                    "removed_networks": [],
+                    "temperatures": [],
+                }),
+            ),
+        ];
+        for (later, expected) in cases {
+            let diff = diff_system_info(base.clone(), later).unwrap();
+            assert_eq!(serde_json::to_value(diff).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_diff_system_info_snapshot_forms() {
+        let base = snapshot(60, 1_000, 1_000, &[], &[], &[]);
+        let as_string = serde_json::Value::String(base.to_string());
+        assert!(diff_system_info(as_string, base.clone()).is_ok());
+
+        let not_json = serde_json::Value::String("{ not json".into());
+        assert!(diff_system_info(not_json, base.clone())
+            .unwrap_err()
+            .contains("first snapshot is not valid JSON"));
+        assert!(diff_system_info(base, json!({ "uptime_seconds": 1 }))
+            .unwrap_err()
+            .contains("second snapshot is not a system info object"));
+    }
+
+    #[test]
+    fn test_format_duration_human() {
+        let cases = [
+            (0, "0s"),
+            (42, "42s"),
+            (59, "59s"),
+            (60, "1m"),
+            (3_599, "59m"),
+            (3_600, "1h 0m"),
+            (4 * 3_600 + 12 * 60 + 30, "4h 12m"),
+            (86_400, "1d 0h 0m"),
+            (3 * 86_400 + 4 * 3_600 + 12 * 60, "3d 4h 12m"),
+            (400 * 86_400 + 59, "400d 0h 0m"),
+        ];
+        for (seconds, expected) in cases {
+            assert_eq!(
+                format_duration_human(seconds),
+                expected,
+                "{} seconds",
+                seconds
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_identifier() {
+        let cases = [
+            ("  PF2ABCDE  ", Some("PF2ABCDE")),
+            ("BFEBFBFF000906EA", Some("BFEBFBFF000906EA")),
+            (
+                "4C4C4544-0042-3510-8048-B4C04F564B32",
+                Some("4C4C4544-0042-3510-8048-B4C04F564B32"),
+            ),
+            ("", None),
+            ("   ", None),
+            ("To Be Filled By O.E.M.", None),
+            ("Default string", None),
+            ("System Serial Number", None),
+            ("N/A", None),
+            ("None", None),
+            ("03000200-0400-0500-0006-000700080009", None),
+            ("00000000-0000-0000-0000-000000000000", None),
+            ("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF", None),
+            ("0000 0000", None),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                clean_identifier(value.to_string()).as_deref(),
+                expected,
+                "{:?}",
+                value
+            );
+        }
+    }
+}
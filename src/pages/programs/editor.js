@@ -41,6 +41,8 @@ export function openEditor(prog) {
     state.editing.description || "";
   /** @type {HTMLInputElement} */ ($("#p-exe")).value =
     state.editing.exe_path || "";
+  /** @type {HTMLSelectElement} */ ($("#p-window-state")).value =
+    state.editing.window_state || "normal";
   const preview = /** @type {HTMLImageElement} */ ($("#p-logo-preview"));
   const fallbackIcon = /** @type {HTMLElement} */ ($("#p-logo-fallback"));
   if (state.editing.logo_data_url) {
@@ -171,12 +173,31 @@ export function wireEditor() {
     state.editing.exe_path = /** @type {HTMLInputElement} */ (
       $("#p-exe")
     ).value.trim();
+    state.editing.window_state = /** @type {HTMLSelectElement} */ (
+      $("#p-window-state")
+    ).value;
 
     if (!state.editing.name || !state.editing.exe_path) {
       alert("Name and executable are required");
       return;
     }
 
+    try {
+      const duplicate = await invoke("check_program_duplicate", {
+        program: state.editing,
+      });
+      if (
+        duplicate &&
+        !confirm(
+          `This tool is already added as "${duplicate.name}". Save anyway?`
+        )
+      ) {
+        return;
+      }
+    } catch (e) {
+      console.warn("Duplicate check failed", e);
+    }
+
     if (!state.editing.logo_data_url) {
       await tryExtractLogo(state.editing.exe_path);
     }
@@ -38,6 +38,7 @@ export const DEFAULT_LOGO = "ph ph-wrench";
  * @property {string} exe_path Full path to the program executable (.exe).
  * @property {boolean} [exe_exists] Whether `exe_path` currently exists.
  * @property {number} [launch_count] Number of times launched via this app.
+ * @property {"normal"|"minimized"|"maximized"} [window_state] Window state used when launching.
  * @property {string} [logo_data_url] Image data URL for the program logo.
  */
 
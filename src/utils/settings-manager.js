@@ -75,6 +75,9 @@ const NetworkSharingSchema = z.object({
   enabled: z.boolean().default(false),
   unc_path: z.string().default(""),
   save_mode: z.enum(["local", "network", "both"]).default("both"),
+  // Share history maintained by the backend (set_last_network_path)
+  last_path: z.string().optional(),
+  recent_paths: z.array(z.string()).default([]),
 });
 
 /**
@@ -83,6 +86,16 @@ const NetworkSharingSchema = z.object({
 const NetworkSchema = z.object({
   iperf_server: z.string().or(z.literal("")).default(""),
   ping_host: z.string().default("8.8.8.8"),
+  // Overrides the get_public_network_info lookup service
+  public_ip_lookup_url: z.string().optional(),
+});
+
+/**
+ * System info settings schema
+ */
+const SystemInfoSchema = z.object({
+  // Seconds a full get_system_info snapshot is reused (backend default: 30)
+  cache_ttl_seconds: z.number().min(0).max(3600).optional(),
 });
 
 /**
@@ -104,6 +117,9 @@ const SentrySchema = z.object({
 
 /**
  * Root application settings schema
+ *
+ * Unknown keys are passed through so saving from the UI never drops settings
+ * that only the backend manages.
  */
 const AppSettingsSchema = z.object({
   business: BusinessSchema.default({}),
@@ -112,8 +128,15 @@ const AppSettingsSchema = z.object({
   network: NetworkSchema.default({}),
   ai: AISchema.default({}),
   sentry: SentrySchema.default({}),
+  system_info: SystemInfoSchema.default({}),
   sentry_enabled: z.boolean().default(true),
-});
+  prevent_sleep_during_run: z.boolean().default(false),
+  auto_restart_runner: z.boolean().default(false),
+  // Tool key -> category override used by get_tool_statuses_grouped
+  tool_categories: z.record(z.string(), z.string()).optional(),
+  // Tool key -> absolute executable path (managed by set_tool_override)
+  tool_path_overrides: z.record(z.string(), z.string()).optional(),
+}).passthrough();
 
 // ============================================================================
 // SETTINGS MANAGER CLASS